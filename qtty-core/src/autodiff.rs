@@ -0,0 +1,603 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Forward-mode automatic differentiation through unit-typed code
+//! (feature-gated).
+//!
+//! This module is enabled by the `autodiff` feature. [`Dual<N>`] is a dual
+//! number carrying an `f64` value and `N` partial derivatives. It implements
+//! [`Scalar`], [`Real`] and [`Transcendental`], so any code written against
+//! `Quantity<U, S: Real>` (unit conversions, angular trigonometry, typed
+//! roots) propagates derivatives without changes.
+//!
+//! Derivatives come back as quantities in the unit of *output per input*: the
+//! derivative of a `Meters` result with respect to a `Seconds` input is a
+//! `Quantity<Per<Meter, Second>>`.
+//!
+//! ```rust
+//! use qtty_core::autodiff::{self, Dual1};
+//! use qtty_core::length::{Meter, Meters};
+//! use qtty_core::time::{Second, Seconds};
+//! use qtty_core::velocity::Velocity;
+//! use qtty_core::Quantity;
+//!
+//! // Free fall: d(t) = ½·g·t², with g = 9.81 m/s².
+//! let fall = |t: Quantity<Second, Dual1>| -> Quantity<Meter, Dual1> {
+//!     Quantity::new(t.value() * t.value() * Dual1::constant(0.5 * 9.81))
+//! };
+//!
+//! let (d, v): (Meters, Velocity<Meter, Second>) = autodiff::derivative(fall, Seconds::new(2.0));
+//! assert!((d.value() - 19.62).abs() < 1e-12); // m
+//! assert!((v.value() - 19.62).abs() < 1e-12); // m/s
+//! ```
+//!
+//! For several inputs, seed each one with [`Quantity::variable`] and read the
+//! partial derivatives with [`Quantity::derivative`].
+
+use crate::scalar::{Real, Scalar, Transcendental};
+use crate::unit::Per;
+use crate::{Quantity, Unit};
+use core::cmp::Ordering;
+use core::f64::consts::{LN_10, LN_2};
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// A dual number: an `f64` value and its partial derivatives with respect to
+/// `N` independent variables.
+///
+/// Comparisons (`==`, `<`, …) look at the value only, so control flow in the
+/// differentiated code behaves exactly as with plain `f64`. `Display` prints
+/// the value only; use `Debug` to see the derivatives.
+#[derive(Clone, Copy, Debug)]
+pub struct Dual<const N: usize> {
+    value: f64,
+    derivatives: [f64; N],
+}
+
+/// A dual number with a single derivative component.
+pub type Dual1 = Dual<1>;
+
+impl<const N: usize> Dual<N> {
+    /// A constant: the value with every derivative zero.
+    #[inline]
+    pub const fn constant(value: f64) -> Self {
+        Self {
+            value,
+            derivatives: [0.0; N],
+        }
+    }
+
+    /// The `index`-th independent variable: the value with derivative `1`
+    /// in component `index` and zero elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= N`.
+    #[inline]
+    pub fn variable(value: f64, index: usize) -> Self {
+        let mut derivatives = [0.0; N];
+        derivatives[index] = 1.0;
+        Self { value, derivatives }
+    }
+
+    /// Builds a dual number from its parts.
+    #[inline]
+    pub const fn new(value: f64, derivatives: [f64; N]) -> Self {
+        Self { value, derivatives }
+    }
+
+    /// The value.
+    #[inline]
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// All partial derivatives.
+    #[inline]
+    pub const fn derivatives(&self) -> &[f64; N] {
+        &self.derivatives
+    }
+
+    /// The partial derivative with respect to variable `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= N`.
+    #[inline]
+    pub const fn derivative(&self, index: usize) -> f64 {
+        self.derivatives[index]
+    }
+
+    /// Applies the chain rule for a function `f` with `f(value) = value` and
+    /// `f'(value) = slope`.
+    #[inline]
+    fn chain(self, value: f64, slope: f64) -> Self {
+        let mut derivatives = self.derivatives;
+        for d in &mut derivatives {
+            *d = scale_partial(*d, slope);
+        }
+        Self { value, derivatives }
+    }
+
+    /// Combines two operands: `value`, plus `∂/∂self · self' + ∂/∂rhs · rhs'`.
+    #[inline]
+    fn combine(self, rhs: Self, value: f64, d_self: f64, d_rhs: f64) -> Self {
+        let mut derivatives = [0.0; N];
+        for (i, d) in derivatives.iter_mut().enumerate() {
+            *d = scale_partial(self.derivatives[i], d_self)
+                + scale_partial(rhs.derivatives[i], d_rhs);
+        }
+        Self { value, derivatives }
+    }
+}
+
+/// `slope · partial`, except that a zero partial stays zero.
+///
+/// An operand that does not depend on a variable contributes nothing to its
+/// derivative, even where `f'` is infinite or NaN (`sqrt`, `ln`, `cbrt` at
+/// zero). Multiplying through would turn `0 · ∞` into NaN and poison every
+/// later gradient.
+#[inline]
+fn scale_partial(partial: f64, slope: f64) -> f64 {
+    if partial == 0.0 {
+        0.0
+    } else {
+        partial * slope
+    }
+}
+
+impl<const N: usize> PartialEq for Dual<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<const N: usize> PartialOrd for Dual<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<const N: usize> Display for Dual<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl<const N: usize> From<f64> for Dual<N> {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Self::constant(value)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Arithmetic
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<const N: usize> Add for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.combine(rhs, self.value + rhs.value, 1.0, 1.0)
+    }
+}
+
+impl<const N: usize> Sub for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.combine(rhs, self.value - rhs.value, 1.0, -1.0)
+    }
+}
+
+impl<const N: usize> Mul for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.combine(rhs, self.value * rhs.value, rhs.value, self.value)
+    }
+}
+
+impl<const N: usize> Div for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+        self.combine(rhs, value, 1.0 / rhs.value, -value / rhs.value)
+    }
+}
+
+impl<const N: usize> Rem for Dual<N> {
+    type Output = Self;
+
+    /// `self - rhs * trunc(self / rhs)`, with the quotient held constant.
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        let value = self.value % rhs.value;
+        let quotient = Real::trunc(self.value / rhs.value);
+        self.combine(rhs, value, 1.0, -quotient)
+    }
+}
+
+impl<const N: usize> Neg for Dual<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self.chain(-self.value, -1.0)
+    }
+}
+
+macro_rules! impl_assign_ops {
+    ($($trait:ident::$method:ident => $op:tt),+ $(,)?) => {
+        $(
+            impl<const N: usize> $trait for Dual<N> {
+                #[inline]
+                fn $method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )+
+    };
+}
+
+impl_assign_ops!(
+    AddAssign::add_assign => +,
+    SubAssign::sub_assign => -,
+    MulAssign::mul_assign => *,
+    DivAssign::div_assign => /,
+);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Scalar traits
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<const N: usize> Scalar for Dual<N> {
+    const ZERO: Self = Self::constant(0.0);
+    const ONE: Self = Self::constant(1.0);
+
+    #[inline]
+    fn abs(self) -> Self {
+        if self.value < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        if other.value < self.value || self.value.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        if other.value > self.value || self.value.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// `self - rhs.abs() * floor(self / rhs.abs())`, with the quotient held
+    /// constant.
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let value = Scalar::rem_euclid(self.value, rhs.value);
+        let quotient = Real::round((self.value - value) / rhs.value);
+        self.combine(rhs, value, 1.0, -quotient)
+    }
+}
+
+impl<const N: usize> Real for Dual<N> {
+    const PI: Self = Self::constant(core::f64::consts::PI);
+    const TAU: Self = Self::constant(core::f64::consts::TAU);
+    const E: Self = Self::constant(core::f64::consts::E);
+    const INFINITY: Self = Self::constant(f64::INFINITY);
+    const NEG_INFINITY: Self = Self::constant(f64::NEG_INFINITY);
+    const NAN: Self = Self::constant(f64::NAN);
+
+    /// Conversion factors are constants: every derivative is zero.
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        Self::constant(value)
+    }
+
+    /// The value, dropping the derivatives.
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.value
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        Self::constant(Real::signum(self.value))
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.value.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.value.is_infinite()
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.value.is_finite()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        let mut result = self * a + b;
+        result.value = Real::mul_add(self.value, a.value, b.value);
+        result
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        Self::constant(Real::floor(self.value))
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        Self::constant(Real::ceil(self.value))
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        Self::constant(Real::round(self.value))
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Self::constant(Real::trunc(self.value))
+    }
+
+    #[inline]
+    fn fract(self) -> Self {
+        self.chain(Real::fract(self.value), 1.0)
+    }
+
+    #[inline]
+    fn powf(self, exp: Self) -> Self {
+        let value = Real::powf(self.value, exp.value);
+        let d_base = exp.value * Real::powf(self.value, exp.value - 1.0);
+        // Skip the `ln` term for constant exponents so that negative bases
+        // still differentiate.
+        let d_exp = if exp.derivatives.iter().all(|d| *d == 0.0) {
+            0.0
+        } else {
+            value * Real::ln(self.value)
+        };
+        self.combine(exp, value, d_base, d_exp)
+    }
+
+    #[inline]
+    fn powi(self, exp: i32) -> Self {
+        let slope = f64::from(exp) * Real::powi(self.value, exp - 1);
+        self.chain(Real::powi(self.value, exp), slope)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        let value = Real::sqrt(self.value);
+        self.chain(value, 0.5 / value)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        let value = Real::cbrt(self.value);
+        self.chain(value, 1.0 / (3.0 * value * value))
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.chain(Real::ln(self.value), 1.0 / self.value)
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        self.chain(Real::log10(self.value), 1.0 / (self.value * LN_10))
+    }
+
+    #[inline]
+    fn log2(self) -> Self {
+        self.chain(Real::log2(self.value), 1.0 / (self.value * LN_2))
+    }
+
+    #[inline]
+    fn log(self, base: Self) -> Self {
+        Real::ln(self) / Real::ln(base)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        let value = Real::exp(self.value);
+        self.chain(value, value)
+    }
+
+    #[inline]
+    fn exp2(self) -> Self {
+        let value = Real::exp2(self.value);
+        self.chain(value, value * LN_2)
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        let value = Real::hypot(self.value, other.value);
+        if value == 0.0 {
+            return self.combine(other, value, 0.0, 0.0);
+        }
+        self.combine(other, value, self.value / value, other.value / value)
+    }
+}
+
+impl<const N: usize> Transcendental for Dual<N> {
+    #[inline]
+    fn sin(self) -> Self {
+        let (sin, cos) = Transcendental::sin_cos(self.value);
+        self.chain(sin, cos)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        let (sin, cos) = Transcendental::sin_cos(self.value);
+        self.chain(cos, -sin)
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        let value = Transcendental::tan(self.value);
+        self.chain(value, 1.0 + value * value)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = Transcendental::sin_cos(self.value);
+        (self.chain(sin, cos), self.chain(cos, -sin))
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        let slope = 1.0 / Real::sqrt(1.0 - self.value * self.value);
+        self.chain(Transcendental::asin(self.value), slope)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        let slope = -1.0 / Real::sqrt(1.0 - self.value * self.value);
+        self.chain(Transcendental::acos(self.value), slope)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        let slope = 1.0 / (1.0 + self.value * self.value);
+        self.chain(Transcendental::atan(self.value), slope)
+    }
+
+    /// `atan2(y = self, x = other)`.
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        let value = Transcendental::atan2(self.value, other.value);
+        let r2 = self.value * self.value + other.value * other.value;
+        self.combine(other, value, other.value / r2, -self.value / r2)
+    }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        self.chain(
+            Transcendental::sinh(self.value),
+            Transcendental::cosh(self.value),
+        )
+    }
+
+    #[inline]
+    fn cosh(self) -> Self {
+        self.chain(
+            Transcendental::cosh(self.value),
+            Transcendental::sinh(self.value),
+        )
+    }
+
+    #[inline]
+    fn tanh(self) -> Self {
+        let value = Transcendental::tanh(self.value);
+        self.chain(value, 1.0 - value * value)
+    }
+
+    #[inline]
+    fn asinh(self) -> Self {
+        let slope = 1.0 / Real::sqrt(self.value * self.value + 1.0);
+        self.chain(Transcendental::asinh(self.value), slope)
+    }
+
+    #[inline]
+    fn acosh(self) -> Self {
+        let slope = 1.0 / Real::sqrt(self.value * self.value - 1.0);
+        self.chain(Transcendental::acosh(self.value), slope)
+    }
+
+    #[inline]
+    fn atanh(self) -> Self {
+        let slope = 1.0 / (1.0 - self.value * self.value);
+        self.chain(Transcendental::atanh(self.value), slope)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Unit-typed seeding and derivatives
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<U: Unit, const N: usize> Quantity<U, Dual<N>> {
+    /// Seeds `value` as the `index`-th independent variable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= N`.
+    #[inline]
+    pub fn variable(value: Quantity<U>, index: usize) -> Self {
+        Quantity::new(Dual::variable(value.value(), index))
+    }
+
+    /// The value, without derivatives.
+    #[inline]
+    pub fn primal(self) -> Quantity<U> {
+        Quantity::new(self.value().value)
+    }
+
+    /// The partial derivative with respect to the `index`-th variable, whose
+    /// unit is `X`.
+    ///
+    /// ```rust
+    /// use qtty_core::autodiff::Dual;
+    /// use qtty_core::length::{Meter, Meters};
+    /// use qtty_core::time::{Second, Seconds};
+    /// use qtty_core::{Per, Quantity};
+    ///
+    /// let x = Quantity::<Meter, Dual<2>>::variable(Meters::new(3.0), 0);
+    /// let t = Quantity::<Second, Dual<2>>::variable(Seconds::new(2.0), 1);
+    /// let speed: Quantity<Per<Meter, Second>, Dual<2>> = x / t;
+    ///
+    /// assert_eq!(speed.derivative::<Meter>(0).value(), 0.5); // 1/t, in 1/s
+    /// assert_eq!(speed.derivative::<Second>(1).value(), -0.75); // -x/t², in m/s²
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= N`.
+    #[inline]
+    pub fn derivative<X: Unit>(self, index: usize) -> Quantity<Per<U, X>>
+    where
+        Per<U, X>: Unit,
+    {
+        Quantity::new(self.value().derivative(index))
+    }
+}
+
+/// Evaluates `f` at `at` and returns its value and derivative, with the
+/// derivative in the unit of output per input.
+#[inline]
+pub fn derivative<X, Y, F>(f: F, at: Quantity<X>) -> (Quantity<Y>, Quantity<Per<Y, X>>)
+where
+    X: Unit,
+    Y: Unit,
+    Per<Y, X>: Unit,
+    F: FnOnce(Quantity<X, Dual1>) -> Quantity<Y, Dual1>,
+{
+    let y = f(Quantity::variable(at, 0));
+    (y.primal(), y.derivative(0))
+}
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Runtime catalogue of the built-in units.
+//!
+//! The per-module inventory macros (`length_units!`, `time_units!`, …) know
+//! every built-in unit marker, but only at macro-expansion time. This module
+//! exposes the same list at runtime as [`UnitInfo`] records, for example to
+//! populate unit pickers in a UI or to build registries across an FFI boundary.
+//!
+//! Only units from enabled cargo features are listed. Units are yielded in a
+//! fixed order: always-available families first (length, time, mass, …), then
+//! feature-gated families. When two units share a symbol (e.g. `h` for both
+//! [`Hour`](crate::time::Hour) and the `astro` hour angle), [`by_symbol`]
+//! returns the first one.
+//!
+//! ```rust
+//! use qtty_core::{catalog, Length, Unit};
+//! use qtty_core::length::Kilometer;
+//!
+//! let km = catalog::by_symbol("km").unwrap();
+//! assert_eq!(km.type_name(), "Kilometer");
+//! assert_eq!(km.ratio(), Kilometer::RATIO);
+//! assert_eq!(km.feature(), None);
+//!
+//! assert!(catalog::of_dimension::<Length>().any(|unit| unit.symbol() == "m"));
+//! ```
+
+use crate::dimension::{DimExponents, Dimension};
+use crate::dynamic::DynUnit;
+use crate::unit::Unit;
+use crate::units::inventory::FAMILIES;
+
+/// Runtime description of one built-in unit marker.
+///
+/// Obtained from [`units`] and the lookup functions in this module.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitInfo {
+    type_name: &'static str,
+    feature: Option<&'static str>,
+    symbol: &'static str,
+    long_name: &'static str,
+    plural: &'static str,
+    system: &'static str,
+    aliases: &'static [&'static str],
+    unit: DynUnit,
+}
+
+impl UnitInfo {
+    /// Describes `U`, registered under `type_name` by the inventory of
+    /// `feature` (`None` for always-available families).
+    pub(crate) const fn of<U: Unit>(
+        type_name: &'static str,
+        feature: Option<&'static str>,
+    ) -> Self {
+        Self {
+            type_name,
+            feature,
+            symbol: U::SYMBOL,
+            long_name: U::LONG_NAME,
+            plural: U::PLURAL,
+            system: U::SYSTEM,
+            aliases: U::ALIASES,
+            unit: DynUnit::of::<U>(),
+        }
+    }
+
+    /// Name of the marker type, e.g. `"Kilometer"` or `"SquareMeter"`.
+    #[inline]
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Cargo feature that enables the unit's family, or `None` if the family
+    /// is always available.
+    #[inline]
+    pub const fn feature(&self) -> Option<&'static str> {
+        self.feature
+    }
+
+    /// [`Unit::SYMBOL`] of the marker.
+    #[inline]
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// [`Unit::LONG_NAME`] of the marker (empty for composite aliases such as
+    /// `SquareMeter`).
+    #[inline]
+    pub const fn long_name(&self) -> &'static str {
+        self.long_name
+    }
+
+    /// [`Unit::PLURAL`] of the marker.
+    #[inline]
+    pub const fn plural(&self) -> &'static str {
+        self.plural
+    }
+
+    /// [`Unit::SYSTEM`] of the marker.
+    #[inline]
+    pub const fn system(&self) -> &'static str {
+        self.system
+    }
+
+    /// [`Unit::ALIASES`] of the marker.
+    #[inline]
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// [`Unit::RATIO`] of the marker.
+    #[inline]
+    pub const fn ratio(&self) -> f64 {
+        self.unit.ratio()
+    }
+
+    /// Dimension exponents of the marker.
+    #[inline]
+    pub const fn dimension(&self) -> DimExponents {
+        self.unit.dimension()
+    }
+
+    /// The marker as a runtime [`DynUnit`].
+    #[inline]
+    pub const fn dyn_unit(&self) -> DynUnit {
+        self.unit
+    }
+
+    /// Whether `name` is the long name, plural or an alias of this unit,
+    /// ignoring ASCII case.
+    pub fn has_name(&self, name: &str) -> bool {
+        !name.is_empty()
+            && [self.long_name, self.plural]
+                .iter()
+                .chain(self.aliases)
+                .any(|candidate| candidate.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Iterates over every enabled built-in unit, in inventory order.
+pub fn units() -> impl Iterator<Item = &'static UnitInfo> + Clone {
+    FAMILIES.iter().flat_map(|family| family.iter())
+}
+
+/// Finds the first built-in unit whose symbol is exactly `symbol`.
+pub fn by_symbol(symbol: &str) -> Option<&'static UnitInfo> {
+    units().find(|unit| unit.symbol == symbol)
+}
+
+/// Finds the first built-in unit named `name` (long name, plural or alias,
+/// ignoring ASCII case).
+pub fn by_name(name: &str) -> Option<&'static UnitInfo> {
+    units().find(|unit| unit.has_name(name))
+}
+
+/// Iterates over the built-in units with the given dimension exponents.
+pub fn by_dimension(dimension: DimExponents) -> impl Iterator<Item = &'static UnitInfo> + Clone {
+    units().filter(move |unit| unit.dimension() == dimension)
+}
+
+/// Iterates over the built-in units of dimension `D`.
+pub fn of_dimension<D: Dimension>() -> impl Iterator<Item = &'static UnitInfo> + Clone {
+    by_dimension(D::EXPONENTS)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::length::{Kilometer, Meter};
+    use crate::time::Hour;
+    use crate::{Length, Time, Unit};
+
+    #[test]
+    fn describes_builtin_units() {
+        let km = by_symbol("km").unwrap();
+        assert_eq!(km.type_name(), "Kilometer");
+        assert_eq!(km.long_name(), "kilometre");
+        assert_eq!(km.system(), "SI");
+        assert_eq!(km.dimension(), Length::EXPONENTS);
+        assert_eq!(km.dyn_unit(), DynUnit::of::<Kilometer>());
+        assert_eq!(km.feature(), None);
+    }
+
+    #[test]
+    fn first_symbol_wins() {
+        assert_eq!(by_symbol("h").unwrap().dyn_unit(), DynUnit::of::<Hour>());
+        assert!(by_symbol("no-such-unit").is_none());
+    }
+
+    #[test]
+    fn looks_up_names() {
+        assert_eq!(by_name("Metres").unwrap().ratio(), Meter::RATIO);
+        assert_eq!(by_name("meter"), by_symbol("m"));
+        assert!(by_name("").is_none());
+    }
+
+    #[test]
+    fn filters_by_dimension() {
+        assert!(of_dimension::<Length>().all(|unit| unit.dimension() == Length::EXPONENTS));
+        assert!(of_dimension::<Time>().any(|unit| unit.type_name() == "Hour"));
+        assert!(!of_dimension::<Length>().any(|unit| unit.type_name() == "Hour"));
+    }
+
+    #[test]
+    fn type_names_are_unique_per_feature_set() {
+        let all: Vec<_> = units().map(UnitInfo::type_name).collect();
+        let mut sorted = all.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), all.len());
+    }
+
+    #[cfg(feature = "astro")]
+    #[test]
+    fn reports_family_feature() {
+        assert_eq!(by_symbol("au").unwrap().feature(), Some("astro"));
+    }
+}
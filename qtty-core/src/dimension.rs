@@ -25,15 +25,268 @@
 //! | `J`      | Luminous intensity       | cd        |
 //! | `A`      | Plane angle (auxiliary)  | rad/deg   |
 
+use core::fmt;
 use core::marker::PhantomData;
-use core::ops::{Add, Sub};
+use core::ops::{Add, Div, Mul, Sub};
 use typenum::Integer;
 
 /// Marker trait for **dimensions**.
 ///
 /// Implemented automatically for every [`Dim<L,T,M,Th,I,N,J,A>`] whose type
 /// parameters satisfy the required bounds.
-pub trait Dimension: 'static {}
+pub trait Dimension: 'static {
+    /// Runtime view of the eight exponents of this dimension.
+    const EXPONENTS: DimExponents;
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Runtime dimension exponents
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The eight exponents of a [`Dim`], available at runtime.
+///
+/// This is the value-level counterpart of the type-level [`Dim`] and is used
+/// wherever a dimension is only known at runtime (e.g. parsed unit strings).
+/// Multiplying two exponent sets adds them; dividing subtracts them.
+///
+/// ```rust
+/// use qtty_core::{Dimension, DimExponents, Length, Time, Velocity};
+///
+/// let v = Length::EXPONENTS / Time::EXPONENTS;
+/// assert_eq!(v, Velocity::EXPONENTS);
+/// assert_eq!(v.to_string(), "L·T⁻¹");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DimExponents {
+    /// Length exponent (`L`).
+    pub length: i8,
+    /// Time exponent (`T`).
+    pub time: i8,
+    /// Mass exponent (`M`).
+    pub mass: i8,
+    /// Thermodynamic temperature exponent (`Th`).
+    pub temperature: i8,
+    /// Electric current exponent (`I`).
+    pub current: i8,
+    /// Amount of substance exponent (`N`).
+    pub amount: i8,
+    /// Luminous intensity exponent (`J`).
+    pub luminous_intensity: i8,
+    /// Plane angle exponent (`A`).
+    pub angle: i8,
+}
+
+impl DimExponents {
+    /// All exponents zero.
+    pub const DIMENSIONLESS: Self = Self::from_array([0; 8]);
+
+    /// Builds exponents from an array in [`Dim`] slot order (`L, T, M, Th, I, N, J, A`).
+    #[inline]
+    pub const fn from_array(e: [i8; 8]) -> Self {
+        Self {
+            length: e[0],
+            time: e[1],
+            mass: e[2],
+            temperature: e[3],
+            current: e[4],
+            amount: e[5],
+            luminous_intensity: e[6],
+            angle: e[7],
+        }
+    }
+
+    /// Returns the exponents as an array in [`Dim`] slot order (`L, T, M, Th, I, N, J, A`).
+    #[inline]
+    pub const fn to_array(self) -> [i8; 8] {
+        [
+            self.length,
+            self.time,
+            self.mass,
+            self.temperature,
+            self.current,
+            self.amount,
+            self.luminous_intensity,
+            self.angle,
+        ]
+    }
+
+    /// Returns `true` if every exponent is zero.
+    #[inline]
+    pub const fn is_dimensionless(self) -> bool {
+        let e = self.to_array();
+        let mut i = 0;
+        while i < 8 {
+            if e[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Exponents of the product of two dimensions (element-wise sum).
+    ///
+    /// Overflowing an `i8` exponent panics in debug builds and in `const`
+    /// evaluation, and wraps in release builds. Use
+    /// [`checked_mul`](Self::checked_mul) for exponents from untrusted input.
+    #[inline]
+    pub const fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self.to_array(), rhs.to_array());
+        let mut out = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            out[i] = a[i] + b[i];
+            i += 1;
+        }
+        Self::from_array(out)
+    }
+
+    /// Exponents of the quotient of two dimensions (element-wise difference).
+    ///
+    /// Overflows like [`mul`](Self::mul); see [`checked_div`](Self::checked_div).
+    #[inline]
+    pub const fn div(self, rhs: Self) -> Self {
+        let (a, b) = (self.to_array(), rhs.to_array());
+        let mut out = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            out[i] = a[i] - b[i];
+            i += 1;
+        }
+        Self::from_array(out)
+    }
+
+    /// Exponents of this dimension raised to the integer power `n`.
+    ///
+    /// Overflows like [`mul`](Self::mul); see [`checked_powi`](Self::checked_powi).
+    #[inline]
+    pub const fn powi(self, n: i8) -> Self {
+        let a = self.to_array();
+        let mut out = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            out[i] = a[i] * n;
+            i += 1;
+        }
+        Self::from_array(out)
+    }
+
+    /// Like [`mul`](Self::mul), but returns `None` if an exponent overflows `i8`.
+    ///
+    /// ```rust
+    /// use qtty_core::{Dimension, DimExponents, Length};
+    ///
+    /// let m127 = Length::EXPONENTS.powi(127);
+    /// assert_eq!(m127.checked_mul(Length::EXPONENTS), None);
+    /// ```
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (self.to_array(), rhs.to_array());
+        let mut out = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            out[i] = match a[i].checked_add(b[i]) {
+                Some(e) => e,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(Self::from_array(out))
+    }
+
+    /// Like [`div`](Self::div), but returns `None` if an exponent overflows `i8`.
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (self.to_array(), rhs.to_array());
+        let mut out = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            out[i] = match a[i].checked_sub(b[i]) {
+                Some(e) => e,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(Self::from_array(out))
+    }
+
+    /// Like [`powi`](Self::powi), but returns `None` if an exponent overflows `i8`.
+    #[inline]
+    pub const fn checked_powi(self, n: i8) -> Option<Self> {
+        let a = self.to_array();
+        let mut out = [0; 8];
+        let mut i = 0;
+        while i < 8 {
+            out[i] = match a[i].checked_mul(n) {
+                Some(e) => e,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(Self::from_array(out))
+    }
+}
+
+impl Mul for DimExponents {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        DimExponents::mul(self, rhs)
+    }
+}
+
+impl Div for DimExponents {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        DimExponents::div(self, rhs)
+    }
+}
+
+/// Formats the exponents as a dimension formula such as `L·T⁻¹`, or `1` when dimensionless.
+impl fmt::Display for DimExponents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SYMBOLS: [&str; 8] = ["L", "T", "M", "Θ", "I", "N", "J", "A"];
+        if self.is_dimensionless() {
+            return f.write_str("1");
+        }
+        let mut first = true;
+        for (symbol, exp) in SYMBOLS.iter().zip(self.to_array()) {
+            if exp == 0 {
+                continue;
+            }
+            if !first {
+                f.write_str("·")?;
+            }
+            first = false;
+            f.write_str(symbol)?;
+            if exp != 1 {
+                write_superscript(f, exp.into())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes `n` using Unicode superscript digits (e.g. `-2` → `⁻²`).
+pub(crate) fn write_superscript(f: &mut dyn fmt::Write, n: i32) -> fmt::Result {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    if n < 0 {
+        f.write_str("⁻")?;
+    }
+    let n = n.unsigned_abs();
+    let mut div = 1;
+    while n / div >= 10 {
+        div *= 10;
+    }
+    while div > 0 {
+        f.write_char(DIGITS[(n / div % 10) as usize])?;
+        div /= 10;
+    }
+    Ok(())
+}
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Core dimension struct
@@ -74,6 +327,8 @@ where
     J: Integer + 'static,
     A: Integer + 'static,
 {
+    const EXPONENTS: DimExponents =
+        DimExponents::from_array([L::I8, T::I8, M::I8, Th::I8, I::I8, N::I8, J::I8, A::I8]);
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -176,6 +431,48 @@ where
     >;
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Dimension integer power (multiplies exponents)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Trait for raising a dimension to a type-level integer power (multiplies exponents).
+pub trait DimPow<E: Integer>: Dimension {
+    /// The resulting dimension.
+    type Output: Dimension;
+}
+
+impl<L, T, M, Th, I, N, J, A, E> DimPow<E> for Dim<L, T, M, Th, I, N, J, A>
+where
+    L: Integer + Mul<E> + 'static,
+    T: Integer + Mul<E> + 'static,
+    M: Integer + Mul<E> + 'static,
+    Th: Integer + Mul<E> + 'static,
+    I: Integer + Mul<E> + 'static,
+    N: Integer + Mul<E> + 'static,
+    J: Integer + Mul<E> + 'static,
+    A: Integer + Mul<E> + 'static,
+    E: Integer,
+    <L as Mul<E>>::Output: Integer + 'static,
+    <T as Mul<E>>::Output: Integer + 'static,
+    <M as Mul<E>>::Output: Integer + 'static,
+    <Th as Mul<E>>::Output: Integer + 'static,
+    <I as Mul<E>>::Output: Integer + 'static,
+    <N as Mul<E>>::Output: Integer + 'static,
+    <J as Mul<E>>::Output: Integer + 'static,
+    <A as Mul<E>>::Output: Integer + 'static,
+{
+    type Output = Dim<
+        <L as Mul<E>>::Output,
+        <T as Mul<E>>::Output,
+        <M as Mul<E>>::Output,
+        <Th as Mul<E>>::Output,
+        <I as Mul<E>>::Output,
+        <N as Mul<E>>::Output,
+        <J as Mul<E>>::Output,
+        <A as Mul<E>>::Output,
+    >;
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Base dimension aliases
 // ─────────────────────────────────────────────────────────────────────────────
@@ -0,0 +1,379 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! SI-prefix auto-scaling formatter for quantities.
+//!
+//! [`Quantity::display_engineering`] returns an [`EngineeringDisplay`] that
+//! rescales the value into the best-fitting SI-prefixed sibling of its unit
+//! before printing it. Only units that exist in the built-in inventory are
+//! considered, so `Meters::new(1.2e9)` prints as `1.2 Gm` because
+//! [`Gigameter`](crate::length::Gigameter) exists, while a custom unit or a
+//! composite unit (`Per`, `Prod`, …) is printed unchanged.
+//!
+//! ```rust
+//! use qtty_core::length::Meters;
+//! use qtty_core::power::Watts;
+//!
+//! assert_eq!(Meters::new(1.2e9).display_engineering().to_string(), "1.2 Gm");
+//! assert_eq!(format!("{:.1}", Watts::new(3.1e-7).display_engineering()), "310.0 nW");
+//! ```
+
+use crate::catalog::{self, UnitInfo};
+use crate::dynamic::powi_f64;
+use crate::scalar::Real;
+use crate::unit::write_unit_suffix;
+use crate::units::inventory::SI_PREFIXES;
+use crate::{DynUnit, Quantity, Unit};
+use core::fmt::{self, Display, Formatter};
+
+/// Display adapter that rescales a quantity to the best SI prefix.
+///
+/// Created by [`Quantity::display_engineering`]. The chosen unit is the
+/// largest registered prefix for which the value's magnitude is at least 1,
+/// falling back to the smallest registered prefix for tiny values. Zero and
+/// non-finite values keep the quantity's own unit. Width, precision and sign
+/// flags apply to the number, as with [`Quantity`]'s own `Display`. The
+/// prefix is chosen for the number as printed, so `999.96 m` at `{:.1}`
+/// becomes `1.0 km` rather than `1000.0 m`.
+///
+/// By default every SI prefix registered for the unit family is eligible,
+/// including `h`, `da`, `d` and `c`. Use
+/// [`thousands_only`](Self::thousands_only) to restrict the choice to
+/// engineering multiples of 1000.
+#[derive(Clone, Copy, Debug)]
+pub struct EngineeringDisplay<U: Unit, S: Real> {
+    quantity: Quantity<U, S>,
+    thousands_only: bool,
+}
+
+impl<U: Unit, S: Real> EngineeringDisplay<U, S> {
+    /// Restricts the chosen prefix to powers of 1000 (`k`, `M`, `m`, `µ`, …),
+    /// plus the unprefixed unit.
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meters;
+    ///
+    /// let q = Meters::new(0.05);
+    /// assert_eq!(q.display_engineering().to_string(), "5 cm");
+    /// assert_eq!(q.display_engineering().thousands_only().to_string(), "50 mm");
+    /// ```
+    #[inline]
+    pub const fn thousands_only(mut self) -> Self {
+        self.thousands_only = true;
+        self
+    }
+}
+
+impl<U: Unit, S: Real> Quantity<U, S> {
+    /// Returns a [`Display`] adapter that prints this quantity with the best
+    /// SI-prefixed unit of the same family, e.g. `1.2 Gm` or `310 nW`.
+    ///
+    /// See [`EngineeringDisplay`] for how the prefix is chosen.
+    #[inline]
+    pub fn display_engineering(self) -> EngineeringDisplay<U, S> {
+        EngineeringDisplay {
+            quantity: self,
+            thousands_only: false,
+        }
+    }
+}
+
+impl<U: Unit, S: Real> Display for EngineeringDisplay<U, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = self.quantity.value();
+        let magnitude = value.to_f64().abs();
+        let choice = family_of::<U>().filter(|_| magnitude.is_finite() && magnitude > 0.0);
+        let Some((family, own_exp)) = choice else {
+            Display::fmt(&value, f)?;
+            return write_unit_suffix::<U>(f);
+        };
+
+        // `magnitude` expressed in the unprefixed unit of the family.
+        let base_magnitude = scale(magnitude, own_exp);
+        let (mut exp, mut symbol) = family.pick(base_magnitude, self.thousands_only);
+        // Rounding to the requested precision can carry into the next prefix
+        // (`999.96 m` at `{:.1}` would print as `1000.0 m`), so pick again
+        // with the value as it will be printed. One pass is enough: after
+        // moving up, the value rounds to at least 1.
+        if let Some(precision) = f.precision() {
+            let printed = round_to(scale(base_magnitude, -exp), precision);
+            (exp, symbol) = family.pick(scale(printed, exp), self.thousands_only);
+        }
+
+        let shift = own_exp - exp;
+        let factor = S::from_f64(powi_f64(10.0, shift.abs()));
+        let scaled = if shift >= 0 {
+            value * factor
+        } else {
+            value / factor
+        };
+        Display::fmt(&scaled, f)?;
+        write!(f, " {symbol}")
+    }
+}
+
+/// Multiplies `x` by `10^exp`, dividing for negative exponents so exact
+/// powers of ten do not pick up rounding noise.
+fn scale(x: f64, exp: i32) -> f64 {
+    let p = powi_f64(10.0, exp.abs());
+    if exp >= 0 {
+        x * p
+    } else {
+        x / p
+    }
+}
+
+/// Rounds `x` to `precision` decimal places, as `{:.precision}` prints it.
+fn round_to(x: f64, precision: usize) -> f64 {
+    // Beyond 17 digits an `f64` has nothing left to round.
+    let digits = Ord::min(precision, 17) as i32;
+    scale(Real::round(scale(x, digits)), -digits)
+}
+
+/// Upper bound on the members of a [`PrefixFamily`]: the unprefixed unit
+/// plus one per SI prefix.
+const MAX_MEMBERS: usize = SI_PREFIXES.len() + 1;
+
+/// An unprefixed built-in unit together with its registered SI-prefixed
+/// siblings, e.g. `m` with `km`, `cm`, `nm`, ….
+#[derive(Clone, Copy)]
+struct PrefixFamily {
+    /// `(prefix exponent, symbol, unit)`, sorted by exponent. The
+    /// unprefixed unit has exponent 0.
+    members: [(i32, &'static str, DynUnit); MAX_MEMBERS],
+    len: usize,
+}
+
+impl PrefixFamily {
+    /// Collects the prefixed siblings of `base`, or `None` if it has none.
+    fn with_base(base: &UnitInfo) -> Option<Self> {
+        let mut family = Self {
+            members: [(0, base.symbol(), base.dyn_unit()); MAX_MEMBERS],
+            len: 1,
+        };
+        for entry in catalog::by_dimension(base.dimension()) {
+            for &(prefix, exp) in SI_PREFIXES {
+                let matches = entry
+                    .symbol()
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest == base.symbol());
+                if matches && is_prefixed(base.dyn_unit(), entry.dyn_unit(), exp) {
+                    family.members[family.len] = (exp, entry.symbol(), entry.dyn_unit());
+                    family.len += 1;
+                    break;
+                }
+            }
+            if family.len == MAX_MEMBERS {
+                break;
+            }
+        }
+        if family.len == 1 {
+            return None;
+        }
+        family.members[..family.len].sort_unstable_by_key(|&(exp, _, _)| exp);
+        Some(family)
+    }
+
+    fn members(&self) -> &[(i32, &'static str, DynUnit)] {
+        &self.members[..self.len]
+    }
+
+    /// Prefix exponent of `U` within this family, if `U` is a member.
+    fn exponent_of<U: Unit>(&self) -> Option<i32> {
+        let own = DynUnit::of::<U>();
+        self.members()
+            .iter()
+            .find(|&&(_, symbol, unit)| symbol == U::SYMBOL && unit == own)
+            .map(|&(exp, _, _)| exp)
+    }
+
+    /// The largest eligible member for which `base_magnitude` (in the
+    /// unprefixed unit) is at least 1, or the smallest eligible member for
+    /// tiny values.
+    fn pick(&self, base_magnitude: f64, thousands_only: bool) -> (i32, &'static str) {
+        let mut eligible = self
+            .members()
+            .iter()
+            .filter(|&&(exp, _, _)| !thousands_only || exp % 3 == 0);
+        // The unprefixed member is always eligible, so this never fails.
+        let smallest = eligible.clone().next().map_or((0, ""), |&(e, s, _)| (e, s));
+        eligible
+            .rfind(|&&(exp, _, _)| base_magnitude >= powi_f64(10.0, exp))
+            .map_or(smallest, |&(exp, symbol, _)| (exp, symbol))
+    }
+}
+
+/// Finds the prefix family that `U` belongs to, and `U`'s prefix exponent
+/// within it. Only built-in units have one.
+///
+/// With `std` the families of the whole catalogue are collected once and
+/// reused by every call.
+#[cfg(feature = "std")]
+fn family_of<U: Unit>() -> Option<(PrefixFamily, i32)> {
+    static FAMILIES: std::sync::OnceLock<Vec<PrefixFamily>> = std::sync::OnceLock::new();
+    FAMILIES
+        .get_or_init(|| {
+            catalog::units()
+                .filter_map(PrefixFamily::with_base)
+                .collect()
+        })
+        .iter()
+        .find_map(|family| Some((*family, family.exponent_of::<U>()?)))
+}
+
+/// Finds the prefix family that `U` belongs to, and `U`'s prefix exponent
+/// within it. Only built-in units have one.
+///
+/// Without `std` there is nowhere to cache the families, so only the
+/// candidate bases of `U` (units whose symbol ends `U::SYMBOL`) are scanned.
+#[cfg(not(feature = "std"))]
+fn family_of<U: Unit>() -> Option<(PrefixFamily, i32)> {
+    catalog::by_dimension(DynUnit::of::<U>().dimension())
+        .filter(|base| U::SYMBOL.ends_with(base.symbol()))
+        .filter_map(PrefixFamily::with_base)
+        .find_map(|family| Some((family, family.exponent_of::<U>()?)))
+}
+
+/// Whether `unit` equals `base` scaled by `10^exp` (same dimension, ratio
+/// within floating-point rounding).
+fn is_prefixed(base: DynUnit, unit: DynUnit, exp: i32) -> bool {
+    if base.dimension() != unit.dimension() {
+        return false;
+    }
+    let expected = scale(base.ratio(), exp);
+    (unit.ratio() - expected).abs() <= 1e-9 * expected.abs()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::length::{Kilometers, Meters};
+    use crate::mass::Kilograms;
+    use crate::power::Watts;
+    use crate::time::{Hours, Minutes, Seconds};
+    use crate::Quantity;
+
+    // ── Prefix selection ──────────────────────────────────────────────────────
+
+    #[test]
+    fn picks_largest_prefix_with_magnitude_at_least_one() {
+        assert_eq!(
+            Meters::new(1.2e9).display_engineering().to_string(),
+            "1.2 Gm"
+        );
+        assert_eq!(
+            Watts::new(3.1e-7).display_engineering().to_string(),
+            "310 nW"
+        );
+        assert_eq!(
+            Meters::new(-4_500.0).display_engineering().to_string(),
+            "-4.5 km"
+        );
+        assert_eq!(Meters::new(1.0).display_engineering().to_string(), "1 m");
+    }
+
+    #[test]
+    fn rescales_from_prefixed_units() {
+        assert_eq!(
+            Kilometers::new(0.0012).display_engineering().to_string(),
+            "1.2 m"
+        );
+        assert_eq!(
+            Kilograms::new(0.25).display_engineering().to_string(),
+            "2.5 hg"
+        );
+        assert_eq!(
+            Kilograms::new(0.25)
+                .display_engineering()
+                .thousands_only()
+                .to_string(),
+            "250 g"
+        );
+    }
+
+    #[test]
+    fn thousands_only_skips_hecto_deca_deci_centi() {
+        let q = Meters::new(0.05);
+        assert_eq!(q.display_engineering().to_string(), "5 cm");
+        assert_eq!(
+            q.display_engineering().thousands_only().to_string(),
+            "50 mm"
+        );
+        let q = Meters::new(300.0);
+        assert_eq!(q.display_engineering().to_string(), "3 hm");
+        assert_eq!(
+            q.display_engineering().thousands_only().to_string(),
+            "300 m"
+        );
+    }
+
+    #[test]
+    fn honours_formatter_flags() {
+        let q = Watts::new(3.1e-7).display_engineering();
+        assert_eq!(format!("{q:.2}"), "310.00 nW");
+        assert_eq!(format!("{q:>8.1}"), "   310.0 nW");
+        assert_eq!(
+            format!("{:+}", Meters::new(2e3).display_engineering()),
+            "+2 km"
+        );
+    }
+
+    #[test]
+    fn rounding_to_precision_carries_into_the_next_prefix() {
+        let q = Meters::new(999.96).display_engineering().thousands_only();
+        assert_eq!(format!("{q:.1}"), "1.0 km");
+        assert_eq!(format!("{q:.2}"), "999.96 m");
+        assert_eq!(q.to_string(), "999.96 m");
+        assert_eq!(
+            format!("{:.0}", Kilometers::new(0.9996).display_engineering()),
+            "1 km"
+        );
+        assert_eq!(
+            format!("{:.1}", Meters::new(99.96).display_engineering()),
+            "1.0 hm"
+        );
+        assert_eq!(
+            format!("{:.1}", Watts::new(-9.9996e-7).display_engineering()),
+            "-1.0 µW"
+        );
+    }
+
+    // ── Fallbacks ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn zero_and_non_finite_keep_the_unit() {
+        assert_eq!(
+            Kilometers::new(0.0).display_engineering().to_string(),
+            "0 km"
+        );
+        assert_eq!(
+            Kilometers::new(f64::NAN).display_engineering().to_string(),
+            "NaN km"
+        );
+        assert_eq!(
+            Meters::new(f64::INFINITY).display_engineering().to_string(),
+            "inf m"
+        );
+    }
+
+    #[test]
+    fn units_without_prefix_family_are_unchanged() {
+        assert_eq!(
+            Hours::new(5_000.0).display_engineering().to_string(),
+            "5000 h"
+        );
+        // `min` must not be read as milli-`in`.
+        assert_eq!(
+            Minutes::new(0.5).display_engineering().to_string(),
+            "0.5 min"
+        );
+
+        let v = Meters::new(3.0) / Seconds::new(1.0);
+        assert_eq!(v.display_engineering().to_string(), "3 m/s");
+    }
+
+    #[test]
+    fn works_for_f32() {
+        let q: Quantity<crate::length::Meter, f32> = Quantity::new(2.5e-6);
+        assert_eq!(q.display_engineering().to_string(), "2.5 μm");
+    }
+}
@@ -0,0 +1,507 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Runtime (dynamically dimensioned) units and quantities.
+//!
+//! [`DynUnit`] is the value-level counterpart of a [`Unit`] marker: it carries
+//! the unit's [`DimExponents`] and its scale [`RATIO`](Unit::RATIO) so that
+//! units only known at runtime (for example, parsed from a string) can still be
+//! checked and converted against typed [`Quantity`] values.
+//!
+//! [`DynQuantity`] pairs a value with a [`DynUnit`]. Dimensional consistency is
+//! checked when the quantity is added, subtracted, or converted back into a
+//! typed [`Quantity`]:
+//!
+//! ```rust
+//! use qtty_core::length::{Kilometers, Meters};
+//! use qtty_core::time::Seconds;
+//! use qtty_core::DynQuantity;
+//!
+//! let d = DynQuantity::from(Kilometers::new(1.5));
+//! let total = d.checked_add(Meters::new(500.0).into()).unwrap();
+//! let m: Meters = total.try_into().unwrap();
+//! assert_eq!(m.value(), 2000.0);
+//!
+//! assert!(d.checked_add(Seconds::new(1.0).into()).is_none());
+//! ```
+
+use crate::dimension::{DimExponents, Dimension};
+use crate::quantity::Quantity;
+use crate::scalar::Real;
+use crate::unit::Unit;
+use core::fmt;
+use core::ops::{Div, Mul, Neg};
+
+/// A unit known only at runtime: dimension exponents plus scale ratio.
+///
+/// The ratio follows the same convention as [`Unit::RATIO`]: it is relative to
+/// the canonical scaling unit of each dimension, so two runtime units with the
+/// same dimension convert by `self.ratio() / other.ratio()`.
+///
+/// ```rust
+/// use qtty_core::length::Kilometer;
+/// use qtty_core::time::Hour;
+/// use qtty_core::{DynUnit, Per};
+///
+/// let km_h = DynUnit::of::<Kilometer>() / DynUnit::of::<Hour>();
+/// assert_eq!(km_h, DynUnit::of::<Per<Kilometer, Hour>>());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynUnit {
+    dimension: DimExponents,
+    ratio: f64,
+}
+
+impl DynUnit {
+    /// The dimensionless unit with ratio `1`.
+    pub const DIMENSIONLESS: Self = Self::new(DimExponents::DIMENSIONLESS, 1.0);
+
+    /// Creates a runtime unit from its dimension exponents and scale ratio.
+    #[inline]
+    pub const fn new(dimension: DimExponents, ratio: f64) -> Self {
+        Self { dimension, ratio }
+    }
+
+    /// Runtime description of the unit marker `U`.
+    #[inline]
+    pub const fn of<U: Unit>() -> Self {
+        Self::new(<U::Dim as Dimension>::EXPONENTS, U::RATIO)
+    }
+
+    /// Dimension exponents of this unit.
+    #[inline]
+    pub const fn dimension(&self) -> DimExponents {
+        self.dimension
+    }
+
+    /// Scale ratio of this unit relative to the canonical unit of its dimension.
+    #[inline]
+    pub const fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// Returns `true` if both units measure the same dimension.
+    #[inline]
+    pub fn is_compatible_with(&self, other: &Self) -> bool {
+        self.dimension == other.dimension
+    }
+
+    /// Factor converting a value in `self` into a value in `other`, or `None`
+    /// if the dimensions differ.
+    #[inline]
+    pub fn conversion_factor_to(&self, other: &Self) -> Option<f64> {
+        self.is_compatible_with(other)
+            .then(|| self.ratio / other.ratio)
+    }
+
+    /// This unit raised to the integer power `n`.
+    ///
+    /// Overflowing an `i8` exponent panics in debug builds and wraps in release
+    /// builds; use [`checked_powi`](Self::checked_powi) for untrusted input.
+    #[inline]
+    pub fn powi(self, n: i8) -> Self {
+        Self::new(self.dimension.powi(n), powi_f64(self.ratio, n.into()))
+    }
+
+    /// Like `self * rhs`, but returns `None` if an exponent overflows `i8`.
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meter;
+    /// use qtty_core::DynUnit;
+    ///
+    /// let m100 = DynUnit::of::<Meter>().powi(100);
+    /// assert_eq!(m100.checked_mul(m100), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let dimension = self.dimension.checked_mul(rhs.dimension)?;
+        Some(Self::new(dimension, self.ratio * rhs.ratio))
+    }
+
+    /// Like `self / rhs`, but returns `None` if an exponent overflows `i8`.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let dimension = self.dimension.checked_div(rhs.dimension)?;
+        Some(Self::new(dimension, self.ratio / rhs.ratio))
+    }
+
+    /// Like [`powi`](Self::powi), but returns `None` if an exponent overflows
+    /// `i8`.
+    #[inline]
+    pub fn checked_powi(self, n: i8) -> Option<Self> {
+        let dimension = self.dimension.checked_powi(n)?;
+        Some(Self::new(dimension, powi_f64(self.ratio, n.into())))
+    }
+}
+
+/// Adds the exponents and multiplies the ratios.
+///
+/// Overflowing an `i8` exponent panics in debug builds and wraps in release
+/// builds; use [`DynUnit::checked_mul`] for untrusted input.
+impl Mul for DynUnit {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.dimension * rhs.dimension, self.ratio * rhs.ratio)
+    }
+}
+
+/// Subtracts the exponents and divides the ratios.
+///
+/// Overflows like `Mul`; use [`DynUnit::checked_div`] for untrusted input.
+impl Div for DynUnit {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::new(self.dimension / rhs.dimension, self.ratio / rhs.ratio)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Dynamic quantities
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Error returned when a runtime dimension does not match the expected one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    /// Dimension that was required.
+    pub expected: DimExponents,
+    /// Dimension that was found.
+    pub found: DimExponents,
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "dimension mismatch: expected {}, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DimensionMismatch {}
+
+/// A quantity whose unit (dimension exponents and ratio) is only known at runtime.
+///
+/// This is the dynamically checked counterpart of [`Quantity<U, S>`]: the
+/// value is stored in `unit`, and every operation that needs matching
+/// dimensions returns `Option`/`Result` instead of being rejected at compile
+/// time. Multiplication and division always succeed and combine exponents.
+///
+/// ```rust
+/// use qtty_core::length::Meters;
+/// use qtty_core::time::{Second, Seconds};
+/// use qtty_core::length::Meter;
+/// use qtty_core::velocity::Velocity;
+/// use qtty_core::DynQuantity;
+///
+/// let v = DynQuantity::from(Meters::new(100.0)) / DynQuantity::from(Seconds::new(20.0));
+/// let typed: Velocity<Meter, Second> = v.try_into().unwrap();
+/// assert_eq!(typed.value(), 5.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynQuantity<S: Real = f64> {
+    value: S,
+    unit: DynUnit,
+}
+
+impl<S: Real> DynQuantity<S> {
+    /// Creates a runtime quantity of `value` measured in `unit`.
+    #[inline]
+    pub const fn new(value: S, unit: DynUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Returns the raw value, expressed in [`unit`](Self::unit).
+    #[inline]
+    pub const fn value(&self) -> S {
+        self.value
+    }
+
+    /// Returns the runtime unit.
+    #[inline]
+    pub const fn unit(&self) -> DynUnit {
+        self.unit
+    }
+
+    /// Returns the dimension exponents of the unit.
+    #[inline]
+    pub const fn dimension(&self) -> DimExponents {
+        self.unit.dimension
+    }
+
+    /// Returns the scale ratio of the unit.
+    #[inline]
+    pub const fn ratio(&self) -> f64 {
+        self.unit.ratio
+    }
+
+    /// Converts to `unit`, or returns `None` if the dimensions differ.
+    #[inline]
+    pub fn to_unit(self, unit: DynUnit) -> Option<Self> {
+        let factor = self.unit.conversion_factor_to(&unit)?;
+        Some(Self::new(self.value * S::from_f64(factor), unit))
+    }
+
+    /// Converts into the typed unit `U`, checking the dimension at runtime.
+    ///
+    /// Equivalent to `Quantity::<U, S>::try_from(self)`.
+    #[inline]
+    pub fn to_quantity<U: Unit>(self) -> Result<Quantity<U, S>, DimensionMismatch> {
+        self.to_unit(DynUnit::of::<U>())
+            .map(|q| Quantity::new(q.value))
+            .ok_or(DimensionMismatch {
+                expected: <U::Dim as Dimension>::EXPONENTS,
+                found: self.dimension(),
+            })
+    }
+
+    /// Adds `rhs` (converted into `self`'s unit), or returns `None` if the
+    /// dimensions differ.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let rhs = rhs.to_unit(self.unit)?;
+        Some(Self::new(self.value + rhs.value, self.unit))
+    }
+
+    /// Subtracts `rhs` (converted into `self`'s unit), or returns `None` if the
+    /// dimensions differ.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let rhs = rhs.to_unit(self.unit)?;
+        Some(Self::new(self.value - rhs.value, self.unit))
+    }
+
+    /// Raises the quantity to the integer power `n`.
+    ///
+    /// Overflows like [`DynUnit::powi`]; see [`checked_powi`](Self::checked_powi).
+    #[inline]
+    pub fn powi(self, n: i8) -> Self {
+        Self::new(self.value.powi(n.into()), self.unit.powi(n))
+    }
+
+    /// Multiplies by `rhs`, or returns `None` if an exponent of the resulting
+    /// unit overflows `i8`.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let unit = self.unit.checked_mul(rhs.unit)?;
+        Some(Self::new(self.value * rhs.value, unit))
+    }
+
+    /// Divides by `rhs`, or returns `None` if an exponent of the resulting unit
+    /// overflows `i8`.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let unit = self.unit.checked_div(rhs.unit)?;
+        Some(Self::new(self.value / rhs.value, unit))
+    }
+
+    /// Raises the quantity to the integer power `n`, or returns `None` if an
+    /// exponent of the resulting unit overflows `i8`.
+    #[inline]
+    pub fn checked_powi(self, n: i8) -> Option<Self> {
+        let unit = self.unit.checked_powi(n)?;
+        Some(Self::new(self.value.powi(n.into()), unit))
+    }
+}
+
+impl<U: Unit, S: Real> From<Quantity<U, S>> for DynQuantity<S> {
+    #[inline]
+    fn from(q: Quantity<U, S>) -> Self {
+        Self::new(q.value(), DynUnit::of::<U>())
+    }
+}
+
+impl<U: Unit, S: Real> TryFrom<DynQuantity<S>> for Quantity<U, S> {
+    type Error = DimensionMismatch;
+
+    #[inline]
+    fn try_from(q: DynQuantity<S>) -> Result<Self, Self::Error> {
+        q.to_quantity()
+    }
+}
+
+/// Multiplies the values and combines the units with `DynUnit`'s `Mul`, which
+/// overflows on extreme exponents; see [`DynQuantity::checked_mul`].
+impl<S: Real> Mul for DynQuantity<S> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.value * rhs.value, self.unit * rhs.unit)
+    }
+}
+
+/// Divides the values and units; see [`DynQuantity::checked_div`] for the
+/// overflow-checked form.
+impl<S: Real> Div for DynQuantity<S> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::new(self.value / rhs.value, self.unit / rhs.unit)
+    }
+}
+
+impl<S: Real> Mul<S> for DynQuantity<S> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: S) -> Self {
+        Self::new(self.value * rhs, self.unit)
+    }
+}
+
+impl<S: Real> Div<S> for DynQuantity<S> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: S) -> Self {
+        Self::new(self.value / rhs, self.unit)
+    }
+}
+
+impl<S: Real> Neg for DynQuantity<S> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.value, self.unit)
+    }
+}
+
+/// Integer power by repeated squaring (`f64::powi` is not available in `core`).
+pub(crate) const fn powi_f64(base: f64, n: i32) -> f64 {
+    let mut result = 1.0;
+    let mut b = base;
+    let mut e = n.unsigned_abs();
+    while e > 0 {
+        if e & 1 == 1 {
+            result *= b;
+        }
+        b *= b;
+        e >>= 1;
+    }
+    if n < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::length::{Kilometer, Kilometers, Meter, Meters};
+    use crate::time::{Hour, Second, Seconds};
+    use crate::{Length, Per, Time};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn dyn_unit_of_composite() {
+        let u = DynUnit::of::<Per<Kilometer, Hour>>();
+        assert_eq!(u.dimension(), Length::EXPONENTS / Time::EXPONENTS);
+        assert_relative_eq!(u.ratio(), 1000.0 / 3600.0);
+        assert_eq!(
+            u.conversion_factor_to(&DynUnit::of::<Per<Meter, Second>>()),
+            Some(1000.0 / 3600.0)
+        );
+        assert_eq!(u.conversion_factor_to(&DynUnit::of::<Meter>()), None);
+    }
+
+    #[test]
+    fn powi_f64_matches_std() {
+        for n in -8..=8 {
+            assert_relative_eq!(powi_f64(1.7, n), 1.7f64.powi(n), max_relative = 1e-14);
+        }
+    }
+
+    #[test]
+    fn checked_add_converts_rhs_into_lhs_unit() {
+        let a = DynQuantity::from(Kilometers::new(1.0));
+        let b = DynQuantity::from(Meters::new(250.0));
+        let sum = a.checked_add(b).unwrap();
+        assert_eq!(sum.unit(), DynUnit::of::<Kilometer>());
+        assert_relative_eq!(sum.value(), 1.25);
+
+        let diff = b.checked_sub(a).unwrap();
+        assert_relative_eq!(diff.value(), -750.0);
+
+        assert!(a.checked_add(Seconds::new(1.0).into()).is_none());
+        assert!(a.checked_sub(Seconds::new(1.0).into()).is_none());
+    }
+
+    #[test]
+    fn mul_div_combine_exponents() {
+        let d = DynQuantity::from(Meters::new(6.0));
+        let t = DynQuantity::from(Seconds::new(2.0));
+        let v = d / t;
+        assert_eq!(
+            v.dimension(),
+            DimExponents::from_array([1, -1, 0, 0, 0, 0, 0, 0])
+        );
+        assert_relative_eq!(v.value(), 3.0);
+
+        let area = d * d;
+        assert_eq!(area.dimension(), Length::EXPONENTS.powi(2));
+        assert_eq!(d.powi(2), area);
+
+        let scaled = -(v * 2.0) / 4.0;
+        assert_relative_eq!(scaled.value(), -1.5);
+        assert_eq!(scaled.unit(), v.unit());
+    }
+
+    #[test]
+    fn checked_ops_report_exponent_overflow() {
+        let m100 = DynUnit::of::<Meter>().powi(100);
+        assert_eq!(m100.checked_mul(m100), None);
+        assert_eq!(
+            m100.checked_div(m100),
+            Some(DynUnit::new(DimExponents::DIMENSIONLESS, 1.0))
+        );
+        let m_min = DynUnit::of::<Meter>().powi(-128);
+        assert_eq!(m_min.checked_powi(-1), None);
+        assert_eq!(m_min.checked_div(DynUnit::of::<Meter>()), None);
+        assert_eq!(
+            DynUnit::of::<Meter>().checked_powi(3),
+            Some(DynUnit::of::<Meter>().powi(3))
+        );
+
+        let q = DynQuantity::new(2.0, m100);
+        assert!(q.checked_mul(q).is_none());
+        assert!(q.checked_powi(2).is_none());
+        let d = DynQuantity::from(Meters::new(6.0));
+        let t = DynQuantity::from(Seconds::new(2.0));
+        assert_eq!(d.checked_div(t), Some(d / t));
+        assert_eq!(d.checked_mul(d), Some(d * d));
+        assert_eq!(d.checked_powi(2), Some(d.powi(2)));
+    }
+
+    #[test]
+    fn try_from_checks_dimension() {
+        let q = DynQuantity::from(Kilometers::new(2.0));
+        let m: Meters = q.try_into().unwrap();
+        assert_relative_eq!(m.value(), 2000.0);
+
+        let err = Quantity::<Second>::try_from(q).unwrap_err();
+        assert_eq!(
+            err,
+            DimensionMismatch {
+                expected: Time::EXPONENTS,
+                found: Length::EXPONENTS,
+            }
+        );
+        assert_eq!(err.to_string(), "dimension mismatch: expected T, found L");
+    }
+
+    #[test]
+    fn f32_scalar() {
+        let q: DynQuantity<f32> = Quantity::<Kilometer, f32>::new(1.5).into();
+        let m: Quantity<Meter, f32> = q.to_quantity().unwrap();
+        assert_relative_eq!(m.value(), 1500.0);
+    }
+}
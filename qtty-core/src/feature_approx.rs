@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `approx` support for `Quantity` types (feature-gated).
+//!
+//! This module is enabled by the `approx` feature. It implements
+//! [`AbsDiffEq`], [`RelativeEq`] and [`UlpsEq`] for `Quantity<U, S>` by
+//! delegating to the scalar, so the `approx` assertion macros accept
+//! quantities directly. Tolerances are raw scalars: `epsilon` is read in unit
+//! `U` and `max_relative` is a plain ratio.
+//!
+//! ```rust
+//! use approx::assert_relative_eq;
+//! use qtty_core::length::Meters;
+//!
+//! assert_relative_eq!(Meters::new(0.1 + 0.2), Meters::new(0.3));
+//! assert_relative_eq!(Meters::new(1.0), Meters::new(1.001), epsilon = 0.01);
+//! ```
+//!
+//! Both sides must share a unit. To compare quantities in different units of
+//! the same dimension, use [`assert_qty_eq!`](crate::assert_qty_eq).
+
+use crate::scalar::Scalar;
+use crate::{Quantity, Unit};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl<U: Unit, S: Scalar + AbsDiffEq> AbsDiffEq for Quantity<U, S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        self.value().abs_diff_eq(&other.value(), epsilon)
+    }
+}
+
+impl<U: Unit, S: Scalar + RelativeEq> RelativeEq for Quantity<U, S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        self.value()
+            .relative_eq(&other.value(), epsilon, max_relative)
+    }
+}
+
+impl<U: Unit, S: Scalar + UlpsEq> UlpsEq for Quantity<U, S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        self.value().ulps_eq(&other.value(), epsilon, max_ulps)
+    }
+}
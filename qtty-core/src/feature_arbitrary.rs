@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `arbitrary` support for `Quantity` types (feature-gated).
+//!
+//! This module is enabled by the `arbitrary` feature. `Quantity<U, S>`
+//! implements [`Arbitrary`] whenever `S` does, so fuzz targets can take
+//! quantities (or structs deriving `Arbitrary` that contain them) directly.
+//! The raw scalar is generated as-is, so floating-point quantities may be NaN
+//! or infinite.
+
+use crate::scalar::Scalar;
+use crate::{Quantity, Unit};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a, U: Unit, S: Scalar + Arbitrary<'a>> Arbitrary<'a> for Quantity<U, S> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        S::arbitrary(u).map(Quantity::new)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        S::size_hint(depth)
+    }
+}
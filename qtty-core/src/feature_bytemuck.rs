@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `bytemuck` support for `Quantity` types (feature-gated).
+//!
+//! This module is enabled by the `bytemuck` feature. `Quantity<U, S>` is
+//! `#[repr(transparent)]` over `S`, so it is [`Zeroable`] and [`Pod`]
+//! whenever `S` is, and it is a [`TransparentWrapper`] of `S`. Raw buffers can
+//! then be cast to quantity slices without `unsafe` in user code:
+//!
+//! ```rust
+//! use qtty_core::length::Meters;
+//!
+//! let bytes: [u8; 16] = bytemuck::cast([1.5_f64, -2.0]);
+//! let distances: &[Meters] = bytemuck::cast_slice(&bytes);
+//! assert_eq!(distances[1], Meters::new(-2.0));
+//! ```
+
+#![allow(unsafe_code)]
+
+use crate::scalar::Scalar;
+use crate::{Quantity, Unit};
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+// SAFETY: `Quantity<U, S>` is `repr(transparent)` over `S` plus a zero-sized
+// `PhantomData<U>`, so the all-zero bit pattern is valid whenever it is valid
+// for `S`.
+unsafe impl<U: Unit, S: Scalar + Zeroable> Zeroable for Quantity<U, S> {}
+
+// SAFETY: same layout as `S` (no padding, every bit pattern of `S` is a valid
+// quantity), and `Quantity` is `Copy + 'static` when `S: Pod`.
+unsafe impl<U: Unit, S: Scalar + Pod> Pod for Quantity<U, S> {}
+
+// SAFETY: `Quantity<U, S>` is `repr(transparent)` with `S` as its only
+// non-zero-sized field.
+unsafe impl<U: Unit, S: Scalar> TransparentWrapper<S> for Quantity<U, S> {}
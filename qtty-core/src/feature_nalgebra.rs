@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `nalgebra` support: typed 3D vectors (feature-gated).
+//!
+//! This module is enabled by the `nalgebra` feature. [`QVector3<U>`] wraps a
+//! `nalgebra::Vector3<f64>` whose components are values in unit `U`.
+//! Dimensionless transforms (`Matrix3`, `Rotation3`, `UnitQuaternion`) apply
+//! to it directly, and products go through [`UnitMul`].
+//!
+//! ```rust
+//! use nalgebra::{Rotation3, Vector3};
+//! use qtty_core::length::{Kilometer, Meter, Meters};
+//! use qtty_core::QVector3;
+//!
+//! let r = QVector3::<Meter>::from_vector(Vector3::new(1.0, 0.0, 0.0));
+//! let rotated = Rotation3::from_axis_angle(&Vector3::z_axis(), core::f64::consts::FRAC_PI_2) * r;
+//! assert!((rotated.y().value() - 1.0).abs() < 1e-12);
+//! assert!((rotated.norm() - Meters::new(1.0)).abs().value() < 1e-12);
+//! assert_eq!(r.to::<Kilometer>().x().value(), 0.001);
+//! ```
+
+use crate::unit_arithmetic::UnitMul;
+use crate::vector::QuantityVector3;
+use crate::{Quantity, Unit};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use nalgebra::{Matrix3, Rotation3, UnitQuaternion, UnitVector3, Vector3};
+
+/// A `nalgebra::Vector3<f64>` whose components are in unit `U`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct QVector3<U: Unit> {
+    vector: Vector3<f64>,
+    _unit: PhantomData<U>,
+}
+
+impl<U: Unit> QVector3<U> {
+    /// Creates a vector from its components.
+    #[inline]
+    pub fn new(x: Quantity<U>, y: Quantity<U>, z: Quantity<U>) -> Self {
+        Self::from_vector(Vector3::new(x.value(), y.value(), z.value()))
+    }
+
+    /// Wraps a raw vector whose components are in unit `U`.
+    #[inline]
+    pub const fn from_vector(vector: Vector3<f64>) -> Self {
+        Self {
+            vector,
+            _unit: PhantomData,
+        }
+    }
+
+    /// The zero vector.
+    #[inline]
+    pub fn zeros() -> Self {
+        Self::from_vector(Vector3::zeros())
+    }
+
+    /// The raw vector, in unit `U`.
+    #[inline]
+    pub const fn as_vector(&self) -> &Vector3<f64> {
+        &self.vector
+    }
+
+    /// Unwraps the raw vector, in unit `U`.
+    #[inline]
+    pub const fn into_vector(self) -> Vector3<f64> {
+        self.vector
+    }
+
+    /// The x component.
+    #[inline]
+    pub fn x(&self) -> Quantity<U> {
+        Quantity::new(self.vector.x)
+    }
+
+    /// The y component.
+    #[inline]
+    pub fn y(&self) -> Quantity<U> {
+        Quantity::new(self.vector.y)
+    }
+
+    /// The z component.
+    #[inline]
+    pub fn z(&self) -> Quantity<U> {
+        Quantity::new(self.vector.z)
+    }
+
+    /// Euclidean length, in unit `U`.
+    #[inline]
+    pub fn norm(&self) -> Quantity<U> {
+        Quantity::new(self.vector.norm())
+    }
+
+    /// Dot product, in the unit of `Quantity<U> * Quantity<V>`.
+    #[inline]
+    pub fn dot<V: Unit>(&self, rhs: &QVector3<V>) -> Quantity<<U as UnitMul<V>>::Output>
+    where
+        U: UnitMul<V>,
+    {
+        Quantity::new(self.vector.dot(&rhs.vector))
+    }
+
+    /// Cross product, in the unit of `Quantity<U> * Quantity<V>`.
+    #[inline]
+    pub fn cross<V: Unit>(&self, rhs: &QVector3<V>) -> QVector3<<U as UnitMul<V>>::Output>
+    where
+        U: UnitMul<V>,
+    {
+        QVector3::from_vector(self.vector.cross(&rhs.vector))
+    }
+
+    /// The dimensionless unit vector in the direction of `self`, or `None`
+    /// when its norm is at most `min_norm`.
+    ///
+    /// Pass a zero `min_norm` to reject only the exact zero vector; a
+    /// tolerance keeps near-zero vectors from normalising to noise.
+    #[inline]
+    pub fn direction(&self, min_norm: Quantity<U>) -> Option<UnitVector3<f64>> {
+        UnitVector3::try_new(self.vector, min_norm.value())
+    }
+
+    /// Converts every component to unit `T`.
+    #[inline]
+    pub fn to<T: Unit<Dim = U::Dim>>(&self) -> QVector3<T> {
+        QVector3::from_vector(self.vector * (U::RATIO / T::RATIO))
+    }
+}
+
+impl<U: Unit> core::fmt::Debug for QVector3<U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QVector3")
+            .field("unit", &U::SYMBOL)
+            .field("vector", &self.vector)
+            .finish()
+    }
+}
+
+impl<U: Unit> From<QuantityVector3<U>> for QVector3<U> {
+    #[inline]
+    fn from(vector: QuantityVector3<U>) -> Self {
+        Self::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl<U: Unit> From<QVector3<U>> for QuantityVector3<U> {
+    #[inline]
+    fn from(vector: QVector3<U>) -> Self {
+        QuantityVector3::new(vector.x(), vector.y(), vector.z())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Arithmetic
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<U: Unit> Add for QVector3<U> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_vector(self.vector + rhs.vector)
+    }
+}
+
+impl<U: Unit> AddAssign for QVector3<U> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.vector += rhs.vector;
+    }
+}
+
+impl<U: Unit> Sub for QVector3<U> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_vector(self.vector - rhs.vector)
+    }
+}
+
+impl<U: Unit> SubAssign for QVector3<U> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.vector -= rhs.vector;
+    }
+}
+
+impl<U: Unit> Neg for QVector3<U> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_vector(-self.vector)
+    }
+}
+
+impl<U: Unit> Mul<f64> for QVector3<U> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::from_vector(self.vector * rhs)
+    }
+}
+
+impl<U: Unit> Div<f64> for QVector3<U> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self::from_vector(self.vector / rhs)
+    }
+}
+
+impl<A: Unit, B: Unit> Mul<Quantity<B>> for QVector3<A>
+where
+    A: UnitMul<B>,
+{
+    type Output = QVector3<<A as UnitMul<B>>::Output>;
+
+    #[inline]
+    fn mul(self, rhs: Quantity<B>) -> Self::Output {
+        QVector3::from_vector(self.vector * rhs.value())
+    }
+}
+
+impl<U: Unit> Mul<Quantity<U>> for UnitVector3<f64> {
+    type Output = QVector3<U>;
+
+    /// Scales a direction by a magnitude.
+    #[inline]
+    fn mul(self, rhs: Quantity<U>) -> QVector3<U> {
+        QVector3::from_vector(self.into_inner() * rhs.value())
+    }
+}
+
+/// Applies a dimensionless linear map to each kind of typed vector.
+macro_rules! impl_dimensionless_transform {
+    ($($transform:ty),+ $(,)?) => {
+        $(
+            impl<U: Unit> Mul<QVector3<U>> for $transform {
+                type Output = QVector3<U>;
+
+                #[inline]
+                fn mul(self, rhs: QVector3<U>) -> QVector3<U> {
+                    QVector3::from_vector(self * rhs.vector)
+                }
+            }
+        )+
+    };
+}
+
+impl_dimensionless_transform!(Matrix3<f64>, Rotation3<f64>, UnitQuaternion<f64>);
@@ -0,0 +1,345 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `ndarray` support: arrays of quantities with the unit at the type level
+//! (feature-gated).
+//!
+//! This module is enabled by the `ndarray` feature. [`QuantityArray<U, D>`]
+//! wraps an `ndarray::Array<f64, D>` whose elements are values in unit `U`, so
+//! arithmetic runs on the plain `f64` array while the unit is tracked by the
+//! type system.
+//!
+//! ```rust
+//! use ndarray::array;
+//! use qtty_core::length::{Kilometer, Meter, Meters};
+//! use qtty_core::time::Second;
+//! use qtty_core::velocity::Velocity;
+//! use qtty_core::QuantityArray;
+//!
+//! let d = QuantityArray::<Meter>::from_array(array![100.0, 200.0, 300.0]);
+//! let t = QuantityArray::<Second>::from_array(array![10.0, 20.0, 20.0]);
+//!
+//! let v = &d / &t;
+//! assert_eq!(v[2], Velocity::<Meter, Second>::new(15.0));
+//! assert_eq!(d.sum(), Meters::new(600.0));
+//! assert_eq!(d.to::<Kilometer>().as_array(), &array![0.1, 0.2, 0.3]);
+//! ```
+
+use crate::slice::{from_values, from_values_mut};
+use crate::unit_arithmetic::{SameDivOutput, UnitDiv, UnitMul};
+use crate::{Quantity, Unit};
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
+use ndarray::{Array, Dimension, Ix1, NdIndex};
+
+/// An n-dimensional array of quantities in unit `U`, stored as a plain
+/// `ndarray::Array<f64, D>`.
+///
+/// Element-wise operators between arrays follow `ndarray`'s rules and panic
+/// when the shapes cannot be broadcast together.
+pub struct QuantityArray<U: Unit, D: Dimension = Ix1> {
+    values: Array<f64, D>,
+    _unit: PhantomData<U>,
+}
+
+impl<U: Unit, D: Dimension> QuantityArray<U, D> {
+    /// Wraps raw values in unit `U`, without copying.
+    #[inline]
+    pub fn from_array(values: Array<f64, D>) -> Self {
+        Self {
+            values,
+            _unit: PhantomData,
+        }
+    }
+
+    /// An array of zero quantities with the given shape.
+    #[inline]
+    pub fn zeros<Sh: ndarray::ShapeBuilder<Dim = D>>(shape: Sh) -> Self {
+        Self::from_array(Array::zeros(shape))
+    }
+
+    /// Unwraps the raw values in unit `U`, without copying.
+    #[inline]
+    pub fn into_array(self) -> Array<f64, D> {
+        self.values
+    }
+
+    /// The raw values in unit `U`.
+    #[inline]
+    pub fn as_array(&self) -> &Array<f64, D> {
+        &self.values
+    }
+
+    /// The raw values in unit `U`, mutably.
+    #[inline]
+    pub fn as_array_mut(&mut self) -> &mut Array<f64, D> {
+        &mut self.values
+    }
+
+    /// The shape of the array.
+    #[inline]
+    pub fn shape(&self) -> &[usize] {
+        self.values.shape()
+    }
+
+    /// The number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the array has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get<I: NdIndex<D>>(&self, index: I) -> Option<Quantity<U>> {
+        self.values.get(index).copied().map(Quantity::new)
+    }
+
+    /// Iterates over the elements in logical order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Quantity<U>> + '_ {
+        self.values.iter().copied().map(Quantity::new)
+    }
+
+    /// Converts every element to unit `T`, in place.
+    #[inline]
+    pub fn to<T: Unit<Dim = U::Dim>>(mut self) -> QuantityArray<T, D> {
+        let factor = U::RATIO / T::RATIO;
+        if factor != 1.0 {
+            self.values *= factor;
+        }
+        QuantityArray::from_array(self.values)
+    }
+
+    /// Sum of all elements (zero for an empty array).
+    #[inline]
+    pub fn sum(&self) -> Quantity<U> {
+        Quantity::new(self.values.sum())
+    }
+
+    /// Arithmetic mean of all elements, or `None` for an empty array.
+    #[inline]
+    pub fn mean(&self) -> Option<Quantity<U>> {
+        self.values.mean().map(Quantity::new)
+    }
+
+    /// Applies `f` to every element, producing an array in unit `T`.
+    #[inline]
+    pub fn map<T: Unit>(
+        &self,
+        mut f: impl FnMut(Quantity<U>) -> Quantity<T>,
+    ) -> QuantityArray<T, D> {
+        QuantityArray::from_array(self.values.mapv(|v| f(Quantity::new(v)).value()))
+    }
+}
+
+impl<U: Unit> QuantityArray<U, Ix1> {
+    /// Builds a one-dimensional array from a vector of quantities.
+    #[inline]
+    pub fn from_quantities(quantities: impl IntoIterator<Item = Quantity<U>>) -> Self {
+        Self::from_array(quantities.into_iter().map(Quantity::value).collect())
+    }
+}
+
+impl<U: Unit, D: Dimension> Clone for QuantityArray<U, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_array(self.values.clone())
+    }
+}
+
+impl<U: Unit, D: Dimension> PartialEq for QuantityArray<U, D> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl<U: Unit, D: Dimension> core::fmt::Debug for QuantityArray<U, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QuantityArray")
+            .field("unit", &U::SYMBOL)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<U: Unit, D: Dimension, I: NdIndex<D>> Index<I> for QuantityArray<U, D> {
+    type Output = Quantity<U>;
+
+    /// Returns the element at `index`, panicking if it is out of bounds.
+    #[inline]
+    fn index(&self, index: I) -> &Quantity<U> {
+        &from_values(core::slice::from_ref(&self.values[index]))[0]
+    }
+}
+
+impl<U: Unit, D: Dimension, I: NdIndex<D>> IndexMut<I> for QuantityArray<U, D> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Quantity<U> {
+        &mut from_values_mut(core::slice::from_mut(&mut self.values[index]))[0]
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Arithmetic
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Maps a [`UnitDiv::Output`] token to the result of dividing two
+/// [`QuantityArray`]s: a plain `Array<f64, D>` when the units cancel, like
+/// [`QuantityDivOutput`](crate::QuantityDivOutput) does for quantities.
+#[doc(hidden)]
+pub trait ArrayDivOutput<D: Dimension> {
+    /// The type that `Div` returns.
+    type Output;
+    /// Wraps (or passes through) the raw quotient.
+    fn wrap(values: Array<f64, D>) -> Self::Output;
+}
+
+impl<D: Dimension> ArrayDivOutput<D> for SameDivOutput {
+    type Output = Array<f64, D>;
+    #[inline]
+    fn wrap(values: Array<f64, D>) -> Array<f64, D> {
+        values
+    }
+}
+
+impl<U: Unit, D: Dimension> ArrayDivOutput<D> for U {
+    type Output = QuantityArray<U, D>;
+    #[inline]
+    fn wrap(values: Array<f64, D>) -> QuantityArray<U, D> {
+        QuantityArray::from_array(values)
+    }
+}
+
+impl<U: Unit, D: Dimension> Add for QuantityArray<U, D> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_array(self.values + rhs.values)
+    }
+}
+
+impl<U: Unit, D: Dimension> Add for &QuantityArray<U, D> {
+    type Output = QuantityArray<U, D>;
+
+    #[inline]
+    fn add(self, rhs: Self) -> QuantityArray<U, D> {
+        QuantityArray::from_array(&self.values + &rhs.values)
+    }
+}
+
+impl<U: Unit, D: Dimension> Sub for QuantityArray<U, D> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_array(self.values - rhs.values)
+    }
+}
+
+impl<U: Unit, D: Dimension> Sub for &QuantityArray<U, D> {
+    type Output = QuantityArray<U, D>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> QuantityArray<U, D> {
+        QuantityArray::from_array(&self.values - &rhs.values)
+    }
+}
+
+impl<U: Unit, D: Dimension> Neg for QuantityArray<U, D> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_array(-self.values)
+    }
+}
+
+impl<U: Unit, D: Dimension> Mul<f64> for QuantityArray<U, D> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self {
+        Self::from_array(self.values * rhs)
+    }
+}
+
+impl<U: Unit, D: Dimension> Div<f64> for QuantityArray<U, D> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Self {
+        Self::from_array(self.values / rhs)
+    }
+}
+
+impl<A: Unit, B: Unit, D: Dimension> Mul<&QuantityArray<B, D>> for &QuantityArray<A, D>
+where
+    A: UnitMul<B>,
+{
+    type Output = QuantityArray<<A as UnitMul<B>>::Output, D>;
+
+    #[inline]
+    fn mul(self, rhs: &QuantityArray<B, D>) -> Self::Output {
+        QuantityArray::from_array(&self.values * &rhs.values)
+    }
+}
+
+impl<A: Unit, B: Unit, D: Dimension> Mul<Quantity<B>> for QuantityArray<A, D>
+where
+    A: UnitMul<B>,
+{
+    type Output = QuantityArray<<A as UnitMul<B>>::Output, D>;
+
+    #[inline]
+    fn mul(self, rhs: Quantity<B>) -> Self::Output {
+        QuantityArray::from_array(self.values * rhs.value())
+    }
+}
+
+impl<N: Unit, M: Unit, D: Dimension> Div<&QuantityArray<M, D>> for &QuantityArray<N, D>
+where
+    N: UnitDiv<M>,
+    <N as UnitDiv<M>>::Output: ArrayDivOutput<D>,
+{
+    type Output = <<N as UnitDiv<M>>::Output as ArrayDivOutput<D>>::Output;
+
+    #[inline]
+    fn div(self, rhs: &QuantityArray<M, D>) -> Self::Output {
+        <<N as UnitDiv<M>>::Output as ArrayDivOutput<D>>::wrap(&self.values / &rhs.values)
+    }
+}
+
+impl<N: Unit, M: Unit, D: Dimension> Div<Quantity<M>> for QuantityArray<N, D>
+where
+    N: UnitDiv<M>,
+    <N as UnitDiv<M>>::Output: ArrayDivOutput<D>,
+{
+    type Output = <<N as UnitDiv<M>>::Output as ArrayDivOutput<D>>::Output;
+
+    #[inline]
+    fn div(self, rhs: Quantity<M>) -> Self::Output {
+        <<N as UnitDiv<M>>::Output as ArrayDivOutput<D>>::wrap(self.values / rhs.value())
+    }
+}
+
+impl<U: Unit, D: Dimension> From<Array<f64, D>> for QuantityArray<U, D> {
+    #[inline]
+    fn from(values: Array<f64, D>) -> Self {
+        Self::from_array(values)
+    }
+}
+
+impl<U: Unit> FromIterator<Quantity<U>> for QuantityArray<U, Ix1> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Quantity<U>>>(iter: I) -> Self {
+        Self::from_quantities(iter)
+    }
+}
@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `rand` support: sampling quantities directly (feature-gated).
+//!
+//! This module is enabled by the `rand` feature. It provides:
+//!
+//! - [`SampleUniform`] for `Quantity<U, S>`, so `Rng::gen_range` and
+//!   [`Uniform`](rand::distributions::Uniform) accept ranges of quantities.
+//! - [`QuantityNormal`] and [`QuantityLogNormal`], normal and log-normal
+//!   distributions parameterised by a quantity mean and standard deviation.
+//! - [`UniformDirection`](crate::angular::UniformDirection), directions
+//!   uniformly distributed on the unit sphere.
+//!
+//! ```rust
+//! use qtty_core::length::{Kilometers, Meters};
+//! use qtty_core::QuantityNormal;
+//! use rand::distributions::Distribution;
+//! use rand::Rng;
+//!
+//! let mut rng = rand::thread_rng();
+//! let d = rng.gen_range(Meters::new(0.0)..Kilometers::new(1.0).to());
+//! assert!(d >= Meters::new(0.0) && d < Meters::new(1_000.0));
+//!
+//! let noise = QuantityNormal::new(Meters::new(0.0), Meters::new(0.1)).unwrap();
+//! let sample: Meters = noise.sample(&mut rng);
+//! assert!(sample.value().is_finite());
+//! ```
+
+use crate::scalar::{Scalar, Transcendental};
+use crate::units::angular::Radians;
+use crate::{Quantity, Unit};
+use core::f64::consts::TAU;
+use core::fmt;
+use core::marker::PhantomData;
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::Distribution;
+use rand::Rng;
+use rand_distr::{LogNormal, Normal, NormalError};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Uniform sampling
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The [`UniformSampler`] backing `Uniform<Quantity<U, S>>`: the scalar's own
+/// sampler with the unit attached to every sample.
+pub struct UniformQuantity<U: Unit, S: SampleUniform> {
+    inner: S::Sampler,
+    _unit: PhantomData<U>,
+}
+
+impl<U: Unit, S: SampleUniform> UniformQuantity<U, S> {
+    #[inline]
+    fn wrap(inner: S::Sampler) -> Self {
+        Self {
+            inner,
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<U: Unit, S: SampleUniform> Clone for UniformQuantity<U, S>
+where
+    S::Sampler: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::wrap(self.inner.clone())
+    }
+}
+
+impl<U: Unit, S: SampleUniform> Copy for UniformQuantity<U, S> where S::Sampler: Copy {}
+
+impl<U: Unit, S: SampleUniform> fmt::Debug for UniformQuantity<U, S>
+where
+    S::Sampler: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UniformQuantity")
+            .field("unit", &U::SYMBOL)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<U: Unit, S: Scalar + SampleUniform> UniformSampler for UniformQuantity<U, S> {
+    type X = Quantity<U, S>;
+
+    #[inline]
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self::wrap(S::Sampler::new(low.borrow().value(), high.borrow().value()))
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self::wrap(S::Sampler::new_inclusive(
+            low.borrow().value(),
+            high.borrow().value(),
+        ))
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Quantity::new(self.inner.sample(rng))
+    }
+}
+
+impl<U: Unit, S: Scalar + SampleUniform> SampleUniform for Quantity<U, S> {
+    type Sampler = UniformQuantity<U, S>;
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Normal and log-normal
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Rejects negative or NaN standard deviations, which `rand_distr` would
+/// otherwise accept.
+#[inline]
+fn check_std_dev(std_dev: f64) -> Result<(), NormalError> {
+    if std_dev.is_nan() || std_dev < 0.0 {
+        return Err(NormalError::BadVariance);
+    }
+    Ok(())
+}
+
+/// Normal distribution over `Quantity<U>`, with mean and standard deviation
+/// given in unit `U`.
+#[derive(Clone, Copy, Debug)]
+pub struct QuantityNormal<U: Unit> {
+    inner: Normal<f64>,
+    _unit: PhantomData<U>,
+}
+
+impl<U: Unit> QuantityNormal<U> {
+    /// Creates the distribution `N(mean, std_dev²)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NormalError`] if `std_dev` is negative or not finite.
+    #[inline]
+    pub fn new(mean: Quantity<U>, std_dev: Quantity<U>) -> Result<Self, NormalError> {
+        check_std_dev(std_dev.value())?;
+        Ok(Self {
+            inner: Normal::new(mean.value(), std_dev.value())?,
+            _unit: PhantomData,
+        })
+    }
+
+    /// The mean.
+    #[inline]
+    pub fn mean(&self) -> Quantity<U> {
+        Quantity::new(self.inner.mean())
+    }
+
+    /// The standard deviation.
+    #[inline]
+    pub fn std_dev(&self) -> Quantity<U> {
+        Quantity::new(self.inner.std_dev())
+    }
+}
+
+impl<U: Unit> Distribution<Quantity<U>> for QuantityNormal<U> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quantity<U> {
+        Quantity::new(self.inner.sample(rng))
+    }
+}
+
+/// Log-normal distribution over strictly positive `Quantity<U>`, with the
+/// mean and standard deviation of the samples given in unit `U`.
+#[derive(Clone, Copy, Debug)]
+pub struct QuantityLogNormal<U: Unit> {
+    inner: LogNormal<f64>,
+    mean: f64,
+    std_dev: f64,
+    _unit: PhantomData<U>,
+}
+
+impl<U: Unit> QuantityLogNormal<U> {
+    /// Creates the log-normal distribution whose samples have the given mean
+    /// and standard deviation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NormalError`] if `mean` is not strictly positive or
+    /// `std_dev` is negative or not finite.
+    #[inline]
+    pub fn new(mean: Quantity<U>, std_dev: Quantity<U>) -> Result<Self, NormalError> {
+        let (mean, std_dev) = (mean.value(), std_dev.value());
+        if mean.is_nan() || mean <= 0.0 {
+            return Err(NormalError::MeanTooSmall);
+        }
+        check_std_dev(std_dev)?;
+        Ok(Self {
+            inner: LogNormal::from_mean_cv(mean, std_dev / mean)?,
+            mean,
+            std_dev,
+            _unit: PhantomData,
+        })
+    }
+
+    /// The mean of the samples.
+    #[inline]
+    pub fn mean(&self) -> Quantity<U> {
+        Quantity::new(self.mean)
+    }
+
+    /// The standard deviation of the samples.
+    #[inline]
+    pub fn std_dev(&self) -> Quantity<U> {
+        Quantity::new(self.std_dev)
+    }
+}
+
+impl<U: Unit> Distribution<Quantity<U>> for QuantityLogNormal<U> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quantity<U> {
+        Quantity::new(self.inner.sample(rng))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Directions on the sphere
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Uniformly distributed directions on the unit sphere, sampled as
+/// `(longitude, latitude)`.
+///
+/// The longitude lies in `[0, 2π)` and the latitude in `[-π/2, π/2]`; the
+/// latitude is drawn as `asin(z)` with `z` uniform in `[-1, 1]`, so equal
+/// areas on the sphere are equally likely.
+///
+/// ```rust
+/// use qtty_core::angular::{Radians, UniformDirection};
+/// use rand::distributions::Distribution;
+///
+/// let (lon, lat): (Radians, Radians) = UniformDirection.sample(&mut rand::thread_rng());
+/// assert!(lon.value() >= 0.0 && lon.value() < core::f64::consts::TAU);
+/// assert!(lat.value().abs() <= core::f64::consts::FRAC_PI_2);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UniformDirection;
+
+impl Distribution<(Radians, Radians)> for UniformDirection {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (Radians, Radians) {
+        let lon = rng.gen_range(0.0..TAU);
+        let z: f64 = rng.gen_range(-1.0..=1.0);
+        (Radians::new(lon), Radians::new(Transcendental::asin(z)))
+    }
+}
@@ -13,12 +13,13 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 // Default serde: serialize the raw scalar value directly.
 // For `Real` types (f64, f32) this serializes the float;
 // for integer types (i8..i128) it serializes the integer.
+// `half::f16`/`half::bf16` are written as numbers through `f32` (see `half_serde`).
 impl<U: Unit, S: Scalar + Serialize> Serialize for Quantity<U, S> {
     fn serialize<Ser>(&self, serializer: Ser) -> core::result::Result<Ser::Ok, Ser::Error>
     where
         Ser: Serializer,
     {
-        self.value().serialize(serializer)
+        serialize_scalar(self.value_ref(), serializer)
     }
 }
 
@@ -27,8 +28,69 @@ impl<'de, U: Unit, S: Scalar + Deserialize<'de>> Deserialize<'de> for Quantity<U
     where
         D: Deserializer<'de>,
     {
-        let value = S::deserialize(deserializer)?;
-        Ok(Quantity::new(value))
+        deserialize_scalar(deserializer).map(Quantity::new)
+    }
+}
+
+fn serialize_scalar<S, Ser>(value: &S, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+where
+    S: Scalar + Serialize,
+    Ser: Serializer,
+{
+    #[cfg(feature = "half")]
+    if let Some(value) = half_serde::to_f32(value) {
+        return serializer.serialize_f32(value);
+    }
+    value.serialize(serializer)
+}
+
+fn deserialize_scalar<'de, S, D>(deserializer: D) -> Result<S, D::Error>
+where
+    S: Scalar + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    #[cfg(feature = "half")]
+    if half_serde::is_half::<S>() {
+        let value = f32::deserialize(deserializer)?;
+        return Ok(half_serde::from_f32(value).expect("`S` is a half type"));
+    }
+    S::deserialize(deserializer)
+}
+
+/// Numeric encoding of the half-precision scalars.
+///
+/// `half`'s own serde impls (which the `serde` feature has to enable so that
+/// `f16: Serialize` satisfies the bounds above) write the raw bits, so `1.5`
+/// would come out as `15872`. Quantities are data, not bit patterns: the value
+/// goes through `f32` instead, which represents every `f16` and `bf16` exactly.
+#[cfg(feature = "half")]
+mod half_serde {
+    use crate::scalar::Scalar;
+    use core::any::{Any, TypeId};
+    use half::{bf16, f16};
+
+    pub(super) fn is_half<S: Scalar>() -> bool {
+        let id = TypeId::of::<S>();
+        id == TypeId::of::<f16>() || id == TypeId::of::<bf16>()
+    }
+
+    pub(super) fn to_f32<S: Scalar>(value: &S) -> Option<f32> {
+        let value: &dyn Any = value;
+        value
+            .downcast_ref::<f16>()
+            .map(|v| v.to_f32())
+            .or_else(|| value.downcast_ref::<bf16>().map(|v| v.to_f32()))
+    }
+
+    pub(super) fn from_f32<S: Scalar>(value: f32) -> Option<S> {
+        let mut slot: Option<S> = None;
+        let any: &mut dyn Any = &mut slot;
+        if let Some(slot) = any.downcast_mut::<Option<f16>>() {
+            *slot = Some(f16::from_f32(value));
+        } else if let Some(slot) = any.downcast_mut::<Option<bf16>>() {
+            *slot = Some(bf16::from_f32(value));
+        }
+        slot
     }
 }
 
@@ -63,7 +125,7 @@ pub mod serde_scalar {
         S: Scalar + Serialize,
         Ser: Serializer,
     {
-        quantity.value_ref().serialize(serializer)
+        serialize_scalar(quantity.value_ref(), serializer)
     }
 
     /// Deserializes the quantity from a scalar value directly.
@@ -74,8 +136,7 @@ pub mod serde_scalar {
         S: Scalar + Deserialize<'de>,
         D: Deserializer<'de>,
     {
-        let value = S::deserialize(deserializer)?;
-        Ok(Quantity::new(value))
+        deserialize_scalar(deserializer).map(Quantity::new)
     }
 }
 
@@ -107,6 +168,7 @@ pub mod serde_with_unit {
 
     use super::*;
     use crate::scalar::Real;
+    use crate::UnitSymbol;
     use serde::de::{self, Deserializer, MapAccess, Visitor};
     use serde::ser::{SerializeStruct, Serializer};
 
@@ -127,14 +189,17 @@ pub mod serde_with_unit {
     {
         let mut state = serializer.serialize_struct("Quantity", 2)?;
         state.serialize_field("value", &quantity.value().to_f64())?;
-        state.serialize_field("unit", U::SYMBOL)?;
+        state.serialize_field("unit", &format!("{}", UnitSymbol::<U>::new()))?;
         state.end()
     }
 
     /// Deserializes a `Quantity<U, S>` from a struct with `value` and optionally `unit` fields.
     ///
     /// The `unit` field is validated if present but not required for backwards compatibility.
-    /// If provided and doesn't match `U::SYMBOL`, an error is returned.
+    /// If provided and doesn't match the symbol of `U` (as rendered by
+    /// [`Unit::fmt_symbol`], so composite units are checked too), an error is
+    /// returned. The legacy [`Unit::SYMBOL`] written by earlier versions (an
+    /// empty string for composite units) is also accepted.
     pub fn deserialize<'de, U, S, D>(deserializer: D) -> Result<Quantity<U, S>, D::Error>
     where
         U: Unit,
@@ -183,13 +248,15 @@ pub mod serde_with_unit {
 
                 let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
 
-                // Validate unit if provided (optional for backwards compatibility)
+                // Validate unit if provided (optional for backwards compatibility).
+                // Older versions wrote `U::SYMBOL`, which is empty for
+                // `Per`/`Prod`/`Pow`, so that form is still accepted.
                 if let Some(ref unit_str) = unit {
-                    if unit_str != U::SYMBOL {
+                    let expected = format!("{}", UnitSymbol::<U>::new());
+                    if *unit_str != expected && unit_str != U::SYMBOL {
                         return Err(de::Error::custom(format!(
                             "unit mismatch: expected '{}', found '{}'",
-                            U::SYMBOL,
-                            unit_str
+                            expected, unit_str
                         )));
                     }
                 }
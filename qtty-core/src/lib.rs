@@ -68,14 +68,39 @@
 //!
 //! # Feature flags
 //!
-//! - `std` (default): enables `std` support.
+//! - `std` (default): enables `std` support (implies `alloc`).
+//! - `alloc`: enables heap-backed types such as [`QuantityVec`] in `no_std` builds.
 //! - `cross-unit-ops` (default): enables direct cross-unit comparison operators (`==`, `<`, etc.) for built-in unit catalogs.
 //! - `serde`: enables `serde` support for `Quantity<U, S>`; serialization is the raw scalar value.
+//! - `parse`: enables runtime parsing of unit expressions into [`DynUnit`] and `FromStr` for
+//!   [`Quantity<U, S>`] (e.g. `"12.5 km/s".parse::<Velocity<Meter, Second>>()`).
 //! - `pyo3`: enables PyO3 bindings for Python interop via `#[pyclass]` and `#[pymethods]`.
+//! - `approx`: implements `AbsDiffEq`, `RelativeEq` and `UlpsEq` for [`Quantity<U, S>`]. The
+//!   [`assert_qty_eq!`] macro for mixed-unit comparisons is always available.
+//! - `autodiff`: enables [`autodiff::Dual`], a forward-mode dual-number scalar whose derivatives
+//!   come back as quantities in output-per-input units.
+//! - `arbitrary`: implements `arbitrary::Arbitrary` for [`Quantity<U, S>`] for fuzzing.
+//! - `bytemuck`: implements `Zeroable`, `Pod` and `TransparentWrapper` for [`Quantity<U, S>`], so
+//!   buffers can be cast to quantity slices without `unsafe`.
+//! - `fixed`: implements `Scalar` and `Exact` for `fixed::FixedI32<Frac>` and `FixedI64<Frac>`, so
+//!   `to_lossy` converts fixed-point quantities through exact unit ratios without floats.
+//! - `half`: implements `Scalar`, `Real` and `Transcendental` for `half::f16` and `half::bf16`
+//!   (computed in `f32`), with the [`Quantity16`] and [`QuantityBf16`] aliases. Under `serde` they
+//!   use `half`'s own encoding; `serde_with_unit` writes the value as a number.
+//! - `ndarray`: enables `QuantityArray<U, D>`, an `ndarray` array of `f64` values with the unit
+//!   tracked at the type level (implies `alloc`).
+//! - `nalgebra`: enables `QVector3<U>`, a `nalgebra::Vector3<f64>` in unit `U` that rotations and
+//!   other dimensionless transforms apply to directly.
+//! - `proptest`: enables the [`proptest`](crate::proptest) strategies for quantities and implements
+//!   proptest's `Arbitrary` for [`Quantity<U, S>`] (implies `std`).
+//! - `rand`: enables `rng.gen_range(..)` over quantities, normal and log-normal distributions with
+//!   quantity parameters, and `angular::UniformDirection` for directions on the sphere.
 //!
 //! # Panics and errors
 //!
-//! This crate does not define an error type and does not return `Result` from its core operations. For floating-point
+//! Core operations on [`Quantity`] do not return `Result`. Errors only arise where dimensions are checked at
+//! runtime: [`DynQuantity`] conversions ([`DimensionMismatch`]) and the optional `parse` feature
+//! (`ParseUnitError`, `ParseQuantityError`). For floating-point
 //! scalars (`f64`, `f32`), arithmetic follows IEEE-754 behavior (NaN and infinities propagate). For integer
 //! scalars, `abs()` uses saturating semantics at the minimum value (e.g. `i32::MIN.abs()` returns `i32::MAX`
 //! instead of panicking). Standard integer overflow rules still apply to addition, subtraction, and multiplication.
@@ -86,31 +111,61 @@
 
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 #![recursion_limit = "512"]
 
 #[cfg(not(feature = "std"))]
 extern crate libm;
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+extern crate alloc;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Core modules
 // ─────────────────────────────────────────────────────────────────────────────
 
+#[cfg(feature = "autodiff")]
+pub mod autodiff;
+pub mod catalog;
 mod dimension;
+mod display;
+mod dynamic;
+#[cfg(feature = "approx")]
+mod feature_approx;
+#[cfg(feature = "arbitrary")]
+mod feature_arbitrary;
+#[cfg(feature = "bytemuck")]
+mod feature_bytemuck;
 #[cfg(feature = "diesel")]
 mod feature_diesel;
+#[cfg(feature = "nalgebra")]
+mod feature_nalgebra;
+#[cfg(feature = "ndarray")]
+mod feature_ndarray;
 #[cfg(feature = "pyo3")]
 mod feature_pyo3;
+#[cfg(feature = "rand")]
+mod feature_rand;
 #[cfg(feature = "serde")]
 mod feature_serde;
 #[cfg(feature = "tiberius")]
 mod feature_tiberius;
 mod macros;
+mod measurement;
+#[cfg(feature = "parse")]
+mod parse;
+#[cfg(feature = "proptest")]
+pub mod proptest;
 mod quantity;
+#[cfg(feature = "alloc")]
+mod quantity_vec;
+mod range;
 pub mod scalar;
+pub mod slice;
 mod unit;
 /// Stable unit arithmetic layer: [`UnitDiv`] and [`UnitMul`] traits.
 pub mod unit_arithmetic;
+mod vector;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Public re-exports of core types
@@ -166,19 +221,47 @@ pub use dimension::{
 
 // Implementation machinery — public for advanced downstream use but not part
 // of the recommended API surface.
+pub use dimension::DimExponents;
+#[doc(hidden)]
+pub use dimension::{Dim, DimDiv, DimMul, DimPow};
+pub use display::EngineeringDisplay;
+pub use dynamic::{DimensionMismatch, DynQuantity, DynUnit};
+#[cfg(feature = "nalgebra")]
+pub use feature_nalgebra::QVector3;
+#[cfg(feature = "ndarray")]
+pub use feature_ndarray::{ArrayDivOutput, QuantityArray};
+#[cfg(feature = "rand")]
+pub use feature_rand::{QuantityLogNormal, QuantityNormal, UniformQuantity};
 #[doc(hidden)]
-pub use dimension::{Dim, DimDiv, DimMul};
+pub use macros::__assert_qty_eq;
+pub use measurement::Measurement;
 pub use quantity::{
     Quantity, Quantity32, Quantity64, QuantityI128, QuantityI16, QuantityI32, QuantityI64,
-    QuantityI8,
+    QuantityI8, QuantityU128, QuantityU16, QuantityU32, QuantityU64, QuantityU8, QuantityUsize,
+};
+#[cfg(feature = "alloc")]
+pub use quantity_vec::QuantityVec;
+pub use range::{QuantityRange, RangeLinspace, RangeStepBy};
+pub use scalar::{Exact, IntegerScalar, Real, Scalar, SignedScalar, Transcendental};
+pub use unit::{ExactRatio, Per, Pow, Prod, Unit, UnitSymbol};
+pub use unit_arithmetic::{
+    QuantityDivOutput, SameDivOutput, UnitDiv, UnitMul, UnitPow, UnitRoot, UnitSqrt,
 };
-pub use scalar::{Exact, IntegerScalar, Real, Scalar, Transcendental};
-pub use unit::{Per, Prod, Unit};
-pub use unit_arithmetic::{QuantityDivOutput, SameDivOutput, UnitDiv, UnitMul, UnitSqrt};
+pub use vector::{QuantityVector2, QuantityVector3};
+
+/// Type-level integers used as exponents by [`Pow`], [`Quantity::powi`] and
+/// [`Quantity::root`] (e.g. `typenum::P3`, `typenum::N2`).
+pub use typenum;
+
+#[cfg(feature = "half")]
+pub use quantity::{Quantity16, QuantityBf16};
 
 #[cfg(feature = "scalar-rational")]
 pub use quantity::QuantityRational;
 
+#[cfg(feature = "parse")]
+pub use parse::{ParseQuantityError, ParseUnitError};
+
 #[cfg(all(feature = "serde", feature = "std"))]
 pub use feature_serde::serde_with_unit;
 
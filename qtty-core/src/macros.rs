@@ -1,7 +1,12 @@
 // SPDX-License-Identifier: BSD-3-Clause
 // Copyright (C) 2026 Vallés Puig, Ramon
 
-//! Macros for defining units and conversions.
+//! Macros for defining units and conversions, and for comparing quantities.
+
+use crate::scalar::Real;
+use crate::unit::write_unit_suffix;
+use crate::{Quantity, Unit};
+use core::fmt::{self, Display, Formatter};
 
 /// Generates bidirectional `From` trait implementations for all pairs of units within a dimension.
 ///
@@ -325,6 +330,90 @@ macro_rules! __impl_cross_ops_each_extra_to_bases {
     };
 }
 
+/// Asserts that two quantities of the same dimension are equal within a
+/// tolerance.
+///
+/// `left`, `right` and `tolerance` may use different units: `right` and
+/// `tolerance` are converted to the unit of `left` before comparing, and a
+/// dimension mismatch is a compile error. The comparison fails for NaN. On
+/// failure the panic message prints every operand with its unit symbol. An
+/// optional trailing format string and arguments are appended to the message,
+/// as with [`assert_eq!`].
+///
+/// # Example
+///
+/// ```rust
+/// use qtty_core::assert_qty_eq;
+/// use qtty_core::length::{Kilometers, Meters, Millimeters};
+///
+/// assert_qty_eq!(Kilometers::new(1.5), Meters::new(1_500.000_4), Millimeters::new(1.0));
+/// ```
+///
+/// ```rust,should_panic
+/// use qtty_core::assert_qty_eq;
+/// use qtty_core::length::{Kilometers, Meters, Millimeters};
+///
+/// // Panics with:
+/// // assertion `left ≈ right` failed
+/// //   left: 1.5 km
+/// //  right: 1499 m
+/// //    tol: 1 mm
+/// assert_qty_eq!(Kilometers::new(1.5), Meters::new(1_499.0), Millimeters::new(1.0));
+/// ```
+#[macro_export]
+macro_rules! assert_qty_eq {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        $crate::__assert_qty_eq($left, $right, $tolerance, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $tolerance:expr, $($arg:tt)+) => {
+        $crate::__assert_qty_eq(
+            $left,
+            $right,
+            $tolerance,
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Runtime half of [`assert_qty_eq!`].
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_qty_eq<U, V, W, S>(
+    left: Quantity<U, S>,
+    right: Quantity<V, S>,
+    tolerance: Quantity<W, S>,
+    message: Option<fmt::Arguments<'_>>,
+) where
+    U: Unit,
+    V: Unit<Dim = U::Dim>,
+    W: Unit<Dim = U::Dim>,
+    S: Real,
+{
+    let diff = (left - right.to::<U>()).abs();
+    if diff.value() <= tolerance.to::<U>().value() {
+        return;
+    }
+    let (left, right, tolerance) = (Shown(left), Shown(right), Shown(tolerance));
+    match message {
+        Some(message) => panic!(
+            "assertion `left ≈ right` failed: {message}\n  left: {left}\n right: {right}\n   tol: {tolerance}"
+        ),
+        None => panic!(
+            "assertion `left ≈ right` failed\n  left: {left}\n right: {right}\n   tol: {tolerance}"
+        ),
+    }
+}
+
+/// Formats any quantity as `<value> <symbol>` for assertion messages.
+struct Shown<U: Unit, S: Real>(Quantity<U, S>);
+
+impl<U: Unit, S: Real> Display for Shown<U, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0.value(), f)?;
+        write_unit_suffix::<U>(f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Length, Quantity, Unit};
@@ -364,4 +453,59 @@ mod tests {
         assert_eq!(large.partial_cmp(&smaller_small), Some(Ordering::Greater));
         assert_eq!(smaller_small.partial_cmp(&large), Some(Ordering::Less));
     }
+
+    #[test]
+    fn assert_qty_eq_accepts_mixed_units_within_tolerance() {
+        use crate::length::{Kilometers, Meters, Millimeters};
+
+        crate::assert_qty_eq!(
+            Kilometers::new(1.5),
+            Meters::new(1_500.000_4),
+            Millimeters::new(1.0)
+        );
+        crate::assert_qty_eq!(Meters::new(1.0), Meters::new(1.0), Meters::new(0.0));
+        crate::assert_qty_eq!(
+            LargeQuantity::new(2.0),
+            SmallQuantity::new(2_000.5),
+            SmallQuantity::new(1.0),
+            "with {}",
+            "message"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion `left ≈ right` failed\n  left: 1.5 km\n right: 1499 m\n   tol: 1 mm"
+    )]
+    fn assert_qty_eq_reports_units_on_failure() {
+        use crate::length::{Kilometers, Meters, Millimeters};
+
+        crate::assert_qty_eq!(
+            Kilometers::new(1.5),
+            Meters::new(1_499.0),
+            Millimeters::new(1.0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "failed: step 3")]
+    fn assert_qty_eq_appends_custom_message() {
+        crate::assert_qty_eq!(
+            LargeQuantity::new(1.0),
+            SmallQuantity::new(0.0),
+            SmallQuantity::new(1.0),
+            "step {}",
+            3
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "left: NaN ltu")]
+    fn assert_qty_eq_rejects_nan() {
+        crate::assert_qty_eq!(
+            LargeQuantity::new(f64::NAN),
+            LargeQuantity::new(f64::NAN),
+            LargeQuantity::new(1.0)
+        );
+    }
 }
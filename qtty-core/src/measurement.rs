@@ -0,0 +1,522 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Quantities with a standard uncertainty.
+//!
+//! [`Measurement<U, S>`] pairs a [`Quantity`] with its standard uncertainty,
+//! which may be asymmetric (`+plus / −minus`). Arithmetic propagates the
+//! uncertainties to first order, assuming uncorrelated inputs: each input's
+//! uncertainty is scaled by the partial derivative of the result, and the
+//! contributions are added in quadrature. With asymmetric uncertainties, the
+//! upper and lower sides are propagated separately; an input whose partial
+//! derivative is negative feeds its upper uncertainty into the result's lower
+//! one.
+//!
+//! ```rust
+//! use qtty_core::length::{Kilometers, Meters};
+//! use qtty_core::time::Seconds;
+//! use qtty_core::Measurement;
+//!
+//! let d = Measurement::new(Kilometers::new(12.0), Kilometers::new(0.3));
+//! let e = Measurement::new(Kilometers::new(0.3), Kilometers::new(0.4));
+//! let total = d + e;
+//! assert_eq!(format!("{total:.1}"), "12.3 ± 0.5 km");
+//!
+//! // Conversions scale both the value and the uncertainty.
+//! let m = total.to::<qtty_core::length::Meter>();
+//! assert!((m.uncertainty().value() - 500.0).abs() < 1e-9);
+//!
+//! // Division produces a `Per` unit, like plain quantities.
+//! let t = Measurement::new(Seconds::new(10.0), Seconds::new(0.1));
+//! let v = Measurement::from(Meters::new(100.0)) / t;
+//! assert!((v.uncertainty().value() - 0.1).abs() < 1e-12);
+//! ```
+
+use crate::dimension::{DimDiv, Dimension};
+use crate::scalar::{Real, Transcendental};
+use crate::unit::{write_unit_suffix, Per};
+use crate::unit_arithmetic::{SameDivOutput, UnitDiv, UnitMul, UnitSqrt};
+use crate::units::angular::AngularUnit;
+use crate::{Quantity, Unit};
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A quantity with a (possibly asymmetric) standard uncertainty.
+///
+/// Both uncertainties are stored as non-negative magnitudes in the same unit
+/// as the value. See the [module documentation](self) for how they propagate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measurement<U: Unit, S: Real = f64> {
+    value: Quantity<U, S>,
+    minus: Quantity<U, S>,
+    plus: Quantity<U, S>,
+}
+
+impl<U: Unit, S: Real> Measurement<U, S> {
+    /// Creates a measurement with a symmetric standard uncertainty `sigma`.
+    ///
+    /// The sign of `sigma` is ignored.
+    #[inline]
+    pub fn new(value: Quantity<U, S>, sigma: Quantity<U, S>) -> Self {
+        Self::asymmetric(value, sigma, sigma)
+    }
+
+    /// Creates a measurement `value +plus −minus`.
+    ///
+    /// The signs of `minus` and `plus` are ignored.
+    #[inline]
+    pub fn asymmetric(value: Quantity<U, S>, minus: Quantity<U, S>, plus: Quantity<U, S>) -> Self {
+        Self {
+            value,
+            minus: minus.abs(),
+            plus: plus.abs(),
+        }
+    }
+
+    /// Creates a measurement with zero uncertainty.
+    #[inline]
+    pub fn exact(value: Quantity<U, S>) -> Self {
+        Self::new(value, Quantity::new(S::ZERO))
+    }
+
+    /// The central value.
+    #[inline]
+    pub fn value(self) -> Quantity<U, S> {
+        self.value
+    }
+
+    /// The standard uncertainty: the mean of the lower and upper
+    /// uncertainties, which are equal for symmetric measurements.
+    #[inline]
+    pub fn uncertainty(self) -> Quantity<U, S> {
+        self.minus.mean(self.plus)
+    }
+
+    /// The uncertainty below the value (`−minus`), as a magnitude.
+    #[inline]
+    pub fn lower_uncertainty(self) -> Quantity<U, S> {
+        self.minus
+    }
+
+    /// The uncertainty above the value (`+plus`), as a magnitude.
+    #[inline]
+    pub fn upper_uncertainty(self) -> Quantity<U, S> {
+        self.plus
+    }
+
+    /// Whether the lower and upper uncertainties are equal.
+    #[inline]
+    pub fn is_symmetric(self) -> bool {
+        self.minus == self.plus
+    }
+
+    /// `value − lower_uncertainty`.
+    #[inline]
+    pub fn lower_bound(self) -> Quantity<U, S> {
+        self.value - self.minus
+    }
+
+    /// `value + upper_uncertainty`.
+    #[inline]
+    pub fn upper_bound(self) -> Quantity<U, S> {
+        self.value + self.plus
+    }
+
+    /// [`uncertainty`](Self::uncertainty) divided by the magnitude of the
+    /// value (infinite or NaN for a zero value).
+    #[inline]
+    pub fn relative_uncertainty(self) -> S {
+        self.uncertainty().value() / self.value.value().abs()
+    }
+
+    /// Converts the value and both uncertainties to unit `T`.
+    ///
+    /// ```rust
+    /// use qtty_core::length::{Kilometers, Meter};
+    /// use qtty_core::Measurement;
+    ///
+    /// let d = Measurement::new(Kilometers::new(1.5), Kilometers::new(0.01));
+    /// let m = d.to::<Meter>();
+    /// assert_eq!(m.value().value(), 1500.0);
+    /// assert_eq!(m.uncertainty().value(), 10.0);
+    /// ```
+    #[inline]
+    pub fn to<T: Unit<Dim = U::Dim>>(self) -> Measurement<T, S> {
+        Measurement {
+            value: self.value.to(),
+            minus: self.minus.to(),
+            plus: self.plus.to(),
+        }
+    }
+
+    /// Applies a function with derivative `derivative` at the value, returning
+    /// the result value in unit `T` with propagated uncertainties.
+    #[inline]
+    fn propagate<T: Unit>(self, value: S, derivative: S) -> Measurement<T, S> {
+        let (minus, plus) = scaled(derivative, self.minus.value(), self.plus.value());
+        Measurement::asymmetric(
+            Quantity::new(value),
+            Quantity::new(minus),
+            Quantity::new(plus),
+        )
+    }
+}
+
+impl<U: UnitSqrt, S: Real> Measurement<U, S> {
+    /// Dimensionally-typed square root (see [`Quantity::sqrt`]), with
+    /// uncertainty `σ / (2·√x)`.
+    #[inline]
+    pub fn sqrt(self) -> Measurement<U::Root, S> {
+        let root = self.value.value().sqrt();
+        self.propagate(root, S::ONE / (root + root))
+    }
+}
+
+impl<U: AngularUnit + Copy, S: Transcendental> Measurement<U, S> {
+    /// Converts the uncertainty of an angle into radians, the unit in which
+    /// the trigonometric derivatives below hold.
+    #[inline]
+    fn radians_per_unit() -> S {
+        S::from_f64(U::RATIO / crate::units::angular::Radian::RATIO)
+    }
+
+    /// Sine of the angle, with uncertainty `|cos x|·σ`.
+    ///
+    /// The result is dimensionless (`U/U`).
+    #[inline]
+    pub fn sin(self) -> Measurement<Per<U, U>, S> {
+        let (sin, cos) = self.value.sin_cos();
+        self.propagate(sin, cos * Self::radians_per_unit())
+    }
+
+    /// Cosine of the angle, with uncertainty `|sin x|·σ`.
+    ///
+    /// The result is dimensionless (`U/U`).
+    #[inline]
+    pub fn cos(self) -> Measurement<Per<U, U>, S> {
+        let (sin, cos) = self.value.sin_cos();
+        self.propagate(cos, -sin * Self::radians_per_unit())
+    }
+
+    /// Tangent of the angle, with uncertainty `σ / cos² x`.
+    ///
+    /// The result is dimensionless (`U/U`).
+    #[inline]
+    pub fn tan(self) -> Measurement<Per<U, U>, S> {
+        let cos = self.value.cos();
+        self.propagate(self.value.tan(), Self::radians_per_unit() / (cos * cos))
+    }
+}
+
+/// Scales the `(minus, plus)` uncertainties by a partial derivative. A
+/// negative derivative swaps the sides.
+#[inline]
+fn scaled<S: Real>(derivative: S, minus: S, plus: S) -> (S, S) {
+    let magnitude = derivative.abs();
+    if derivative < S::ZERO {
+        (magnitude * plus, magnitude * minus)
+    } else {
+        (magnitude * minus, magnitude * plus)
+    }
+}
+
+/// Combines two independent inputs with partial derivatives `da` and `db`.
+#[inline]
+fn combine<S: Real>(da: S, a: (S, S), db: S, b: (S, S)) -> (S, S) {
+    let (a_minus, a_plus) = scaled(da, a.0, a.1);
+    let (b_minus, b_plus) = scaled(db, b.0, b.1);
+    (a_minus.hypot(b_minus), a_plus.hypot(b_plus))
+}
+
+impl<U: Unit, S: Real> From<Quantity<U, S>> for Measurement<U, S> {
+    /// Wraps an exact quantity (zero uncertainty).
+    #[inline]
+    fn from(value: Quantity<U, S>) -> Self {
+        Self::exact(value)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Arithmetic
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<U: Unit, S: Real> Measurement<U, S> {
+    #[inline]
+    fn sides(self) -> (S, S) {
+        (self.minus.value(), self.plus.value())
+    }
+
+    #[inline]
+    fn combined<T: Unit>(value: S, (minus, plus): (S, S)) -> Measurement<T, S> {
+        Measurement::asymmetric(
+            Quantity::new(value),
+            Quantity::new(minus),
+            Quantity::new(plus),
+        )
+    }
+}
+
+impl<U: Unit, S: Real> Add for Measurement<U, S> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let value = (self.value + rhs.value).value();
+        Self::combined(value, combine(S::ONE, self.sides(), S::ONE, rhs.sides()))
+    }
+}
+
+impl<U: Unit, S: Real> Sub for Measurement<U, S> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        let value = (self.value - rhs.value).value();
+        Self::combined(value, combine(S::ONE, self.sides(), -S::ONE, rhs.sides()))
+    }
+}
+
+impl<U: Unit, S: Real> Neg for Measurement<U, S> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            value: -self.value,
+            minus: self.plus,
+            plus: self.minus,
+        }
+    }
+}
+
+impl<U: Unit, S: Real> Mul<S> for Measurement<U, S> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: S) -> Self {
+        self.propagate((self.value * rhs).value(), rhs)
+    }
+}
+
+impl<U: Unit, S: Real> Div<S> for Measurement<U, S> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: S) -> Self {
+        self.propagate((self.value / rhs).value(), S::ONE / rhs)
+    }
+}
+
+impl<A: Unit, B: Unit, S: Real> Mul<Measurement<B, S>> for Measurement<A, S>
+where
+    A: UnitMul<B>,
+{
+    type Output = Measurement<<A as UnitMul<B>>::Output, S>;
+
+    #[inline]
+    fn mul(self, rhs: Measurement<B, S>) -> Self::Output {
+        let (a, b) = (self.value.value(), rhs.value.value());
+        Self::combined(a * b, combine(b, self.sides(), a, rhs.sides()))
+    }
+}
+
+/// Maps a [`UnitDiv::Output`] token to the unit of a [`Measurement`]
+/// quotient: the composite unit itself, or `Per<N, D>` when the units cancel.
+///
+/// Unlike [`Quantity`] division, dividing two measurements of the same unit
+/// keeps a (dimensionless) `Measurement`, so the uncertainty is not lost.
+#[doc(hidden)]
+pub trait MeasurementDivOutput<N: Unit, D: Unit> {
+    /// Unit of the quotient.
+    type Unit: Unit;
+}
+
+impl<N: Unit, D: Unit> MeasurementDivOutput<N, D> for SameDivOutput
+where
+    N::Dim: DimDiv<D::Dim>,
+    <N::Dim as DimDiv<D::Dim>>::Output: Dimension,
+{
+    type Unit = Per<N, D>;
+}
+
+impl<N: Unit, D: Unit, U: Unit> MeasurementDivOutput<N, D> for U {
+    type Unit = U;
+}
+
+impl<N: Unit, D: Unit, S: Real> Div<Measurement<D, S>> for Measurement<N, S>
+where
+    N: UnitDiv<D>,
+    <N as UnitDiv<D>>::Output: MeasurementDivOutput<N, D>,
+{
+    type Output = Measurement<<<N as UnitDiv<D>>::Output as MeasurementDivOutput<N, D>>::Unit, S>;
+
+    #[inline]
+    fn div(self, rhs: Measurement<D, S>) -> Self::Output {
+        let (a, b) = (self.value.value(), rhs.value.value());
+        let quotient = a / b;
+        Self::combined(
+            quotient,
+            combine(S::ONE / b, self.sides(), -quotient / b, rhs.sides()),
+        )
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Formatting
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Prints `12.3 ± 0.4 km`, or `12.3 +0.5/−0.4 km` for asymmetric
+/// uncertainties. Precision and sign flags apply to the value; precision also
+/// applies to the uncertainties.
+impl<U: Unit, S: Real> Display for Measurement<U, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.value.value(), f)?;
+        let (minus, plus) = self.sides();
+        match f.precision() {
+            Some(p) if self.is_symmetric() => write!(f, " ± {plus:.p$}")?,
+            Some(p) => write!(f, " +{plus:.p$}/−{minus:.p$}")?,
+            None if self.is_symmetric() => write!(f, " ± {plus}")?,
+            None => write!(f, " +{plus}/−{minus}")?,
+        }
+        write_unit_suffix::<U>(f)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::angular::{Degrees, Radians};
+    use crate::area::SquareMeters;
+    use crate::length::{Kilometer, Kilometers, Meter, Meters};
+    use crate::time::Seconds;
+    use approx::assert_relative_eq;
+
+    fn m(value: f64, sigma: f64) -> Measurement<Meter> {
+        Measurement::new(Meters::new(value), Meters::new(sigma))
+    }
+
+    // ── Construction ──
+
+    #[test]
+    fn accessors_and_bounds() {
+        let x = Measurement::asymmetric(Meters::new(10.0), Meters::new(-1.0), Meters::new(3.0));
+        assert_eq!(x.lower_uncertainty(), Meters::new(1.0));
+        assert_eq!(x.upper_uncertainty(), Meters::new(3.0));
+        assert_eq!(x.uncertainty(), Meters::new(2.0));
+        assert_eq!(x.lower_bound(), Meters::new(9.0));
+        assert_eq!(x.upper_bound(), Meters::new(13.0));
+        assert!(!x.is_symmetric());
+        assert_relative_eq!(x.relative_uncertainty(), 0.2);
+        assert!(Measurement::from(Meters::new(1.0)).uncertainty().value() == 0.0);
+    }
+
+    #[test]
+    fn conversion_scales_uncertainty() {
+        let km = Measurement::asymmetric(
+            Kilometers::new(2.0),
+            Kilometers::new(0.1),
+            Kilometers::new(0.2),
+        );
+        let x = km.to::<Meter>();
+        assert_relative_eq!(x.value().value(), 2000.0);
+        assert_relative_eq!(x.lower_uncertainty().value(), 100.0);
+        assert_relative_eq!(x.upper_uncertainty().value(), 200.0);
+        assert_eq!(x.to::<Kilometer>().value(), km.value());
+    }
+
+    // ── Propagation ──
+
+    #[test]
+    fn sum_and_difference_add_in_quadrature() {
+        let s = m(10.0, 3.0) + m(5.0, 4.0);
+        assert_relative_eq!(s.value().value(), 15.0);
+        assert_relative_eq!(s.uncertainty().value(), 5.0);
+        let d = m(10.0, 3.0) - m(5.0, 4.0);
+        assert_relative_eq!(d.value().value(), 5.0);
+        assert_relative_eq!(d.uncertainty().value(), 5.0);
+    }
+
+    #[test]
+    fn subtraction_swaps_asymmetric_sides() {
+        let a = Measurement::asymmetric(Meters::new(10.0), Meters::new(1.0), Meters::new(2.0));
+        let d = Measurement::from(Meters::new(0.0)) - a;
+        assert_relative_eq!(d.lower_uncertainty().value(), 2.0);
+        assert_relative_eq!(d.upper_uncertainty().value(), 1.0);
+        assert_eq!(-a, d);
+    }
+
+    #[test]
+    fn product_and_quotient_use_relative_uncertainties() {
+        let area = m(3.0, 0.3) * m(4.0, 0.4);
+        let _: &Measurement<crate::Prod<Meter, Meter>> = &area;
+        assert_relative_eq!(area.value().value(), 12.0);
+        // Relative uncertainties 10 % and 10 % combine to √2 · 10 %.
+        assert_relative_eq!(
+            area.relative_uncertainty(),
+            0.1 * 2f64.sqrt(),
+            epsilon = 1e-12
+        );
+
+        let v = m(100.0, 1.0) / Measurement::new(Seconds::new(10.0), Seconds::new(0.0));
+        assert_relative_eq!(v.value().value(), 10.0);
+        assert_relative_eq!(v.uncertainty().value(), 0.1);
+
+        let ratio = m(6.0, 0.6) / m(3.0, 0.0);
+        let _: &Measurement<Per<Meter, Meter>> = &ratio;
+        assert_relative_eq!(ratio.value().value(), 2.0);
+        assert_relative_eq!(ratio.uncertainty().value(), 0.2);
+    }
+
+    #[test]
+    fn scalar_operations_scale_uncertainty() {
+        let x = m(2.0, 0.5) * -3.0;
+        assert_relative_eq!(x.value().value(), -6.0);
+        assert_relative_eq!(x.uncertainty().value(), 1.5);
+        let y = m(2.0, 0.5) / 2.0;
+        assert_relative_eq!(y.uncertainty().value(), 0.25);
+    }
+
+    #[test]
+    fn sqrt_halves_relative_uncertainty() {
+        let side = Measurement::new(SquareMeters::new(16.0), SquareMeters::new(0.8)).sqrt();
+        assert_relative_eq!(side.value().value(), 4.0);
+        assert_relative_eq!(side.uncertainty().value(), 0.1);
+    }
+
+    #[test]
+    fn trig_uses_radian_derivatives() {
+        let x = Measurement::new(Radians::new(0.0), Radians::new(0.01));
+        assert_relative_eq!(x.sin().uncertainty().value(), 0.01);
+        assert_relative_eq!(x.cos().uncertainty().value(), 0.0);
+
+        let deg = Measurement::new(Degrees::new(60.0), Degrees::new(1.0));
+        let cos = deg.cos();
+        assert_relative_eq!(cos.value().value(), 0.5, epsilon = 1e-12);
+        assert_relative_eq!(
+            cos.uncertainty().value(),
+            60f64.to_radians().sin() * 1f64.to_radians(),
+            epsilon = 1e-15
+        );
+        assert_relative_eq!(
+            deg.tan().uncertainty().value(),
+            1f64.to_radians() / 0.25,
+            epsilon = 1e-12
+        );
+    }
+
+    // ── Display ──
+
+    #[test]
+    fn display_formats() {
+        assert_eq!(m(12.3, 0.4).to_string(), "12.3 ± 0.4 m");
+        assert_eq!(format!("{:.2}", m(12.3, 0.4)), "12.30 ± 0.40 m");
+        let a = Measurement::asymmetric(
+            Kilometers::new(1.0),
+            Kilometers::new(0.1),
+            Kilometers::new(0.2),
+        );
+        assert_eq!(a.to_string(), "1 +0.2/−0.1 km");
+        assert_eq!(format!("{:+.1}", a), "+1.0 +0.2/−0.1 km");
+        assert_eq!(format!("{:.1}", m(6.0, 0.6) / m(3.0, 0.0)), "2.0 ± 0.2");
+    }
+}
@@ -0,0 +1,560 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Runtime parsing of unit expressions and quantities (feature `parse`).
+//!
+//! A unit expression is resolved against the built-in unit inventories into a
+//! [`DynUnit`]. The grammar is deliberately small:
+//!
+//! - **Symbols** are the built-in [`Unit::SYMBOL`]s (`m`, `km`, `h`, `°`, `mas`, …).
+//! - **Names** are the built-in [`Unit::LONG_NAME`]s, [`Unit::PLURAL`]s and
+//!   [`Unit::ALIASES`], compared ignoring ASCII case (`kilometre`, `kilometers`,
+//!   `nautical miles`). Exact symbols win over names.
+//! - **SI prefixes** (`Q` … `q`, including `da`, `µ`/`μ`/`u`) may be attached to
+//!   the coherent SI symbols (`m`, `s`, `g`, `K`, `A`, `mol`, `cd`, `rad`, `sr`,
+//!   `Hz`, `N`, `Pa`, `J`, `W`, `C`, `V`, `F`, `Ω`, `Wb`, `T`, `H`, `lm`, `lx`,
+//!   `L`, `eV`, `pc`).
+//! - **Products** use `·`, `⋅` or `*`; **quotients** use `/`. Every factor after a
+//!   `/` divides the expression, so `J/kg·K` reads as `J/(kg·K)`.
+//! - **Exponents** are written `^n` / `^-n` or with Unicode superscripts (`m²`, `s⁻¹`).
+//! - **Parentheses** group sub-expressions: `J/(kg·K)`.
+//!
+//! ```rust
+//! use qtty_core::length::Meter;
+//! use qtty_core::time::Second;
+//! use qtty_core::{DynUnit, Per};
+//!
+//! let unit: DynUnit = "km/s".parse().unwrap();
+//! assert_eq!(unit.dimension(), DynUnit::of::<Per<Meter, Second>>().dimension());
+//! assert_eq!(unit.ratio(), 1000.0);
+//! ```
+//!
+//! Quantities parse as a number followed by a unit expression and are converted
+//! into the requested unit:
+//!
+//! ```rust
+//! use qtty_core::length::Meters;
+//! use qtty_core::velocity::Velocity;
+//! use qtty_core::length::Meter;
+//! use qtty_core::time::Second;
+//!
+//! let d: Meters = "1.5 km".parse().unwrap();
+//! assert_eq!(d.value(), 1500.0);
+//!
+//! let v: Velocity<Meter, Second> = "36 km/h".parse().unwrap();
+//! assert!((v.value() - 10.0).abs() < 1e-12);
+//!
+//! assert!("3 s".parse::<Meters>().is_err());
+//! ```
+//!
+//! When the target unit is itself only known at runtime, parse into a
+//! [`DynQuantity`] instead.
+
+use crate::catalog;
+use crate::dimension::DimExponents;
+use crate::dynamic::{powi_f64, DimensionMismatch, DynQuantity, DynUnit};
+use crate::quantity::Quantity;
+use crate::scalar::Real;
+use crate::unit::Unit;
+use crate::units::inventory::SI_PREFIXES;
+use core::fmt;
+use core::str::FromStr;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Errors
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Error returned when a unit expression cannot be parsed.
+///
+/// Positions are byte offsets into the (untrimmed) input string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseUnitError {
+    /// The input contains no unit expression.
+    Empty,
+    /// The symbol starting at `position` is not a known unit.
+    UnknownUnit {
+        /// Byte offset of the unknown symbol.
+        position: usize,
+    },
+    /// An unexpected character (or the end of input) was found at `position`.
+    UnexpectedChar {
+        /// Byte offset of the unexpected character.
+        position: usize,
+    },
+    /// The exponent starting at `position` is malformed or out of range.
+    InvalidExponent {
+        /// Byte offset of the exponent.
+        position: usize,
+    },
+    /// The parenthesis opened at `position` is never closed.
+    UnclosedParenthesis {
+        /// Byte offset of the opening parenthesis.
+        position: usize,
+    },
+}
+
+impl fmt::Display for ParseUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty unit expression"),
+            Self::UnknownUnit { position } => write!(f, "unknown unit at byte {position}"),
+            Self::UnexpectedChar { position } => {
+                write!(f, "unexpected character at byte {position}")
+            }
+            Self::InvalidExponent { position } => {
+                write!(f, "invalid exponent at byte {position}")
+            }
+            Self::UnclosedParenthesis { position } => {
+                write!(f, "unclosed parenthesis at byte {position}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseUnitError {}
+
+/// Error returned when a quantity string cannot be parsed into a [`Quantity`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseQuantityError {
+    /// The leading number is missing or malformed.
+    InvalidNumber,
+    /// The unit expression following the number is invalid.
+    Unit(ParseUnitError),
+    /// The unit parsed correctly but measures a different dimension than the
+    /// target unit.
+    DimensionMismatch {
+        /// Dimension of the target unit.
+        expected: DimExponents,
+        /// Dimension of the parsed unit.
+        found: DimExponents,
+    },
+}
+
+impl fmt::Display for ParseQuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber => f.write_str("invalid or missing number"),
+            Self::Unit(err) => write!(f, "invalid unit: {err}"),
+            Self::DimensionMismatch { expected, found } => {
+                write!(f, "dimension mismatch: expected {expected}, found {found}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseQuantityError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Unit(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseUnitError> for ParseQuantityError {
+    fn from(err: ParseUnitError) -> Self {
+        Self::Unit(err)
+    }
+}
+
+impl From<DimensionMismatch> for ParseQuantityError {
+    fn from(err: DimensionMismatch) -> Self {
+        Self::DimensionMismatch {
+            expected: err.expected,
+            found: err.found,
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Symbol resolution
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Symbols that accept an SI prefix.
+const PREFIXABLE: &[&str] = &[
+    "m", "s", "g", "K", "A", "mol", "cd", "rad", "sr", "Hz", "N", "Pa", "J", "W", "C", "V", "F",
+    "Ω", "Wb", "T", "H", "lm", "lx", "L", "eV", "pc",
+];
+
+/// Looks up a built-in unit by its exact symbol.
+fn lookup_symbol(symbol: &str) -> Option<DynUnit> {
+    catalog::by_symbol(symbol).map(catalog::UnitInfo::dyn_unit)
+}
+
+/// Looks up a built-in unit by exact symbol, then by long name, plural or
+/// alias (ignoring ASCII case).
+fn lookup_symbol_or_name(atom: &str) -> Option<DynUnit> {
+    lookup_symbol(atom).or_else(|| catalog::by_name(atom).map(catalog::UnitInfo::dyn_unit))
+}
+
+/// Resolves a single unit atom: exact symbol, unit name, bare `1`, or SI
+/// prefix + symbol.
+fn resolve_atom(atom: &str) -> Option<DynUnit> {
+    if atom == "1" {
+        return Some(DynUnit::DIMENSIONLESS);
+    }
+    if let Some(unit) = lookup_symbol_or_name(atom) {
+        return Some(unit);
+    }
+    SI_PREFIXES.iter().find_map(|&(prefix, exp)| {
+        let base = atom.strip_prefix(prefix)?;
+        if !PREFIXABLE.contains(&base) {
+            return None;
+        }
+        let unit = lookup_symbol(base)?;
+        Some(DynUnit::new(
+            unit.dimension(),
+            unit.ratio() * powi_f64(10.0, exp),
+        ))
+    })
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Expression parser
+// ─────────────────────────────────────────────────────────────────────────────
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+fn superscript_digit(c: char) -> Option<u32> {
+    SUPERSCRIPT_DIGITS
+        .iter()
+        .position(|&d| d == c)
+        .map(|d| d as u32)
+}
+
+fn ascii_digit(c: char) -> Option<u32> {
+    c.to_digit(10)
+}
+
+fn is_product_op(c: char) -> bool {
+    matches!(c, '·' | '⋅' | '*')
+}
+
+fn is_atom_char(c: char) -> bool {
+    !(c.is_whitespace()
+        || is_product_op(c)
+        || matches!(c, '/' | '(' | ')' | '^' | '⁻' | '⁺')
+        || superscript_digit(c).is_some())
+}
+
+/// Recursive-descent parser over a unit expression.
+///
+/// ```text
+/// expr    := product ('/' product)*
+/// product := factor (('·' | '⋅' | '*') factor)*
+/// factor  := ('(' expr ')' | atom) exponent?
+/// ```
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn expr(&mut self) -> Result<DynUnit, ParseUnitError> {
+        let mut unit = self.product()?;
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('/') {
+                return Ok(unit);
+            }
+            let op = self.pos;
+            self.bump();
+            unit = unit
+                .checked_div(self.product()?)
+                .ok_or(ParseUnitError::InvalidExponent { position: op })?;
+        }
+    }
+
+    fn product(&mut self) -> Result<DynUnit, ParseUnitError> {
+        let mut unit = self.factor()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(c) if is_product_op(c) => {
+                    let op = self.pos;
+                    self.bump();
+                    unit = unit
+                        .checked_mul(self.factor()?)
+                        .ok_or(ParseUnitError::InvalidExponent { position: op })?;
+                }
+                _ => return Ok(unit),
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<DynUnit, ParseUnitError> {
+        self.skip_whitespace();
+        let base = if self.peek() == Some('(') {
+            let open = self.pos;
+            self.bump();
+            let inner = self.expr()?;
+            self.skip_whitespace();
+            if self.peek() != Some(')') {
+                return Err(ParseUnitError::UnclosedParenthesis { position: open });
+            }
+            self.bump();
+            inner
+        } else {
+            self.atom()?
+        };
+        let start = self.pos;
+        Ok(match self.exponent()? {
+            Some(n) => {
+                // Exponents combine with `i8` arithmetic, so `(m^64)^2` must
+                // be rejected rather than overflow.
+                base.checked_powi(n)
+                    .ok_or(ParseUnitError::InvalidExponent { position: start })?
+            }
+            None => base,
+        })
+    }
+
+    fn atom(&mut self) -> Result<DynUnit, ParseUnitError> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_atom_char) {
+            self.bump();
+        }
+        if start == self.pos {
+            return Err(ParseUnitError::UnexpectedChar { position: start });
+        }
+        resolve_atom(&self.src[start..self.pos])
+            .ok_or(ParseUnitError::UnknownUnit { position: start })
+    }
+
+    fn exponent(&mut self) -> Result<Option<i8>, ParseUnitError> {
+        let start = self.pos;
+        let (negative, digit): (bool, fn(char) -> Option<u32>) = match self.peek() {
+            Some('^') => {
+                self.bump();
+                let negative = match self.peek() {
+                    Some('-' | '−') => true,
+                    Some('+') => false,
+                    _ => return self.digits(start, false, ascii_digit).map(Some),
+                };
+                self.bump();
+                (negative, ascii_digit)
+            }
+            Some('⁻') => {
+                self.bump();
+                (true, superscript_digit)
+            }
+            Some('⁺') => {
+                self.bump();
+                (false, superscript_digit)
+            }
+            Some(c) if superscript_digit(c).is_some() => (false, superscript_digit),
+            _ => return Ok(None),
+        };
+        self.digits(start, negative, digit).map(Some)
+    }
+
+    fn digits(
+        &mut self,
+        start: usize,
+        negative: bool,
+        digit: fn(char) -> Option<u32>,
+    ) -> Result<i8, ParseUnitError> {
+        let invalid = ParseUnitError::InvalidExponent { position: start };
+        let mut value: i32 = 0;
+        let mut any = false;
+        while let Some(d) = self.peek().and_then(digit) {
+            value = value * 10 + d as i32;
+            // `i8::MIN` has magnitude 128; the final `try_from` rejects `+128`.
+            if value > -i32::from(i8::MIN) {
+                return Err(invalid);
+            }
+            any = true;
+            self.bump();
+        }
+        if !any {
+            return Err(invalid);
+        }
+        let value = if negative { -value } else { value };
+        i8::try_from(value).map_err(|_| invalid)
+    }
+}
+
+/// Parses a unit expression, reporting errors relative to `offset`.
+fn parse_unit_at(src: &str, offset: usize) -> Result<DynUnit, ParseUnitError> {
+    let trimmed = src.trim();
+    if trimmed.is_empty() {
+        return Err(ParseUnitError::Empty);
+    }
+    // Whole-string match first so composite built-in symbols (`m/s²`,
+    // `W·m⁻²·sr⁻¹`, `fl oz`) and multi-word names (`nautical miles`) resolve
+    // to their own marker.
+    if let Some(unit) = lookup_symbol_or_name(trimmed) {
+        return Ok(unit);
+    }
+    let mut parser = Parser { src, pos: 0 };
+    let unit = parser.expr().map_err(|err| shift(err, offset))?;
+    parser.skip_whitespace();
+    if parser.pos != src.len() {
+        return Err(shift(
+            ParseUnitError::UnexpectedChar {
+                position: parser.pos,
+            },
+            offset,
+        ));
+    }
+    Ok(unit)
+}
+
+fn shift(err: ParseUnitError, offset: usize) -> ParseUnitError {
+    match err {
+        ParseUnitError::Empty => ParseUnitError::Empty,
+        ParseUnitError::UnknownUnit { position } => ParseUnitError::UnknownUnit {
+            position: position + offset,
+        },
+        ParseUnitError::UnexpectedChar { position } => ParseUnitError::UnexpectedChar {
+            position: position + offset,
+        },
+        ParseUnitError::InvalidExponent { position } => ParseUnitError::InvalidExponent {
+            position: position + offset,
+        },
+        ParseUnitError::UnclosedParenthesis { position } => ParseUnitError::UnclosedParenthesis {
+            position: position + offset,
+        },
+    }
+}
+
+impl FromStr for DynUnit {
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_unit_at(s, 0)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Quantity parsing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Length of the leading decimal number (`[+-]digits[.digits][e[+-]digits]`).
+fn number_len(s: &str) -> usize {
+    let b = s.as_bytes();
+    let mut i = 0;
+    if matches!(b.first(), Some(b'+' | b'-')) {
+        i += 1;
+    }
+    let mantissa_start = i;
+    while b.get(i).is_some_and(u8::is_ascii_digit) {
+        i += 1;
+    }
+    if b.get(i) == Some(&b'.') {
+        i += 1;
+        while b.get(i).is_some_and(u8::is_ascii_digit) {
+            i += 1;
+        }
+    }
+    if i == mantissa_start {
+        return 0;
+    }
+    if matches!(b.get(i), Some(b'e' | b'E')) {
+        let mut j = i + 1;
+        if matches!(b.get(j), Some(b'+' | b'-')) {
+            j += 1;
+        }
+        if b.get(j).is_some_and(u8::is_ascii_digit) {
+            while b.get(j).is_some_and(u8::is_ascii_digit) {
+                j += 1;
+            }
+            i = j;
+        }
+    }
+    i
+}
+
+/// Parses `"<number> <unit>"` into a runtime quantity.
+///
+/// An empty unit yields a dimensionless quantity. Non-finite numbers (`inf`,
+/// `NaN`) must be separated from the unit by whitespace.
+impl<S: Real> FromStr for DynQuantity<S> {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let body = s.trim_start();
+        let offset = s.len() - body.len();
+        let split = match number_len(body) {
+            0 => body.find(char::is_whitespace).unwrap_or(body.len()),
+            n => n,
+        };
+        let value: f64 = body[..split]
+            .parse()
+            .map_err(|_| ParseQuantityError::InvalidNumber)?;
+
+        let rest = &body[split..];
+        let unit = if rest.trim().is_empty() {
+            DynUnit::DIMENSIONLESS
+        } else {
+            parse_unit_at(rest, offset + split)?
+        };
+        Ok(Self::new(S::from_f64(value), unit))
+    }
+}
+
+/// Parses `"<number> <unit>"` and converts the value into `U`.
+///
+/// The unit may be any expression with the same dimension as `U`; an empty
+/// unit is accepted only when `U` is dimensionless. The conversion is carried
+/// out in `f64` before narrowing to `S`.
+impl<U: Unit, S: Real> FromStr for Quantity<U, S> {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed: DynQuantity = s.parse()?;
+        let q = parsed.to_quantity::<U>()?;
+        Ok(Self::new(S::from_f64(q.value())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_len_variants() {
+        assert_eq!(number_len("12.5 km"), 4);
+        assert_eq!(number_len("-3e-2m"), 5);
+        assert_eq!(number_len("1e"), 1);
+        assert_eq!(number_len(".5"), 2);
+        assert_eq!(number_len("km"), 0);
+    }
+
+    #[test]
+    fn prefix_requires_prefixable_base() {
+        // `min` is an exact symbol, never milli-`in`.
+        assert_eq!(resolve_atom("min").unwrap().ratio(), 60.0);
+        assert!(resolve_atom("kmin").is_none());
+        assert!(resolve_atom("xm").is_none());
+    }
+
+    #[test]
+    fn errors_are_offset_into_quantity_input() {
+        let err = "  12 km/xyz".parse::<crate::length::Meters>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseQuantityError::Unit(ParseUnitError::UnknownUnit { position: 8 })
+        );
+    }
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `proptest` strategies for quantities (feature-gated).
+//!
+//! This module is enabled by the `proptest` feature. It provides ready-made
+//! strategies so property tests do not have to wrap raw `f64` strategies and
+//! filter out NaN and infinities by hand:
+//!
+//! - [`finite`]: any finite value in unit `U`.
+//! - [`in_range`]: values inside a range of quantities. The bounds may be
+//!   written in another unit of the same dimension and converted with `to()`.
+//! - [`units_of`]: the built-in units of a dimension, from the [`catalog`].
+//!
+//! `Quantity<U, S>` also implements proptest's `Arbitrary` whenever `S` does,
+//! so `any::<Meters>()` works as well.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use qtty_core::length::{Kilometers, Meter, Meters};
+//! use qtty_core::proptest::{finite, in_range};
+//!
+//! proptest! {
+//!     fn round_trips_through_kilometers(d in finite::<Meter>()) {
+//!         prop_assert!(d.to::<qtty_core::length::Kilometer>().value().is_finite());
+//!     }
+//!
+//!     fn stays_in_range(d in in_range(Kilometers::new(0.0)..=Meters::new(500.0).to())) {
+//!         prop_assert!(d >= Kilometers::new(0.0) && d <= Kilometers::new(0.5));
+//!     }
+//! }
+//! # round_trips_through_kilometers();
+//! # stays_in_range();
+//! ```
+//!
+//! [`catalog`]: crate::catalog
+
+use crate::catalog::{self, UnitInfo};
+use crate::dimension::Dimension;
+use crate::range::QuantityRange;
+use crate::scalar::Scalar;
+use crate::{Quantity, Unit};
+use proptest::arbitrary::Arbitrary;
+use proptest::num::f64::{NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+use proptest::sample::{select, Select};
+use proptest::strategy::{BoxedStrategy, Map, Strategy};
+
+/// Any finite value in unit `U`: normal, subnormal and zero values of either
+/// sign, never NaN or infinite.
+///
+/// Values span the whole `f64` range, so converting extreme values to a much
+/// smaller unit can still overflow. Use [`in_range`] when a test needs
+/// bounded magnitudes.
+pub fn finite<U: Unit>() -> impl Strategy<Value = Quantity<U>> + Clone {
+    (POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO).prop_map(Quantity::new)
+}
+
+/// Values inside `range`, in the unit of its bounds.
+///
+/// Accepts `start..end`, `start..=end` or any [`QuantityRange`]. Bounds in
+/// different units of the same dimension can be aligned with `to()`:
+/// `in_range(Kilometers::new(1.0)..Meters::new(1500.0).to())`.
+///
+/// # Panics
+///
+/// Panics if the range is empty or has a non-finite endpoint.
+pub fn in_range<U: Unit>(range: impl Into<QuantityRange<U>>) -> BoxedStrategy<Quantity<U>> {
+    let range = range.into();
+    let (start, end) = (range.start().value(), range.end().value());
+    assert!(
+        start.is_finite() && end.is_finite(),
+        "in_range: endpoints must be finite"
+    );
+    assert!(!range.is_empty(), "in_range: empty range");
+
+    let values = if range.end_included() {
+        (start..=end).boxed()
+    } else {
+        (start..end).boxed()
+    };
+    let values = if range.start_included() {
+        values
+    } else {
+        values
+            .prop_filter("excluded start", move |v| *v != start)
+            .boxed()
+    };
+    values.prop_map(Quantity::new).boxed()
+}
+
+/// The enabled built-in units of dimension `D`, as [`catalog`] records.
+///
+/// Pair it with a value strategy to exercise conversions across every unit
+/// of a dimension through [`UnitInfo::dyn_unit`].
+///
+/// # Panics
+///
+/// Panics if no enabled built-in unit has dimension `D`.
+///
+/// [`catalog`]: crate::catalog
+pub fn units_of<D: Dimension>() -> Select<&'static UnitInfo> {
+    let units: Vec<_> = catalog::of_dimension::<D>().collect();
+    assert!(
+        !units.is_empty(),
+        "units_of: no built-in unit of this dimension"
+    );
+    select(units)
+}
+
+impl<U: Unit, S: Scalar + Arbitrary> Arbitrary for Quantity<U, S> {
+    type Parameters = S::Parameters;
+    type Strategy = Map<S::Strategy, fn(S) -> Self>;
+
+    fn arbitrary_with(args: S::Parameters) -> Self::Strategy {
+        S::arbitrary_with(args).prop_map(Quantity::new)
+    }
+}
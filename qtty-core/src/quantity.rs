@@ -3,14 +3,15 @@
 
 //! Quantity type and its implementations.
 
-use crate::scalar::{Exact, Real, Scalar, Transcendental};
-use crate::unit::Unit;
-use crate::unit_arithmetic::{QuantityDivOutput, UnitDiv, UnitMul, UnitSqrt};
+use crate::scalar::{Exact, Real, Scalar, SignedScalar, Transcendental};
+use crate::unit::{exact_conversion, Unit};
+use crate::unit_arithmetic::{QuantityDivOutput, UnitDiv, UnitMul, UnitPow, UnitRoot, UnitSqrt};
 use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
 use core::iter::Sum;
 use core::marker::PhantomData;
 use core::ops::*;
+use typenum::{Integer, P3};
 
 /// A quantity with a specific unit and scalar type.
 ///
@@ -21,6 +22,13 @@ use core::ops::*;
 /// The default scalar type is `f64`, so `Quantity<Meter>` is equivalent to
 /// `Quantity<Meter, f64>`.
 ///
+/// # Layout
+///
+/// `Quantity<U, S>` is guaranteed to be `#[repr(transparent)]` over `S`: it
+/// has the size, alignment and ABI of the bare scalar. Slices of quantities
+/// can therefore be viewed as slices of scalars without copying; see
+/// [`slice`](crate::slice).
+///
 /// # Examples
 ///
 /// Basic usage with default `f64`:
@@ -58,6 +66,14 @@ pub type Quantity64<U> = Quantity<U, f64>;
 /// A quantity backed by `f32`.
 pub type Quantity32<U> = Quantity<U, f32>;
 
+/// A quantity backed by `half::f16`.
+#[cfg(feature = "half")]
+pub type Quantity16<U> = Quantity<U, half::f16>;
+
+/// A quantity backed by `half::bf16`.
+#[cfg(feature = "half")]
+pub type QuantityBf16<U> = Quantity<U, half::bf16>;
+
 /// A quantity backed by `num_rational::Rational64`.
 #[cfg(feature = "scalar-rational")]
 pub type QuantityRational<U> = Quantity<U, num_rational::Rational64>;
@@ -77,6 +93,24 @@ pub type QuantityI64<U> = Quantity<U, i64>;
 /// A quantity backed by `i128`.
 pub type QuantityI128<U> = Quantity<U, i128>;
 
+/// A quantity backed by `u8`.
+pub type QuantityU8<U> = Quantity<U, u8>;
+
+/// A quantity backed by `u16`.
+pub type QuantityU16<U> = Quantity<U, u16>;
+
+/// A quantity backed by `u32`.
+pub type QuantityU32<U> = Quantity<U, u32>;
+
+/// A quantity backed by `u64`.
+pub type QuantityU64<U> = Quantity<U, u64>;
+
+/// A quantity backed by `u128`.
+pub type QuantityU128<U> = Quantity<U, u128>;
+
+/// A quantity backed by `usize`.
+pub type QuantityUsize<U> = Quantity<U, usize>;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Core implementation for all Scalar types
 // ─────────────────────────────────────────────────────────────────────────────
@@ -280,12 +314,9 @@ impl<U: Unit, S: Real> Quantity<U, S> {
     /// Because [`Unit::RATIO`] is always an `f64`, this ratio is computed in
     /// `f64` and then cast back to `S` via [`Scalar::from_f64`].  For
     /// floating-point scalars (`f64`, `f32`) the precision loss is negligible.
-    /// For **exact scalar types** (`Rational64`, integers) the cast is lossy:
-    /// the numeric value will be an `f64`-rounded approximation of the true
-    /// rational ratio.  See [`Exact`] for the explicit trade-off documentation.
-    ///
-    /// If you need unit conversion without the `f64` round-trip, store values
-    /// in the target unit from the start rather than converting after the fact.
+    /// **Exact scalar types** (`Rational64`, integers) convert with
+    /// [`to_lossy`](Quantity::to_lossy) instead, which uses
+    /// [`Unit::EXACT_RATIO`] when available; see [`Exact`].
     ///
     /// # Example
     ///
@@ -433,13 +464,17 @@ impl<U: Unit, S: Real> Quantity<U, S> {
 impl<U: Unit, S: Exact> Quantity<U, S> {
     /// Converts this quantity to another unit of the same dimension (lossy).
     ///
-    /// For integer scalars this performs the conversion through `f64` intermediate
-    /// arithmetic, then truncates back to the integer type. The result may lose
+    /// When both units define an [`EXACT_RATIO`](Unit::EXACT_RATIO) the value
+    /// is multiplied by the exact fraction with integer arithmetic, so
+    /// rationals convert exactly and integers are only truncated. Otherwise the
+    /// conversion goes through `f64`. In both cases the result may lose
     /// precision due to:
     ///
-    /// - **Truncation toward zero** for fractional results (e.g. `1500 m → 1 km`).
-    /// - **Saturation at integer bounds** when the converted value exceeds the
-    ///   target type's range (e.g. `1 km → 127 m` for `i8`).
+    /// - **Truncation toward zero** for fractional integer results
+    ///   (e.g. `1500 m → 1 km`).
+    /// - **Saturation at the type's bounds** when the converted value exceeds
+    ///   the target type's range (e.g. `1 km → 127 m` for `i8`).
+    /// - **`f64` rounding** when either unit has no exact ratio (e.g. radians).
     ///
     /// Use [`checked_to_lossy`](Self::checked_to_lossy) if you need to detect
     /// range overflow.
@@ -449,13 +484,22 @@ impl<U: Unit, S: Exact> Quantity<U, S> {
     /// ```rust
     /// use qtty_core::Quantity;
     /// use qtty_core::length::{Meter, Kilometer};
+    /// use qtty_core::time::{Nanosecond, Day};
     ///
     /// let m: Quantity<Meter, i32> = Quantity::new(1500);
     /// let km: Quantity<Kilometer, i32> = m.to_lossy();
     /// assert_eq!(km.value(), 1); // truncated from 1.5
+    ///
+    /// // 10⁵ days in nanoseconds exceeds 2⁵³ but is still exact.
+    /// let days: Quantity<Day, i64> = Quantity::new(100_000);
+    /// let ns: Quantity<Nanosecond, i64> = days.to_lossy();
+    /// assert_eq!(ns.value(), 8_640_000_000_000_000_000);
     /// ```
     #[inline]
     pub fn to_lossy<T: Unit<Dim = U::Dim>>(self) -> Quantity<T, S> {
+        if let Some(ratio) = exact_conversion(U::EXACT_RATIO, T::EXACT_RATIO) {
+            return Quantity::<T, S>::new(self.0.saturating_mul_ratio(ratio));
+        }
         let ratio = U::RATIO / T::RATIO;
         // Same-ratio fast path: skip the f64 round-trip entirely.
         // Without this, large integer values (e.g. near i64::MAX) would be
@@ -490,6 +534,9 @@ impl<U: Unit, S: Exact> Quantity<U, S> {
     /// ```
     #[inline]
     pub fn checked_to_lossy<T: Unit<Dim = U::Dim>>(self) -> Option<Quantity<T, S>> {
+        if let Some(ratio) = exact_conversion(U::EXACT_RATIO, T::EXACT_RATIO) {
+            return self.0.checked_mul_ratio(ratio).map(Quantity::<T, S>::new);
+        }
         let ratio = U::RATIO / T::RATIO;
         // Same-ratio fast path: the value is unchanged, so always in range.
         // Without this, large integers near i64::MAX would round-trip through
@@ -631,7 +678,7 @@ impl<U: Unit + Copy> Quantity<U, f32> {
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Const methods for signed integer types
+// Const methods for integer types
 // ─────────────────────────────────────────────────────────────────────────────
 
 macro_rules! impl_const_for_int {
@@ -684,7 +731,7 @@ macro_rules! impl_const_for_int {
     )* };
 }
 
-impl_const_for_int!(i8, i16, i32, i64, i128);
+impl_const_for_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize);
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Operator implementations
@@ -782,7 +829,7 @@ impl<U: Unit, S: Scalar> DivAssign<S> for Quantity<U, S> {
     }
 }
 
-impl<U: Unit, S: Scalar> Neg for Quantity<U, S> {
+impl<U: Unit, S: SignedScalar> Neg for Quantity<U, S> {
     type Output = Self;
     #[inline]
     fn neg(self) -> Self {
@@ -808,6 +855,25 @@ impl<U: Unit> Mul<Quantity<U, f32>> for f32 {
     }
 }
 
+// Multiplication for half-precision floats (feature-gated)
+#[cfg(feature = "half")]
+impl<U: Unit> Mul<Quantity<U, half::f16>> for half::f16 {
+    type Output = Quantity<U, half::f16>;
+    #[inline]
+    fn mul(self, rhs: Quantity<U, half::f16>) -> Self::Output {
+        rhs * self
+    }
+}
+
+#[cfg(feature = "half")]
+impl<U: Unit> Mul<Quantity<U, half::bf16>> for half::bf16 {
+    type Output = Quantity<U, half::bf16>;
+    #[inline]
+    fn mul(self, rhs: Quantity<U, half::bf16>) -> Self::Output {
+        rhs * self
+    }
+}
+
 // Multiplication for Rational64 (feature-gated)
 #[cfg(feature = "scalar-rational")]
 impl<U: Unit> Mul<Quantity<U, num_rational::Rational64>> for num_rational::Rational64 {
@@ -828,7 +894,7 @@ impl<U: Unit> Mul<Quantity<U, num_rational::Rational32>> for num_rational::Ratio
     }
 }
 
-// Commutative multiplication for signed integer scalars
+// Commutative multiplication for integer scalars
 macro_rules! impl_int_commutative_mul {
     ($($t:ty),*) => { $(
         impl<U: Unit> Mul<Quantity<U, $t>> for $t {
@@ -841,7 +907,7 @@ macro_rules! impl_int_commutative_mul {
     )* };
 }
 
-impl_int_commutative_mul!(i8, i16, i32, i64, i128);
+impl_int_commutative_mul!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize);
 
 // Rem for types that implement Rem (floats and integers)
 impl<U: Unit, S: Scalar + Rem<Output = S>> Rem<S> for Quantity<U, S> {
@@ -990,6 +1056,101 @@ where
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Dimensionally-typed integer powers and roots
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<U: Unit, S: Real> Quantity<U, S> {
+    /// Raises the quantity to the type-level integer power `N`.
+    ///
+    /// The result unit is given by [`UnitPow`]: `P2` and `P3` produce the same
+    /// `Prod` shapes as repeated multiplication, other exponents (including
+    /// negative ones) produce a [`Pow<U, N>`](crate::Pow).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use qtty_core::area::SquareMeters;
+    /// use qtty_core::length::Meters;
+    /// use qtty_core::time::{Second, Seconds};
+    /// use qtty_core::typenum::{N1, P2};
+    /// use qtty_core::{Pow, Quantity};
+    ///
+    /// let area: SquareMeters = Meters::new(3.0).powi::<P2>();
+    /// assert_eq!(area.value(), 9.0);
+    ///
+    /// let per_second: Quantity<Pow<Second, N1>> = Seconds::new(4.0).powi::<N1>();
+    /// assert_eq!(per_second.value(), 0.25);
+    /// ```
+    #[inline]
+    pub fn powi<N: Integer>(self) -> Quantity<<U as UnitPow<N>>::Output, S>
+    where
+        U: UnitPow<N>,
+    {
+        Quantity::new(self.0.powi(N::I32))
+    }
+
+    /// Dimensionally-typed cube root.
+    ///
+    /// Available for any unit that is a cube according to [`UnitRoot`]:
+    /// `Pow<R, P3>`, `Prod<Prod<R, R>, R>`, or a named volume such as
+    /// [`CubicMeter`](crate::volume::CubicMeter). Unlike [`sqrt`](Self::sqrt),
+    /// negative values have a real cube root and keep their sign.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meter;
+    /// use qtty_core::volume::CubicMeters;
+    /// use qtty_core::Quantity;
+    ///
+    /// let side: Quantity<Meter> = CubicMeters::new(27.0).cbrt();
+    /// assert!((side.value() - 3.0).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn cbrt(self) -> Quantity<<U as UnitRoot<P3>>::Root, S>
+    where
+        U: UnitRoot<P3>,
+    {
+        Quantity::new(self.0.cbrt())
+    }
+
+    /// Dimensionally-typed `N`-th root.
+    ///
+    /// Generalises [`sqrt`](Self::sqrt) and [`cbrt`](Self::cbrt) to any
+    /// exponent `N` for which `U` implements [`UnitRoot<N>`](UnitRoot).
+    /// Odd roots of negative values are negative; even roots of negative
+    /// values are NaN, as with [`sqrt`](Self::sqrt).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use qtty_core::length::{Meter, Meters};
+    /// use qtty_core::typenum::{P2, P4};
+    /// use qtty_core::Quantity;
+    ///
+    /// let hyper = Meters::new(2.0).powi::<P4>();
+    /// let side: Quantity<Meter> = hyper.root::<P4>();
+    /// assert!((side.value() - 2.0).abs() < 1e-12);
+    ///
+    /// let area = hyper.root::<P2>();
+    /// assert!((area.value() - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn root<N: Integer>(self) -> Quantity<<U as UnitRoot<N>>::Root, S>
+    where
+        U: UnitRoot<N>,
+    {
+        let value = match N::I32 {
+            1 => self.0,
+            2 => self.0.sqrt(),
+            3 => self.0.cbrt(),
+            n if n % 2 != 0 && self.0 < S::ZERO => -(-self.0).powf(S::from_f64(1.0 / f64::from(n))),
+            n => self.0.powf(S::from_f64(1.0 / f64::from(n))),
+        };
+        Quantity::new(value)
+    }
+}
+
 impl<U, S> Quantity<U, S>
 where
     U: Unit<Dim = crate::dimension::Dimensionless>,
@@ -0,0 +1,277 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Owning, contiguous buffer of quantities.
+
+use crate::scalar::{Real, Scalar};
+use crate::slice::{from_values, from_values_mut, scale_in_place};
+use crate::{Quantity, Unit};
+use core::fmt;
+use core::iter::{FromIterator, Map};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::{IntoIter, Vec};
+#[cfg(feature = "std")]
+use std::vec::{IntoIter, Vec};
+
+/// A growable buffer of quantities in the unit `U`, stored as plain `S`
+/// values.
+///
+/// `QuantityVec` dereferences to `[Quantity<U, S>]`, so indexing, iteration
+/// and slice methods work as on a `Vec<Quantity<U, S>>`. Unit conversion with
+/// [`to`](Self::to) reuses the allocation and runs as a single loop over the
+/// scalars.
+///
+/// ```rust
+/// use qtty_core::length::{Kilometer, Meter, Meters};
+/// use qtty_core::QuantityVec;
+///
+/// let distances: QuantityVec<Meter> = QuantityVec::from_values(vec![1500.0, 250.0]);
+/// assert_eq!(distances[0], Meters::new(1500.0));
+///
+/// let km = distances.to::<Kilometer>();
+/// assert_eq!(km.as_values(), &[1.5, 0.25]);
+/// ```
+pub struct QuantityVec<U: Unit, S: Scalar = f64> {
+    values: Vec<S>,
+    _unit: PhantomData<U>,
+}
+
+impl<U: Unit, S: Scalar> QuantityVec<U, S> {
+    /// Creates an empty buffer.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::from_values(Vec::new())
+    }
+
+    /// Creates an empty buffer with room for `capacity` quantities.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_values(Vec::with_capacity(capacity))
+    }
+
+    /// Wraps raw values in unit `U`, without copying.
+    #[inline]
+    pub const fn from_values(values: Vec<S>) -> Self {
+        Self {
+            values,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Unwraps the raw values in unit `U`, without copying.
+    #[inline]
+    pub fn into_values(self) -> Vec<S> {
+        self.values
+    }
+
+    /// The raw values in unit `U`.
+    #[inline]
+    pub fn as_values(&self) -> &[S] {
+        &self.values
+    }
+
+    /// The raw values in unit `U`, mutably.
+    #[inline]
+    pub fn as_values_mut(&mut self) -> &mut [S] {
+        &mut self.values
+    }
+
+    /// The quantities as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[Quantity<U, S>] {
+        from_values(&self.values)
+    }
+
+    /// The quantities as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [Quantity<U, S>] {
+        from_values_mut(&mut self.values)
+    }
+
+    /// Appends a quantity.
+    #[inline]
+    pub fn push(&mut self, quantity: Quantity<U, S>) {
+        self.values.push(quantity.value());
+    }
+
+    /// Removes and returns the last quantity, if any.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Quantity<U, S>> {
+        self.values.pop().map(Quantity::new)
+    }
+
+    /// Removes every quantity, keeping the allocation.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// Reserves room for at least `additional` more quantities.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+    }
+}
+
+impl<U: Unit, S: Real> QuantityVec<U, S> {
+    /// Converts every quantity to unit `T`, reusing the allocation.
+    ///
+    /// See [`convert_slice_in_place`](crate::slice::convert_slice_in_place).
+    #[inline]
+    pub fn to<T: Unit<Dim = U::Dim>>(mut self) -> QuantityVec<T, S> {
+        scale_in_place(&mut self.values, S::from_f64(U::RATIO / T::RATIO));
+        QuantityVec::from_values(self.values)
+    }
+}
+
+impl<U: Unit, S: Scalar> Deref for QuantityVec<U, S> {
+    type Target = [Quantity<U, S>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<U: Unit, S: Scalar> DerefMut for QuantityVec<U, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<U: Unit, S: Scalar> Clone for QuantityVec<U, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_values(self.values.clone())
+    }
+}
+
+impl<U: Unit, S: Scalar> Default for QuantityVec<U, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U: Unit, S: Scalar> PartialEq for QuantityVec<U, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl<U: Unit, S: Scalar> fmt::Debug for QuantityVec<U, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<U: Unit, S: Scalar> From<Vec<Quantity<U, S>>> for QuantityVec<U, S> {
+    #[inline]
+    fn from(quantities: Vec<Quantity<U, S>>) -> Self {
+        quantities.into_iter().collect()
+    }
+}
+
+impl<U: Unit, S: Scalar, const N: usize> From<[Quantity<U, S>; N]> for QuantityVec<U, S> {
+    #[inline]
+    fn from(quantities: [Quantity<U, S>; N]) -> Self {
+        quantities.into_iter().collect()
+    }
+}
+
+impl<U: Unit, S: Scalar> From<QuantityVec<U, S>> for Vec<Quantity<U, S>> {
+    #[inline]
+    fn from(quantities: QuantityVec<U, S>) -> Self {
+        quantities.into_iter().collect()
+    }
+}
+
+impl<U: Unit, S: Scalar> FromIterator<Quantity<U, S>> for QuantityVec<U, S> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Quantity<U, S>>>(iter: I) -> Self {
+        Self::from_values(iter.into_iter().map(Quantity::value).collect())
+    }
+}
+
+impl<U: Unit, S: Scalar> Extend<Quantity<U, S>> for QuantityVec<U, S> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Quantity<U, S>>>(&mut self, iter: I) {
+        self.values.extend(iter.into_iter().map(Quantity::value));
+    }
+}
+
+impl<U: Unit, S: Scalar> IntoIterator for QuantityVec<U, S> {
+    type Item = Quantity<U, S>;
+    type IntoIter = Map<IntoIter<S>, fn(S) -> Quantity<U, S>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter().map(Quantity::new)
+    }
+}
+
+impl<'a, U: Unit, S: Scalar> IntoIterator for &'a QuantityVec<U, S> {
+    type Item = &'a Quantity<U, S>;
+    type IntoIter = core::slice::Iter<'a, Quantity<U, S>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, U: Unit, S: Scalar> IntoIterator for &'a mut QuantityVec<U, S> {
+    type Item = &'a mut Quantity<U, S>;
+    type IntoIter = core::slice::IterMut<'a, Quantity<U, S>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::length::{Kilometer, Kilometers, Meter, Meters};
+
+    #[test]
+    fn behaves_like_a_vec() {
+        let mut v = QuantityVec::<Meter>::with_capacity(2);
+        v.push(Meters::new(1.0));
+        v.extend([Meters::new(2.0), Meters::new(3.0)]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[1], Meters::new(2.0));
+        v[1] = Meters::new(5.0);
+        assert_eq!(v.iter().copied().sum::<Meters>(), Meters::new(9.0));
+        assert_eq!(v.pop(), Some(Meters::new(3.0)));
+        assert_eq!(
+            format!("{v:?}"),
+            format!("{:?}", [Meters::new(1.0), Meters::new(5.0)])
+        );
+        v.clear();
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn converts_whole_buffer() {
+        let v: QuantityVec<Kilometer> = [Kilometers::new(1.0), Kilometers::new(0.5)].into();
+        let m = v.clone().to::<Meter>();
+        assert_eq!(m.as_values(), &[1000.0, 500.0]);
+        assert_eq!(m.to::<Kilometer>(), v);
+    }
+
+    #[test]
+    fn round_trips_through_vec() {
+        let quantities = vec![Meters::new(1.0), Meters::new(2.0)];
+        let v = QuantityVec::from(quantities.clone());
+        let back: Vec<Meters> = v.clone().into();
+        assert_eq!(back, quantities);
+        assert_eq!(v.into_values(), vec![1.0, 2.0]);
+    }
+}
@@ -0,0 +1,598 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Intervals of quantities.
+//!
+//! [`QuantityRange<U, S>`] is a finite interval whose endpoints are either
+//! included (closed) or excluded (open). It answers containment questions for
+//! any unit of the same dimension and samples itself with [`step_by`] or
+//! [`linspace`].
+//!
+//! ```rust
+//! use qtty_core::length::{Micrometers, Nanometers};
+//! use qtty_core::QuantityRange;
+//!
+//! let visible = QuantityRange::closed(Nanometers::new(380.0), Nanometers::new(750.0));
+//! assert!(visible.contains(Micrometers::new(0.55)));
+//!
+//! let band = QuantityRange::closed_open(Nanometers::new(700.0), Nanometers::new(900.0));
+//! let red = visible.intersection(&band).unwrap();
+//! assert_eq!(red.to_string(), "[700 nm, 750 nm]");
+//!
+//! let samples: Vec<_> = red.step_by(Nanometers::new(25.0)).collect();
+//! assert_eq!(samples.len(), 3);
+//! assert_eq!(samples[2], Nanometers::new(750.0));
+//! ```
+//!
+//! [`step_by`]: QuantityRange::step_by
+//! [`linspace`]: QuantityRange::linspace
+
+use crate::scalar::Real;
+use crate::unit::write_unit_suffix;
+use crate::{Quantity, Unit};
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
+use core::ops::{Range, RangeInclusive};
+
+/// A finite interval of quantities with open or closed endpoints.
+///
+/// A range whose start lies after its end (or that excludes its only point)
+/// is empty: it contains nothing and yields no samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuantityRange<U: Unit, S: Real = f64> {
+    start: Quantity<U, S>,
+    end: Quantity<U, S>,
+    start_included: bool,
+    end_included: bool,
+}
+
+impl<U: Unit, S: Real> QuantityRange<U, S> {
+    /// Creates a range from its endpoints and whether each one is included.
+    #[inline]
+    pub const fn new(
+        start: Quantity<U, S>,
+        start_included: bool,
+        end: Quantity<U, S>,
+        end_included: bool,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            start_included,
+            end_included,
+        }
+    }
+
+    /// `[start, end]`: both endpoints included.
+    #[inline]
+    pub const fn closed(start: Quantity<U, S>, end: Quantity<U, S>) -> Self {
+        Self::new(start, true, end, true)
+    }
+
+    /// `(start, end)`: both endpoints excluded.
+    #[inline]
+    pub const fn open(start: Quantity<U, S>, end: Quantity<U, S>) -> Self {
+        Self::new(start, false, end, false)
+    }
+
+    /// `[start, end)`: start included, end excluded.
+    #[inline]
+    pub const fn closed_open(start: Quantity<U, S>, end: Quantity<U, S>) -> Self {
+        Self::new(start, true, end, false)
+    }
+
+    /// `(start, end]`: start excluded, end included.
+    #[inline]
+    pub const fn open_closed(start: Quantity<U, S>, end: Quantity<U, S>) -> Self {
+        Self::new(start, false, end, true)
+    }
+
+    /// The lower endpoint.
+    #[inline]
+    pub const fn start(&self) -> Quantity<U, S> {
+        self.start
+    }
+
+    /// The upper endpoint.
+    #[inline]
+    pub const fn end(&self) -> Quantity<U, S> {
+        self.end
+    }
+
+    /// Whether the lower endpoint belongs to the range.
+    #[inline]
+    pub const fn start_included(&self) -> bool {
+        self.start_included
+    }
+
+    /// Whether the upper endpoint belongs to the range.
+    #[inline]
+    pub const fn end_included(&self) -> bool {
+        self.end_included
+    }
+
+    /// Whether the range contains no value. Ranges with a NaN endpoint are
+    /// empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        match self.start.partial_cmp(&self.end) {
+            Some(Ordering::Less) => false,
+            Some(Ordering::Equal) => !(self.start_included && self.end_included),
+            _ => true,
+        }
+    }
+
+    /// `end − start`, or zero for an empty range.
+    #[inline]
+    pub fn length(&self) -> Quantity<U, S> {
+        if self.is_empty() {
+            Quantity::new(S::ZERO)
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// Whether `value` lies in the range. `value` may use any unit of the same
+    /// dimension; it is compared with [`Quantity::cmp_unit`], like the
+    /// cross-unit `<`/`>` operators.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::{Degrees, Radians};
+    /// use qtty_core::QuantityRange;
+    ///
+    /// let above_horizon = QuantityRange::open_closed(Degrees::new(0.0), Degrees::new(90.0));
+    /// assert!(above_horizon.contains(Radians::new(0.5)));
+    /// assert!(!above_horizon.contains(Degrees::new(0.0)));
+    /// ```
+    #[inline]
+    pub fn contains<V: Unit<Dim = U::Dim>>(&self, value: Quantity<V, S>) -> bool {
+        let after_start = match self.start.cmp_unit(&value) {
+            Some(Ordering::Less) => true,
+            Some(Ordering::Equal) => self.start_included,
+            _ => false,
+        };
+        after_start
+            && match self.end.cmp_unit(&value) {
+                Some(Ordering::Greater) => true,
+                Some(Ordering::Equal) => self.end_included,
+                _ => false,
+            }
+    }
+
+    /// The values that lie in both ranges, or `None` if they are disjoint.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (start, start_included) = pick_start(self, other, Ordering::Greater, |a, b| a && b);
+        let (end, end_included) = pick_end(self, other, Ordering::Less, |a, b| a && b);
+        let range = Self::new(start, start_included, end, end_included);
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// The smallest range that contains both ranges (and any gap between
+    /// them). An empty range contributes nothing.
+    #[inline]
+    pub fn union_hull(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let (start, start_included) = pick_start(self, other, Ordering::Less, |a, b| a || b);
+        let (end, end_included) = pick_end(self, other, Ordering::Greater, |a, b| a || b);
+        Self::new(start, start_included, end, end_included)
+    }
+
+    /// Whether the two ranges share at least one value.
+    #[inline]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Restricts `value` to `[start, end]`.
+    ///
+    /// Excluded endpoints cannot be represented by a nearest value, so they
+    /// are returned as-is. The result is unspecified for an empty range.
+    #[inline]
+    pub fn clamp(&self, value: Quantity<U, S>) -> Quantity<U, S> {
+        value.max(self.start).min(self.end)
+    }
+
+    /// Converts both endpoints to unit `T`, keeping their inclusivity.
+    #[inline]
+    pub fn to<T: Unit<Dim = U::Dim>>(&self) -> QuantityRange<T, S> {
+        QuantityRange::new(
+            self.start.to(),
+            self.start_included,
+            self.end.to(),
+            self.end_included,
+        )
+    }
+
+    /// Iterates over `start, start + step, start + 2·step, …` while the values
+    /// stay in the range. An excluded start is skipped.
+    ///
+    /// `step` may use any unit of the same dimension. Each sample is computed
+    /// as `start + i·step`, so rounding errors do not accumulate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not strictly positive.
+    ///
+    /// ```rust
+    /// use qtty_core::time::{Minutes, Seconds};
+    /// use qtty_core::QuantityRange;
+    ///
+    /// let window = QuantityRange::closed_open(Seconds::new(0.0), Seconds::new(180.0));
+    /// let starts: Vec<_> = window.step_by(Minutes::new(1.0)).collect();
+    /// assert_eq!(starts, [Seconds::new(0.0), Seconds::new(60.0), Seconds::new(120.0)]);
+    /// ```
+    #[inline]
+    pub fn step_by<V: Unit<Dim = U::Dim>>(&self, step: Quantity<V, S>) -> RangeStepBy<U, S> {
+        let step = step.to::<U>();
+        assert!(
+            step.value() > S::ZERO,
+            "QuantityRange::step_by requires a positive step"
+        );
+        RangeStepBy {
+            range: *self,
+            step,
+            index: if self.start_included { 0 } else { 1 },
+            done: false,
+        }
+    }
+
+    /// Iterates over `n` evenly spaced values spanning the range.
+    ///
+    /// For a closed range the first and last samples are the endpoints. An
+    /// excluded endpoint is not sampled: the spacing is chosen as if it were
+    /// one extra sample. An empty range yields nothing.
+    ///
+    /// ```rust
+    /// use qtty_core::length::Meters;
+    /// use qtty_core::QuantityRange;
+    ///
+    /// let closed = QuantityRange::closed(Meters::new(0.0), Meters::new(1.0));
+    /// let xs: Vec<f64> = closed.linspace(5).map(|x| x.value()).collect();
+    /// assert_eq!(xs, [0.0, 0.25, 0.5, 0.75, 1.0]);
+    ///
+    /// let open = QuantityRange::open(Meters::new(0.0), Meters::new(1.0));
+    /// let xs: Vec<f64> = open.linspace(3).map(|x| x.value()).collect();
+    /// assert_eq!(xs, [0.25, 0.5, 0.75]);
+    /// ```
+    #[inline]
+    pub fn linspace(&self, n: usize) -> RangeLinspace<U, S> {
+        let n = if self.is_empty() { 0 } else { n };
+        let offset = usize::from(!self.start_included);
+        let intervals = (n + offset + usize::from(!self.end_included)).saturating_sub(1);
+        RangeLinspace {
+            start: self.start,
+            length: self.end - self.start,
+            intervals,
+            offset,
+            front: 0,
+            back: n,
+        }
+    }
+}
+
+/// Picks the start of a combined range: the endpoint that wins under
+/// `prefer`, with inclusivity merged by `merge` when both endpoints coincide.
+#[inline]
+fn pick_start<U: Unit, S: Real>(
+    a: &QuantityRange<U, S>,
+    b: &QuantityRange<U, S>,
+    prefer: Ordering,
+    merge: fn(bool, bool) -> bool,
+) -> (Quantity<U, S>, bool) {
+    match a.start.partial_cmp(&b.start) {
+        Some(Ordering::Equal) => (a.start, merge(a.start_included, b.start_included)),
+        Some(ordering) if ordering == prefer => (a.start, a.start_included),
+        Some(_) => (b.start, b.start_included),
+        None => (Quantity::new(S::NAN), false),
+    }
+}
+
+/// Same as [`pick_start`], for the end of a combined range.
+#[inline]
+fn pick_end<U: Unit, S: Real>(
+    a: &QuantityRange<U, S>,
+    b: &QuantityRange<U, S>,
+    prefer: Ordering,
+    merge: fn(bool, bool) -> bool,
+) -> (Quantity<U, S>, bool) {
+    match a.end.partial_cmp(&b.end) {
+        Some(Ordering::Equal) => (a.end, merge(a.end_included, b.end_included)),
+        Some(ordering) if ordering == prefer => (a.end, a.end_included),
+        Some(_) => (b.end, b.end_included),
+        None => (Quantity::new(S::NAN), false),
+    }
+}
+
+impl<U: Unit, S: Real> From<RangeInclusive<Quantity<U, S>>> for QuantityRange<U, S> {
+    /// `a..=b` becomes the closed range `[a, b]`.
+    #[inline]
+    fn from(range: RangeInclusive<Quantity<U, S>>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::closed(start, end)
+    }
+}
+
+impl<U: Unit, S: Real> From<Range<Quantity<U, S>>> for QuantityRange<U, S> {
+    /// `a..b` becomes the half-open range `[a, b)`.
+    #[inline]
+    fn from(range: Range<Quantity<U, S>>) -> Self {
+        Self::closed_open(range.start, range.end)
+    }
+}
+
+/// Prints `[1 m, 5 m)`-style interval notation. Format flags apply to both
+/// endpoint values.
+impl<U: Unit, S: Real> Display for QuantityRange<U, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.start_included { "[" } else { "(" })?;
+        Display::fmt(&self.start.value(), f)?;
+        write_unit_suffix::<U>(f)?;
+        f.write_str(", ")?;
+        Display::fmt(&self.end.value(), f)?;
+        write_unit_suffix::<U>(f)?;
+        f.write_str(if self.end_included { "]" } else { ")" })
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Iterators
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Iterator returned by [`QuantityRange::step_by`].
+#[derive(Clone, Debug)]
+pub struct RangeStepBy<U: Unit, S: Real = f64> {
+    range: QuantityRange<U, S>,
+    step: Quantity<U, S>,
+    index: u64,
+    done: bool,
+}
+
+impl<U: Unit, S: Real> Iterator for RangeStepBy<U, S> {
+    type Item = Quantity<U, S>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let value = self.range.start + self.step * S::from_f64(self.index as f64);
+        if self.range.contains(value) {
+            self.index += 1;
+            Some(value)
+        } else {
+            self.done = true;
+            None
+        }
+    }
+}
+
+impl<U: Unit, S: Real> FusedIterator for RangeStepBy<U, S> {}
+
+/// Iterator returned by [`QuantityRange::linspace`].
+#[derive(Clone, Debug)]
+pub struct RangeLinspace<U: Unit, S: Real = f64> {
+    start: Quantity<U, S>,
+    length: Quantity<U, S>,
+    intervals: usize,
+    offset: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<U: Unit, S: Real> RangeLinspace<U, S> {
+    #[inline]
+    fn sample(&self, index: usize) -> Quantity<U, S> {
+        if self.intervals == 0 {
+            return self.start;
+        }
+        let position = index + self.offset;
+        if position == self.intervals {
+            // Land exactly on the end point instead of `start + length`.
+            return self.start + self.length;
+        }
+        let fraction = S::from_f64(position as f64) / S::from_f64(self.intervals as f64);
+        self.start + self.length * fraction
+    }
+}
+
+impl<U: Unit, S: Real> Iterator for RangeLinspace<U, S> {
+    type Item = Quantity<U, S>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let value = self.sample(self.front);
+        self.front += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<U: Unit, S: Real> DoubleEndedIterator for RangeLinspace<U, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.sample(self.back))
+    }
+}
+
+impl<U: Unit, S: Real> ExactSizeIterator for RangeLinspace<U, S> {}
+
+impl<U: Unit, S: Real> FusedIterator for RangeLinspace<U, S> {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::angular::{Degree, Degrees, Radians};
+    use crate::length::{Kilometers, Meter, Meters};
+    use approx::assert_relative_eq;
+
+    fn m(value: f64) -> Meters {
+        Meters::new(value)
+    }
+
+    // ── Bounds and containment ──
+
+    #[test]
+    fn contains_respects_bounds() {
+        let closed = QuantityRange::closed(m(1.0), m(2.0));
+        let open = QuantityRange::open(m(1.0), m(2.0));
+        assert!(closed.contains(m(1.0)) && closed.contains(m(2.0)));
+        assert!(!open.contains(m(1.0)) && !open.contains(m(2.0)));
+        assert!(open.contains(m(1.5)));
+        assert!(QuantityRange::closed_open(m(1.0), m(2.0)).contains(m(1.0)));
+        assert!(!QuantityRange::closed_open(m(1.0), m(2.0)).contains(m(2.0)));
+        assert!(!closed.contains(Meters::new(f64::NAN)));
+    }
+
+    #[test]
+    fn contains_accepts_other_units() {
+        let range = QuantityRange::closed(m(500.0), m(1500.0));
+        assert!(range.contains(Kilometers::new(1.0)));
+        assert!(range.contains(Kilometers::new(1.5)));
+        assert!(!range.contains(Kilometers::new(1.6)));
+    }
+
+    #[test]
+    fn emptiness() {
+        assert!(QuantityRange::closed(m(2.0), m(1.0)).is_empty());
+        assert!(QuantityRange::closed_open(m(1.0), m(1.0)).is_empty());
+        assert!(!QuantityRange::closed(m(1.0), m(1.0)).is_empty());
+        assert_eq!(QuantityRange::closed(m(2.0), m(1.0)).length(), m(0.0));
+        assert_eq!(QuantityRange::open(m(1.0), m(4.0)).length(), m(3.0));
+    }
+
+    #[test]
+    fn from_std_ranges() {
+        assert_eq!(
+            QuantityRange::from(m(1.0)..=m(2.0)),
+            QuantityRange::closed(m(1.0), m(2.0))
+        );
+        assert_eq!(
+            QuantityRange::from(m(1.0)..m(2.0)),
+            QuantityRange::closed_open(m(1.0), m(2.0))
+        );
+    }
+
+    // ── Set operations ──
+
+    #[test]
+    fn intersection_and_overlap() {
+        let a = QuantityRange::closed(m(0.0), m(10.0));
+        let b = QuantityRange::open_closed(m(5.0), m(20.0));
+        assert_eq!(
+            a.intersection(&b),
+            Some(QuantityRange::open_closed(m(5.0), m(10.0)))
+        );
+        assert!(a.overlaps(&b));
+
+        let touching = QuantityRange::open(m(10.0), m(20.0));
+        assert_eq!(a.intersection(&touching), None);
+        assert!(!a.overlaps(&touching));
+        let sharing = QuantityRange::closed(m(10.0), m(20.0));
+        assert_eq!(
+            a.intersection(&sharing),
+            Some(QuantityRange::closed(m(10.0), m(10.0)))
+        );
+    }
+
+    #[test]
+    fn union_hull_spans_both() {
+        let a = QuantityRange::closed_open(m(0.0), m(1.0));
+        let b = QuantityRange::open(m(3.0), m(4.0));
+        assert_eq!(a.union_hull(&b), QuantityRange::closed_open(m(0.0), m(4.0)));
+        let c = QuantityRange::closed(m(3.0), m(4.0));
+        assert_eq!(b.union_hull(&c), c);
+        let empty = QuantityRange::open(m(9.0), m(9.0));
+        assert_eq!(empty.union_hull(&a), a);
+    }
+
+    #[test]
+    fn clamp_and_conversion() {
+        let range = QuantityRange::closed(Degrees::new(-90.0), Degrees::new(90.0));
+        assert_eq!(range.clamp(Degrees::new(120.0)), Degrees::new(90.0));
+        assert_eq!(range.clamp(Degrees::new(-5.0)), Degrees::new(-5.0));
+        let radians = range.to::<crate::angular::Radian>();
+        assert_relative_eq!(radians.end().value(), core::f64::consts::FRAC_PI_2);
+        assert!(radians.to::<Degree>().contains(Radians::new(1.0)));
+    }
+
+    // ── Sampling ──
+
+    #[test]
+    fn step_by_stops_at_end() {
+        let values: Vec<_> = QuantityRange::closed(m(0.0), m(1.0))
+            .step_by(Meters::new(0.3))
+            .map(|x| x.value())
+            .collect();
+        assert_eq!(values.len(), 4);
+        assert_relative_eq!(values[3], 0.9, epsilon = 1e-12);
+
+        let skipped: Vec<_> = QuantityRange::open(m(0.0), m(3.0))
+            .step_by(m(1.0))
+            .collect();
+        assert_eq!(skipped, [m(1.0), m(2.0)]);
+        assert_eq!(
+            QuantityRange::closed(m(1.0), m(0.0))
+                .step_by(m(1.0))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn step_by_converts_step() {
+        let range = QuantityRange::closed(m(0.0), m(2000.0));
+        assert_eq!(range.step_by(Kilometers::new(1.0)).count(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "positive step")]
+    fn step_by_rejects_zero_step() {
+        let _ = QuantityRange::closed(m(0.0), m(1.0)).step_by(m(0.0));
+    }
+
+    #[test]
+    fn linspace_samples() {
+        let range = QuantityRange::closed(m(1.0), m(3.0));
+        let values: Vec<_> = range.linspace(3).collect();
+        assert_eq!(values, [m(1.0), m(2.0), m(3.0)]);
+        assert_eq!(range.linspace(1).collect::<Vec<_>>(), [m(1.0)]);
+        assert_eq!(range.linspace(0).len(), 0);
+
+        let reversed: Vec<_> = range.linspace(3).rev().collect();
+        assert_eq!(reversed, [m(3.0), m(2.0), m(1.0)]);
+
+        let half_open: Vec<_> = QuantityRange::closed_open(m(0.0), m(1.0))
+            .linspace(4)
+            .collect();
+        assert_eq!(half_open, [m(0.0), m(0.25), m(0.5), m(0.75)]);
+        assert_eq!(QuantityRange::open(m(1.0), m(1.0)).linspace(5).count(), 0);
+    }
+
+    // ── Display ──
+
+    #[test]
+    fn display_uses_interval_notation() {
+        let range = QuantityRange::<Meter>::open_closed(m(1.0), m(2.5));
+        assert_eq!(range.to_string(), "(1 m, 2.5 m]");
+        assert_eq!(format!("{range:.1}"), "(1.0 m, 2.5 m]");
+    }
+}
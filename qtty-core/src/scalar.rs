@@ -4,19 +4,24 @@
 //! Scalar traits for quantity values.
 //!
 //! This module defines the trait hierarchy for numeric types that can be used as the
-//! underlying storage for [`Quantity`](crate::Quantity). The traits are sealed to prevent
-//! external implementations, ensuring consistent behavior across the crate.
+//! underlying storage for [`Quantity`](crate::Quantity). The traits are open: downstream
+//! crates may implement them for their own numeric types (see
+//! [Custom scalar types](#custom-scalar-types)).
 //!
 //! # Trait Hierarchy
 //!
 //! ```text
 //! Scalar (basic arithmetic, copy, partial ordering)
 //!    │
-//!    ├── Real (floating-point-like: from_f64, to_f64, constants like PI)
+//!    ├── SignedScalar (negation; implemented for every `Scalar + Neg`)
 //!    │      │
-//!    │      └── Transcendental (sin, cos, sqrt, etc. - requires std or libm)
+//!    │      └── Real (floating-point-like: from_f64, to_f64, constants like PI)
+//!    │             │
+//!    │             └── Transcendental (sin, cos, sqrt, etc. - requires std or libm)
 //!    │
 //!    └── Exact (exact scalar families - no floating-only ops)
+//!           │
+//!           └── IntegerScalar (signed and unsigned primitive integers)
 //! ```
 //!
 //! # Supported Scalar Types
@@ -24,14 +29,95 @@
 //! **Always available:**
 //! - `f64` (default) - implements `Scalar`, `Real`, `Transcendental`
 //! - `f32` - implements `Scalar`, `Real`, `Transcendental`
+//! - `i8`, `i16`, `i32`, `i64`, `i128` - implement `Scalar`, `SignedScalar`, `Exact`,
+//!   `IntegerScalar`
+//! - `u8`, `u16`, `u32`, `u64`, `u128`, `usize` - implement `Scalar`, `Exact`,
+//!   `IntegerScalar`, but not `SignedScalar`, so their quantities cannot be negated
 //!
 //! **Feature-gated:**
 //! - `num_rational::Rational64` (`scalar-rational`) - implements `Scalar`, `Exact`
 //! - `num_rational::Rational32` (`scalar-rational`) - implements `Scalar`, `Exact`
+//! - `fixed::FixedI32<Frac>` and `fixed::FixedI64<Frac>` (`fixed`) - implement `Scalar`,
+//!   `Exact` for up to 30 (resp. 62) fractional bits, so that `ONE` is representable
+//! - `half::f16` and `half::bf16` (`half`) - implement `Scalar`, `Real`, `Transcendental`;
+//!   everything beyond the basic operators is computed in `f32`
 //!
 //! Note: `BigRational` is NOT supported because `BigInt` does not implement `Copy`,
 //! which is required by the `Scalar` trait for performance and ergonomics.
 //!
+//! # Custom scalar types
+//!
+//! Any `Copy` numeric type (interval arithmetic, software floats, fixed-point
+//! decimals, …) can back a `Quantity` by implementing [`Scalar`] plus either
+//! [`Real`] (conversion through `f64`, optionally [`Transcendental`]) or
+//! [`Exact`] (conversion through exact ratios). The operator impls, unit
+//! conversions and formatting in this crate then work unchanged.
+//!
+//! Each trait documents the laws that the crate relies on. Implementations
+//! only need to follow them up to the type's own rounding; breaking them does
+//! not cause undefined behavior, but conversions and comparisons may give
+//! wrong results.
+//!
+//! ```rust
+//! use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+//! use qtty_core::length::{Kilometer, Meter};
+//! use qtty_core::scalar::{Exact, Scalar};
+//! use qtty_core::{ExactRatio, Quantity};
+//!
+//! /// Whole millimetres stored as an `i64`.
+//! #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+//! struct Milli(i64);
+//!
+//! macro_rules! forward {
+//!     ($($op:ident::$f:ident, $aop:ident::$af:ident, $sym:tt;)+) => {$(
+//!         impl $op for Milli {
+//!             type Output = Self;
+//!             fn $f(self, rhs: Self) -> Self { Milli(self.0 $sym rhs.0) }
+//!         }
+//!         impl $aop for Milli {
+//!             fn $af(&mut self, rhs: Self) { *self = *self $sym rhs; }
+//!         }
+//!     )+};
+//! }
+//! forward! {
+//!     Add::add, AddAssign::add_assign, +;
+//!     Sub::sub, SubAssign::sub_assign, -;
+//!     Mul::mul, MulAssign::mul_assign, *;
+//!     Div::div, DivAssign::div_assign, /;
+//! }
+//! impl Neg for Milli {
+//!     type Output = Self;
+//!     fn neg(self) -> Self { Milli(-self.0) }
+//! }
+//!
+//! impl Scalar for Milli {
+//!     const ZERO: Self = Milli(0);
+//!     const ONE: Self = Milli(1);
+//!     fn abs(self) -> Self { Milli(self.0.abs()) }
+//!     fn min(self, other: Self) -> Self { Milli(Ord::min(self.0, other.0)) }
+//!     fn max(self, other: Self) -> Self { Milli(Ord::max(self.0, other.0)) }
+//!     fn rem_euclid(self, rhs: Self) -> Self { Milli(self.0.rem_euclid(rhs.0)) }
+//! }
+//!
+//! impl Exact for Milli {
+//!     fn to_f64_approx(self) -> f64 { self.0 as f64 }
+//!     fn from_f64_approx(value: f64) -> Self { Milli(value as i64) }
+//!     fn checked_from_f64(value: f64) -> Option<Self> {
+//!         i64::checked_from_f64(value).map(Milli)
+//!     }
+//!     fn checked_mul_ratio(self, ratio: ExactRatio) -> Option<Self> {
+//!         self.0.checked_mul_ratio(ratio).map(Milli)
+//!     }
+//!     fn saturating_mul_ratio(self, ratio: ExactRatio) -> Self {
+//!         Milli(self.0.saturating_mul_ratio(ratio))
+//!     }
+//! }
+//!
+//! let d: Quantity<Kilometer, Milli> = Quantity::new(Milli(3));
+//! let m: Quantity<Meter, Milli> = (d + d).to_lossy();
+//! assert_eq!(m.value(), Milli(6_000));
+//! ```
+//!
 //! # Example
 //!
 //! ```rust
@@ -43,31 +129,10 @@
 //! }
 //! ```
 
+use crate::unit::ExactRatio;
 use core::fmt::{Debug, Display};
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
 
-// ─────────────────────────────────────────────────────────────────────────────
-// Sealed trait pattern
-// ─────────────────────────────────────────────────────────────────────────────
-
-mod private {
-    pub trait Sealed {}
-
-    impl Sealed for f64 {}
-    impl Sealed for f32 {}
-    impl Sealed for i8 {}
-    impl Sealed for i16 {}
-    impl Sealed for i32 {}
-    impl Sealed for i64 {}
-    impl Sealed for i128 {}
-
-    #[cfg(feature = "scalar-rational")]
-    impl Sealed for num_rational::Rational64 {}
-
-    #[cfg(feature = "scalar-rational")]
-    impl Sealed for num_rational::Rational32 {}
-}
-
 // ─────────────────────────────────────────────────────────────────────────────
 // Core Scalar trait
 // ─────────────────────────────────────────────────────────────────────────────
@@ -78,10 +143,20 @@ mod private {
 /// as the underlying storage for quantities: basic arithmetic operations,
 /// copy semantics, and partial ordering.
 ///
-/// This trait is sealed and cannot be implemented outside this crate.
+/// # Laws
+///
+/// Implementations outside this crate must uphold, up to the type's own
+/// rounding:
+///
+/// - `ZERO` is the identity of `+` and `ONE` the identity of `*`.
+/// - The compound operators agree with the binary ones (`a += b` leaves
+///   `a + b` in `a`).
+/// - `partial_cmp` is consistent with `==` and returns `None` only for
+///   unordered values (such as NaN).
+/// - `abs` is never negative for ordered values, `min` and `max` return one of
+///   their operands, and `rem_euclid(rhs)` lies in `[ZERO, rhs.abs())`.
 pub trait Scalar:
-    private::Sealed
-    + Copy
+    Copy
     + Clone
     + Debug
     + PartialEq
@@ -94,7 +169,6 @@ pub trait Scalar:
     + SubAssign
     + MulAssign
     + DivAssign
-    + Neg<Output = Self>
     + Sized
     + 'static
 {
@@ -117,6 +191,20 @@ pub trait Scalar:
     fn rem_euclid(self, rhs: Self) -> Self;
 }
 
+/// A [`Scalar`] that can be negated.
+///
+/// Negation is not part of [`Scalar`] so that unsigned integers can back a
+/// [`Quantity`](crate::Quantity); `-quantity` requires this bound instead.
+/// It is implemented for every scalar with `Neg<Output = Self>`.
+///
+/// # Laws
+///
+/// In addition to the [`Scalar`] laws: `a - b == a + (-b)` and `-(-a) == a`
+/// (signed integers wrap or panic at `MIN` like the primitive `-`).
+pub trait SignedScalar: Scalar + Neg<Output = Self> {}
+
+impl<T: Scalar + Neg<Output = T>> SignedScalar for T {}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Real trait (floating-point-like operations)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -133,8 +221,19 @@ pub trait Scalar:
 ///   representation is not possible (e.g., for `f32`).
 /// - `to_f64` should produce the closest `f64` representation.
 ///
-/// This trait is sealed and cannot be implemented outside this crate.
-pub trait Real: Scalar + Display + Rem<Output = Self> {
+/// # Laws
+///
+/// Unit conversion computes `value * Self::from_f64(ratio)`, so in addition to
+/// the [`Scalar`] laws:
+///
+/// - `from_f64(1.0) == ONE`, `from_f64(0.0) == ZERO`, and
+///   `from_f64(x).to_f64()` is the value nearest `x` that the type can hold.
+/// - `from_f64` and `to_f64` are monotonic.
+/// - The constants match their `f64` counterparts through `from_f64`. Types
+///   without NaN or infinities should return the closest meaningful value
+///   (e.g. an unbounded interval), and the `is_*` predicates must agree with
+///   what they return.
+pub trait Real: SignedScalar + Display + Rem<Output = Self> {
     /// The mathematical constant π (pi).
     const PI: Self;
 
@@ -245,7 +344,11 @@ pub trait Real: Scalar + Display + Rem<Output = Self> {
 /// Exact numeric types like rationals typically do not implement this trait
 /// because trigonometric functions produce irrational results.
 ///
-/// This trait is sealed and cannot be implemented outside this crate.
+/// # Laws
+///
+/// Each function agrees with its `f64` counterpart applied through
+/// [`Real::to_f64`] and [`Real::from_f64`], up to the type's own rounding.
+/// Angles are in radians.
 pub trait Transcendental: Real {
     /// Sine function.
     fn sin(self) -> Self;
@@ -296,20 +399,32 @@ pub trait Transcendental: Real {
 
 /// Trait for exact numeric types that avoid floating-point rounding.
 ///
-/// Types implementing this trait (e.g., `Rational64`, `Rational32`, signed integers)
+/// Types implementing this trait (e.g., `Rational64`, `Rational32`, primitive integers)
 /// provide exact arithmetic but typically do not support transcendental functions.
 ///
-/// # Unit conversion is lossy
+/// # Unit conversion
+///
+/// When both units carry an [`EXACT_RATIO`](crate::Unit::EXACT_RATIO), unit
+/// conversion multiplies by that fraction using integer arithmetic
+/// ([`checked_mul_ratio`](Exact::checked_mul_ratio)), so rationals convert
+/// exactly and integers only lose the fractional part of the result.
 ///
-/// The `to_f64_approx` and `from_f64_approx` methods enable lossy unit conversion
-/// for types that cannot implement [`Real`]. Because unit ratios are stored as `f64`,
-/// converting between units with an `Exact` scalar goes through `f64` and back,
-/// which introduces floating-point rounding. This means the `scalar-rational` feature
-/// provides **exact arithmetic within a fixed unit** but **lossy unit conversion**.
-/// For integers, `from_f64_approx` truncates toward zero and saturates at the
-/// type's bounds.
+/// Otherwise the `to_f64_approx` and `from_f64_approx` methods provide a lossy
+/// fallback for types that cannot implement [`Real`]: the value goes through
+/// `f64` and back, which introduces floating-point rounding. For integers,
+/// `from_f64_approx` truncates toward zero and saturates at the type's bounds.
 ///
-/// This trait is sealed and cannot be implemented outside this crate.
+/// # Laws
+///
+/// In addition to the [`Scalar`] laws:
+///
+/// - `checked_mul_ratio(ExactRatio::new(1, 1)) == Some(self)`.
+/// - `checked_mul_ratio` returns `None` only when the result does not fit;
+///   otherwise `saturating_mul_ratio` returns the same value.
+/// - `checked_from_f64` returns `None` exactly when `from_f64_approx` would
+///   have to saturate, or the input is NaN or infinite.
+/// - `from_f64_approx(self.to_f64_approx())` returns `self` whenever the
+///   value is exactly representable as an `f64`.
 pub trait Exact: Scalar {
     /// Convert to `f64`, potentially losing precision.
     ///
@@ -330,15 +445,27 @@ pub trait Exact: Scalar {
     /// For integers, returns `None` when the truncated value falls outside
     /// `[Self::MIN, Self::MAX]` or when the input is NaN/infinite.
     fn checked_from_f64(value: f64) -> Option<Self>;
+
+    /// Multiplies by an exact ratio without going through `f64`.
+    ///
+    /// Integers truncate the result toward zero; rationals are exact. Returns
+    /// `None` if the result does not fit in this type.
+    fn checked_mul_ratio(self, ratio: ExactRatio) -> Option<Self>;
+
+    /// Like [`checked_mul_ratio`](Exact::checked_mul_ratio), but saturates at
+    /// the type's bounds instead of returning `None`.
+    fn saturating_mul_ratio(self, ratio: ExactRatio) -> Self;
 }
 
 /// Marker trait for integer scalar types.
 ///
-/// This is implemented only by signed integer types (`i8`, `i16`, `i32`, `i64`, `i128`).
+/// This crate implements it for the signed (`i8` to `i128`) and unsigned (`u8` to `u128`,
+/// `usize`) primitive integers.
 /// It is used to provide non-overlapping `Display` implementations for integer
 /// quantities while leaving room for other non-integer [`Exact`] scalar families.
 ///
-/// This trait is sealed and cannot be implemented outside this crate.
+/// Downstream integer-like types may implement it to advertise that their
+/// values are whole numbers.
 pub trait IntegerScalar: Exact + Display {}
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -1286,6 +1413,29 @@ mod rational_impl {
     use super::*;
     use num_rational::{Rational32, Rational64};
 
+    /// Computes `(numer / denom) * ratio` in lowest terms, or `None` if either
+    /// part overflows `i128`.
+    fn mul_fraction_by_ratio(numer: i128, denom: i128, ratio: ExactRatio) -> Option<(i128, i128)> {
+        // Both inputs are already reduced, so cross-reducing keeps the result
+        // in lowest terms and the intermediate products as small as possible.
+        let g1 = gcd(numer.unsigned_abs(), ratio.denom().unsigned_abs());
+        let g2 = gcd(ratio.numer().unsigned_abs(), denom.unsigned_abs());
+        let numer = (numer / g1).checked_mul(ratio.numer() / g2)?;
+        let denom = (denom / g2).checked_mul(ratio.denom() / g1)?;
+        Some((numer, denom))
+    }
+
+    fn gcd(mut a: u128, mut b: u128) -> i128 {
+        while b != 0 {
+            let t = a % b;
+            a = b;
+            b = t;
+        }
+        // Callers always pass a non-zero `b` taken from an `i128`, so the
+        // result is bounded by it and fits back into `i128`.
+        a as i128
+    }
+
     impl Scalar for Rational64 {
         const ZERO: Self = Rational64::new_raw(0, 1);
         const ONE: Self = Rational64::new_raw(1, 1);
@@ -1356,6 +1506,27 @@ mod rational_impl {
             }
             Rational64::approximate_float(value)
         }
+
+        #[inline]
+        fn checked_mul_ratio(self, ratio: ExactRatio) -> Option<Self> {
+            let (numer, denom) =
+                mul_fraction_by_ratio(*self.numer() as i128, *self.denom() as i128, ratio)?;
+            Some(Rational64::new(
+                i64::try_from(numer).ok()?,
+                i64::try_from(denom).ok()?,
+            ))
+        }
+
+        #[inline]
+        fn saturating_mul_ratio(self, ratio: ExactRatio) -> Self {
+            self.checked_mul_ratio(ratio).unwrap_or_else(|| {
+                if self < Self::ZERO {
+                    Rational64::new_raw(i64::MIN, 1)
+                } else {
+                    Rational64::new_raw(i64::MAX, 1)
+                }
+            })
+        }
     }
 
     impl Scalar for Rational32 {
@@ -1426,6 +1597,27 @@ mod rational_impl {
             }
             Rational32::approximate_float(value)
         }
+
+        #[inline]
+        fn checked_mul_ratio(self, ratio: ExactRatio) -> Option<Self> {
+            let (numer, denom) =
+                mul_fraction_by_ratio(*self.numer() as i128, *self.denom() as i128, ratio)?;
+            Some(Rational32::new(
+                i32::try_from(numer).ok()?,
+                i32::try_from(denom).ok()?,
+            ))
+        }
+
+        #[inline]
+        fn saturating_mul_ratio(self, ratio: ExactRatio) -> Self {
+            self.checked_mul_ratio(ratio).unwrap_or_else(|| {
+                if self < Self::ZERO {
+                    Rational32::new_raw(i32::MIN, 1)
+                } else {
+                    Rational32::new_raw(i32::MAX, 1)
+                }
+            })
+        }
     }
 }
 
@@ -1437,6 +1629,19 @@ mod rational_impl {
 // Signed integer implementations
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Computes `value * ratio`, truncated toward zero, or `None` on `i128`
+/// overflow.
+#[inline]
+fn mul_int_by_ratio(value: i128, ratio: ExactRatio) -> Option<i128> {
+    // Split `value = q·d + r` so that only the remainder is multiplied before
+    // dividing: `value·n/d = q·n + r·n/d`. Since `n, d > 0` and `r` has the
+    // sign of `value`, truncating the second term truncates the whole result.
+    let (n, d) = (ratio.numer(), ratio.denom());
+    let whole = (value / d).checked_mul(n)?;
+    let frac = (value % d).checked_mul(n)? / d;
+    whole.checked_add(frac)
+}
+
 macro_rules! impl_scalar_for_signed_int {
     ($($t:ty),*) => { $(
         impl Scalar for $t {
@@ -1501,6 +1706,17 @@ macro_rules! impl_scalar_for_signed_int {
                 }
                 Some(value as Self)
             }
+
+            #[inline]
+            fn checked_mul_ratio(self, ratio: ExactRatio) -> Option<Self> {
+                mul_int_by_ratio(self as i128, ratio).and_then(|v| Self::try_from(v).ok())
+            }
+
+            #[inline]
+            fn saturating_mul_ratio(self, ratio: ExactRatio) -> Self {
+                self.checked_mul_ratio(ratio)
+                    .unwrap_or(if self < 0 { Self::MIN } else { Self::MAX })
+            }
         }
 
         impl IntegerScalar for $t {}
@@ -1509,6 +1725,323 @@ macro_rules! impl_scalar_for_signed_int {
 
 impl_scalar_for_signed_int!(i8, i16, i32, i64, i128);
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Unsigned integer implementations
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Computes `value * ratio`, truncated toward zero, or `None` on `u128`
+/// overflow. The unsigned counterpart of [`mul_int_by_ratio`], needed because
+/// `u128` values do not fit in `i128`.
+#[inline]
+fn mul_uint_by_ratio(value: u128, ratio: ExactRatio) -> Option<u128> {
+    // Ratio parts are positive `i128`s, so they always fit in `u128`.
+    let (n, d) = (ratio.numer() as u128, ratio.denom() as u128);
+    let whole = (value / d).checked_mul(n)?;
+    let frac = (value % d).checked_mul(n)? / d;
+    whole.checked_add(frac)
+}
+
+macro_rules! impl_scalar_for_unsigned_int {
+    ($($t:ty),*) => { $(
+        impl Scalar for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            #[inline]
+            fn abs(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                self.rem_euclid(rhs)
+            }
+        }
+
+        impl Exact for $t {
+            #[inline]
+            fn to_f64_approx(self) -> f64 {
+                self as f64
+            }
+
+            /// Truncates toward zero and saturates, so negative inputs and NaN
+            /// become `0`.
+            #[inline]
+            fn from_f64_approx(value: f64) -> Self {
+                value as Self
+            }
+
+            #[inline]
+            fn checked_from_f64(value: f64) -> Option<Self> {
+                if !value.is_finite() {
+                    return None;
+                }
+                // Same reasoning as for signed integers: `MAX + 1` is the
+                // exclusive bound, and rounds to exactly `2^n` for the wide
+                // types. Values in `(-1, 0)` truncate to zero and are accepted.
+                const F_MAX_EXCL: f64 = <$t>::MAX as f64 + 1.0;
+                if !(value > -1.0 && value < F_MAX_EXCL) {
+                    return None;
+                }
+                Some(value as Self)
+            }
+
+            #[inline]
+            fn checked_mul_ratio(self, ratio: ExactRatio) -> Option<Self> {
+                mul_uint_by_ratio(self as u128, ratio).and_then(|v| Self::try_from(v).ok())
+            }
+
+            #[inline]
+            fn saturating_mul_ratio(self, ratio: ExactRatio) -> Self {
+                self.checked_mul_ratio(ratio).unwrap_or(Self::MAX)
+            }
+        }
+
+        impl IntegerScalar for $t {}
+    )* };
+}
+
+impl_scalar_for_unsigned_int!(u8, u16, u32, u64, u128, usize);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Fixed-point implementations (feature-gated)
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "fixed")]
+mod fixed_impl {
+    use super::*;
+    use fixed::types::extra::{IsLessOrEqual, LeEqU32, LeEqU64, True, U30, U62};
+    use fixed::{FixedI32, FixedI64};
+
+    /// Implements [`Scalar`] and [`Exact`] for a signed fixed-point type.
+    ///
+    /// `ONE` must be representable, which leaves at least two integer bits
+    /// (`$max_frac` fractional bits at most). Conversions by an exact ratio
+    /// scale the raw bits with [`mul_int_by_ratio`], so they never touch
+    /// floating point and truncate toward zero at the type's resolution.
+    macro_rules! impl_scalar_for_fixed {
+        ($($t:ident($bits:ty, $le_eq:ident, $max_frac:ty)),*) => { $(
+            impl<Frac> Scalar for $t<Frac>
+            where
+                Frac: $le_eq + IsLessOrEqual<$max_frac, Output = True> + 'static,
+            {
+                const ZERO: Self = $t::ZERO;
+                const ONE: Self = $t::ONE;
+
+                #[inline]
+                fn abs(self) -> Self {
+                    self.saturating_abs()
+                }
+
+                #[inline]
+                fn min(self, other: Self) -> Self {
+                    Ord::min(self, other)
+                }
+
+                #[inline]
+                fn max(self, other: Self) -> Self {
+                    Ord::max(self, other)
+                }
+
+                #[inline]
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    self.rem_euclid(rhs)
+                }
+            }
+
+            impl<Frac> Exact for $t<Frac>
+            where
+                Frac: $le_eq + IsLessOrEqual<$max_frac, Output = True> + 'static,
+            {
+                #[inline]
+                fn to_f64_approx(self) -> f64 {
+                    self.to_num::<f64>()
+                }
+
+                /// Rounds to the nearest representable value and saturates at
+                /// the type's bounds; NaN maps to zero.
+                #[inline]
+                fn from_f64_approx(value: f64) -> Self {
+                    if value.is_nan() {
+                        return Self::ZERO;
+                    }
+                    Self::saturating_from_num(value)
+                }
+
+                #[inline]
+                fn checked_from_f64(value: f64) -> Option<Self> {
+                    Self::checked_from_num(value)
+                }
+
+                #[inline]
+                fn checked_mul_ratio(self, ratio: ExactRatio) -> Option<Self> {
+                    mul_int_by_ratio(self.to_bits() as i128, ratio)
+                        .and_then(|bits| <$bits>::try_from(bits).ok())
+                        .map(Self::from_bits)
+                }
+
+                #[inline]
+                fn saturating_mul_ratio(self, ratio: ExactRatio) -> Self {
+                    self.checked_mul_ratio(ratio).unwrap_or(if self < Self::ZERO {
+                        Self::MIN
+                    } else {
+                        Self::MAX
+                    })
+                }
+            }
+        )* };
+    }
+
+    impl_scalar_for_fixed!(FixedI32(i32, LeEqU32, U30), FixedI64(i64, LeEqU64, U62));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Half-precision implementations (feature-gated)
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "half")]
+mod half_impl {
+    use super::*;
+    use half::{bf16, f16};
+
+    /// Implements unary methods of `$trait` by promoting to `f32`, applying
+    /// the `f32` implementation and rounding the result back.
+    macro_rules! via_f32 {
+        ($trait:ident: $($name:ident),* $(,)?) => { $(
+            #[inline]
+            fn $name(self) -> Self {
+                Self::from_f32(<f32 as $trait>::$name(self.to_f32()))
+            }
+        )* };
+    }
+
+    /// Implements [`Scalar`], [`Real`] and [`Transcendental`] for a 16-bit
+    /// float.
+    ///
+    /// Storage and arithmetic stay in the 16-bit type; everything beyond the
+    /// operators is computed in `f32` and rounded back, which is exact for the
+    /// predicates and gives correctly rounded results for the rest in all but
+    /// rare double-rounding cases.
+    macro_rules! impl_scalar_for_half {
+        ($($t:ident),*) => { $(
+            impl Scalar for $t {
+                const ZERO: Self = $t::ZERO;
+                const ONE: Self = $t::ONE;
+
+                via_f32!(Scalar: abs);
+
+                #[inline]
+                fn min(self, other: Self) -> Self {
+                    Self::from_f32(Scalar::min(self.to_f32(), other.to_f32()))
+                }
+
+                #[inline]
+                fn max(self, other: Self) -> Self {
+                    Self::from_f32(Scalar::max(self.to_f32(), other.to_f32()))
+                }
+
+                #[inline]
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    Self::from_f32(Scalar::rem_euclid(self.to_f32(), rhs.to_f32()))
+                }
+            }
+
+            impl Real for $t {
+                const PI: Self = $t::PI;
+                const TAU: Self = $t::from_f64_const(core::f64::consts::TAU);
+                const E: Self = $t::E;
+                const INFINITY: Self = $t::INFINITY;
+                const NEG_INFINITY: Self = $t::NEG_INFINITY;
+                const NAN: Self = $t::NAN;
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    $t::from_f64(value)
+                }
+
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    $t::to_f64(self)
+                }
+
+                #[inline]
+                fn is_nan(self) -> bool {
+                    $t::is_nan(self)
+                }
+
+                #[inline]
+                fn is_infinite(self) -> bool {
+                    $t::is_infinite(self)
+                }
+
+                #[inline]
+                fn is_finite(self) -> bool {
+                    $t::is_finite(self)
+                }
+
+                #[inline]
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    Self::from_f32(Real::mul_add(self.to_f32(), a.to_f32(), b.to_f32()))
+                }
+
+                #[inline]
+                fn powf(self, exp: Self) -> Self {
+                    Self::from_f32(Real::powf(self.to_f32(), exp.to_f32()))
+                }
+
+                #[inline]
+                fn powi(self, exp: i32) -> Self {
+                    Self::from_f32(Real::powi(self.to_f32(), exp))
+                }
+
+                #[inline]
+                fn log(self, base: Self) -> Self {
+                    Self::from_f32(Real::log(self.to_f32(), base.to_f32()))
+                }
+
+                #[inline]
+                fn hypot(self, other: Self) -> Self {
+                    Self::from_f32(Real::hypot(self.to_f32(), other.to_f32()))
+                }
+
+                via_f32!(
+                    Real: signum, floor, ceil, round, trunc, fract, sqrt, cbrt, ln, log10, log2, exp,
+                    exp2,
+                );
+            }
+
+            impl Transcendental for $t {
+                #[inline]
+                fn sin_cos(self) -> (Self, Self) {
+                    let (sin, cos) = Transcendental::sin_cos(self.to_f32());
+                    (Self::from_f32(sin), Self::from_f32(cos))
+                }
+
+                #[inline]
+                fn atan2(self, other: Self) -> Self {
+                    Self::from_f32(Transcendental::atan2(self.to_f32(), other.to_f32()))
+                }
+
+                via_f32!(
+                    Transcendental: sin, cos, tan, asin, acos, atan, sinh, cosh, tanh, asinh, acosh, atanh,
+                );
+            }
+        )* };
+    }
+
+    impl_scalar_for_half!(f16, bf16);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Zero-copy views between quantity slices and scalar slices.
+//!
+//! [`Quantity<U, S>`] is `#[repr(transparent)]` over `S`, so a slice of
+//! quantities has exactly the layout of a slice of scalars. The functions here
+//! reinterpret one as the other without copying, and convert a whole slice to
+//! another unit in place.
+//!
+//! ```rust
+//! use qtty_core::length::{Kilometer, Meters};
+//! use qtty_core::slice;
+//!
+//! let mut raw = [1500.0, 250.0, 42.0];
+//! let distances: &mut [Meters] = slice::from_values_mut(&mut raw);
+//! let km = slice::convert_slice_in_place::<_, Kilometer, _>(distances);
+//! assert_eq!(km[0].value(), 1.5);
+//! assert_eq!(slice::as_values(km), &[1.5, 0.25, 0.042]);
+//! ```
+
+#![allow(unsafe_code)]
+
+use crate::scalar::{Real, Scalar};
+use crate::{Quantity, Unit};
+
+/// Views a slice of quantities as their raw values in unit `U`.
+#[inline]
+pub fn as_values<U: Unit, S: Scalar>(quantities: &[Quantity<U, S>]) -> &[S] {
+    // SAFETY: `Quantity<U, S>` is `repr(transparent)` over `S` (its other
+    // field is a zero-sized `PhantomData`), so both slices have the same
+    // length, size, alignment and bit validity.
+    unsafe { core::slice::from_raw_parts(quantities.as_ptr().cast::<S>(), quantities.len()) }
+}
+
+/// Mutable version of [`as_values`].
+#[inline]
+pub fn as_values_mut<U: Unit, S: Scalar>(quantities: &mut [Quantity<U, S>]) -> &mut [S] {
+    // SAFETY: see `as_values`; the exclusive borrow is carried over.
+    unsafe {
+        core::slice::from_raw_parts_mut(quantities.as_mut_ptr().cast::<S>(), quantities.len())
+    }
+}
+
+/// Views a slice of raw values as quantities in unit `U`.
+#[inline]
+pub fn from_values<U: Unit, S: Scalar>(values: &[S]) -> &[Quantity<U, S>] {
+    // SAFETY: see `as_values`; every `S` is a valid `Quantity<U, S>`.
+    unsafe { core::slice::from_raw_parts(values.as_ptr().cast::<Quantity<U, S>>(), values.len()) }
+}
+
+/// Mutable version of [`from_values`].
+#[inline]
+pub fn from_values_mut<U: Unit, S: Scalar>(values: &mut [S]) -> &mut [Quantity<U, S>] {
+    // SAFETY: see `as_values`; the exclusive borrow is carried over.
+    unsafe {
+        core::slice::from_raw_parts_mut(values.as_mut_ptr().cast::<Quantity<U, S>>(), values.len())
+    }
+}
+
+/// Converts every quantity in `quantities` to unit `T`, in place, and returns
+/// the same memory viewed as `T` quantities.
+///
+/// The conversion factor is computed once, as in [`Quantity::to`], and the
+/// loop is a plain multiplication over the scalars, which the compiler can
+/// auto-vectorise.
+#[inline]
+pub fn convert_slice_in_place<U, T, S>(quantities: &mut [Quantity<U, S>]) -> &mut [Quantity<T, S>]
+where
+    U: Unit,
+    T: Unit<Dim = U::Dim>,
+    S: Real,
+{
+    let values = as_values_mut(quantities);
+    scale_in_place(values, S::from_f64(U::RATIO / T::RATIO));
+    from_values_mut(values)
+}
+
+/// Multiplies every value by `factor`, skipping the loop for a factor of one.
+#[inline]
+pub(crate) fn scale_in_place<S: Scalar>(values: &mut [S], factor: S) {
+    if factor != S::ONE {
+        for value in values {
+            *value *= factor;
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::length::{Kilometer, Kilometers, Meter, Meters};
+    use crate::time::{Hour, Second};
+
+    #[test]
+    fn layout_matches_scalar() {
+        use core::mem::{align_of, size_of};
+        assert_eq!(size_of::<Meters>(), size_of::<f64>());
+        assert_eq!(align_of::<Meters>(), align_of::<f64>());
+        assert_eq!(size_of::<Quantity<Meter, f32>>(), size_of::<f32>());
+        assert_eq!(size_of::<Quantity<Meter, i8>>(), size_of::<i8>());
+        assert_eq!(size_of::<[Meters; 4]>(), size_of::<[f64; 4]>());
+    }
+
+    #[test]
+    fn reinterprets_both_ways() {
+        let raw = [1.0, 2.0, 3.0];
+        let quantities: &[Meters] = from_values(&raw);
+        assert_eq!(
+            quantities,
+            &[Meters::new(1.0), Meters::new(2.0), Meters::new(3.0)]
+        );
+        assert_eq!(as_values(quantities).as_ptr(), raw.as_ptr());
+
+        let mut owned = [Meters::new(1.0), Meters::new(2.0)];
+        as_values_mut(&mut owned)[1] = 5.0;
+        assert_eq!(owned[1], Meters::new(5.0));
+        let mut ints = [1_i32, 2];
+        from_values_mut::<Second, _>(&mut ints)[0] += Quantity::new(10);
+        assert_eq!(ints, [11, 2]);
+    }
+
+    #[test]
+    fn converts_in_place() {
+        let mut values = [Meters::new(1500.0), Meters::new(-20.0)];
+        let km = convert_slice_in_place::<Meter, Kilometer, f64>(&mut values);
+        assert_eq!(km, &[Kilometers::new(1.5), Kilometers::new(-0.02)]);
+        assert_eq!(as_values(&values), &[1.5, -0.02]);
+
+        let mut hours = [Quantity::<Hour, f32>::new(2.0)];
+        let seconds = convert_slice_in_place::<_, Second, _>(&mut hours);
+        assert_eq!(seconds[0].value(), 7200.0);
+    }
+
+    #[test]
+    fn matches_scalar_conversion() {
+        let mut values: [Meters; 5] = core::array::from_fn(|i| Meters::new(i as f64 * 0.1));
+        let expected = values.map(|q| q.to::<Kilometer>());
+        assert_eq!(
+            convert_slice_in_place::<_, Kilometer, _>(&mut values),
+            &expected
+        );
+    }
+}
@@ -3,11 +3,14 @@
 
 //! Unit types and traits.
 
-use crate::dimension::{DimDiv, DimMul, Dimension};
+use crate::dimension::{write_superscript, DimDiv, DimMul, DimPow, Dimension};
+use crate::dynamic::powi_f64;
 use crate::scalar::Scalar;
 use crate::Quantity;
+use core::any::TypeId;
 use core::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
 use core::marker::PhantomData;
+use typenum::Integer;
 
 /// Trait implemented by every **unit** type.
 ///
@@ -16,10 +19,11 @@ use core::marker::PhantomData;
 ///   because `1 km = 1000 m`.
 ///
 /// * `SYMBOL` is the printable string (e.g. `"m"` or `"km"`).
-///   For some composite generic units (such as [`Per`] and [`Prod`]), this is
-///   currently an empty string because Rust does not yet support composing
-///   generic unit symbols as a `const`. Use `Display`/`LowerExp`/`UpperExp` on
-///   [`Quantity`] for user-facing formatting of composite units.
+///   For composite generic units ([`Per`], [`Prod`], [`Pow`]) this is an empty
+///   string because Rust does not yet support composing generic unit symbols
+///   as a `const`. Their symbol is rendered at runtime by
+///   [`fmt_symbol`](Unit::fmt_symbol) (or [`UnitSymbol`]), which is what
+///   `Display`/`LowerExp`/`UpperExp` on [`Quantity`] and the serde helpers use.
 ///
 /// * `Dim` ties the unit to its underlying [`Dimension`].
 ///
@@ -30,23 +34,363 @@ use core::marker::PhantomData;
 ///
 /// # Conversion precision
 ///
-/// `RATIO` is an `f64`, so converting between units always involves an `f64`
-/// multiplication even when the scalar type is "exact" (e.g. `Rational64` or
-/// an integer). This is a deliberate trade-off: the type-level unit system
-/// remains zero-cost and dimensional analysis is exact, but the *numeric*
-/// conversion factor is limited to `f64` precision. Changing this to, say, a
-/// rational ratio would require a core trait redesign because the ratio is a
-/// compile-time constant and Rust's const generics do not yet support
-/// arbitrary-precision types.
+/// `RATIO` is an `f64`, so converting between units of an `f64`/`f32`
+/// quantity always involves a floating-point multiplication. Units whose
+/// factor is an exact fraction (SI prefixes, minutes and hours, the
+/// international foot, …) additionally expose it as
+/// [`EXACT_RATIO`](Unit::EXACT_RATIO). [`Exact`](crate::Exact) scalars
+/// (integers, `Rational64`, …) use that fraction whenever both units carry one,
+/// so `Quantity<Kilometer, i64>` → `Quantity<Meter, i64>` is a pure integer
+/// multiplication. Units defined through irrational or measured constants
+/// (radians, parsecs, electronvolts) leave `EXACT_RATIO` as `None` and fall
+/// back to `f64` conversion.
 pub trait Unit: Copy + PartialEq + Debug + 'static {
     /// Unit-to-canonical conversion factor.
     const RATIO: f64;
 
+    /// Exact unit-to-canonical conversion factor, when one exists.
+    ///
+    /// Must agree with [`RATIO`](Unit::RATIO) up to `f64` rounding. Defaults
+    /// to `None`; `#[derive(Unit)]` sets it from `exact_ratio = N / D`.
+    const EXACT_RATIO: Option<ExactRatio> = None;
+
     /// Dimension to which this unit belongs.
     type Dim: Dimension;
 
     /// Printable symbol, shown by [`core::fmt::Display`].
     const SYMBOL: &'static str;
+
+    /// Singular long name, e.g. `"kilometre"`, shown by alternate
+    /// formatting (`{:#}`).
+    ///
+    /// Empty by default, in which case `{:#}` falls back to the symbol.
+    /// `#[derive(Unit)]` always sets it, deriving it from the type name when
+    /// no `long_name` is given.
+    const LONG_NAME: &'static str = "";
+
+    /// Plural long name, e.g. `"kilometres"`. Defaults to
+    /// [`LONG_NAME`](Unit::LONG_NAME).
+    const PLURAL: &'static str = Self::LONG_NAME;
+
+    /// Unit system the unit belongs to, e.g. `"SI"`, `"SI-accepted"`,
+    /// `"astronomical"` or `"customary"`. Empty when unspecified.
+    const SYSTEM: &'static str = "";
+
+    /// Alternative names accepted by name lookups (other spellings, common
+    /// abbreviations), e.g. `["kilometer", "kilometers"]`.
+    const ALIASES: &'static [&'static str] = &[];
+
+    /// Calls `visit` with every named factor of this unit, its type identity
+    /// and its exponent, each exponent multiplied by `exp`.
+    ///
+    /// Named units report `(SYMBOL, TypeId::of::<Self>(), exp)`. Composite
+    /// units forward to their parts, so `Per<Meter, Prod<Second, Second>>`
+    /// visits `"m"` with `1` and `"s"` twice with `-1`. Only composite units
+    /// override this.
+    #[doc(hidden)]
+    fn visit_factors(exp: i32, visit: &mut dyn FnMut(&'static str, TypeId, i32)) {
+        visit(Self::SYMBOL, TypeId::of::<Self>(), exp);
+    }
+
+    /// Writes the printable symbol of this unit.
+    ///
+    /// Named units write [`SYMBOL`](Unit::SYMBOL). Composite units are
+    /// rendered recursively from their factors, with repeated factors
+    /// collapsed into exponents. Factors that cancel out are dropped:
+    ///
+    /// - `Prod<Meter, Meter>` → `m²`
+    /// - `Per<Per<Meter, Second>, Second>` → `m/s²`
+    /// - `Per<Joule, Prod<Kilogram, Kelvin>>` → `J/(kg·K)`
+    /// - `Pow<Second, N1>` → `s⁻¹`
+    fn fmt_symbol(f: &mut Formatter<'_>) -> Result {
+        write_factors::<Self>(f)
+    }
+}
+
+/// [`Display`] adapter for the symbol of unit `U`.
+///
+/// ```rust
+/// use qtty_core::length::Meter;
+/// use qtty_core::time::Second;
+/// use qtty_core::{Per, Prod, UnitSymbol};
+///
+/// assert_eq!(UnitSymbol::<Meter>::new().to_string(), "m");
+/// assert_eq!(UnitSymbol::<Prod<Meter, Meter>>::new().to_string(), "m²");
+/// assert_eq!(UnitSymbol::<Per<Per<Meter, Second>, Second>>::new().to_string(), "m/s²");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSymbol<U: Unit>(PhantomData<U>);
+
+impl<U: Unit> UnitSymbol<U> {
+    /// Creates the adapter.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<U: Unit> Default for UnitSymbol<U> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U: Unit> Display for UnitSymbol<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        U::fmt_symbol(f)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Composite symbol rendering
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// Rendering works directly on the `visit_factors` traversal so it needs no
+// allocation: each distinct factor is emitted at its first occurrence with the
+// net exponent summed over the whole tree. Factors are told apart by unit type
+// rather than by symbol, so `Hour` and `HourAngle` (both `h`) never cancel.
+// Unit trees are tiny, so the quadratic number of traversals is irrelevant.
+
+/// Calls `f` with each distinct factor unit of `U` and its net non-zero
+/// exponent, in order of first appearance. Stops at the first error.
+fn for_each_net_factor<U: Unit>(mut f: impl FnMut(&'static str, i32) -> Result) -> Result {
+    let mut index = 0;
+    let mut result = Ok(());
+    U::visit_factors(1, &mut |symbol, id, _| {
+        if result.is_ok() {
+            if let Some(exp) = net_exponent::<U>(index, id) {
+                if exp != 0 {
+                    result = f(symbol, exp);
+                }
+            }
+        }
+        index += 1;
+    });
+    result
+}
+
+/// Net exponent of the unit `id` in `U`, or `None` if it already occurs
+/// before position `index` (and was therefore accounted for there).
+fn net_exponent<U: Unit>(index: usize, id: TypeId) -> Option<i32> {
+    let mut i = 0;
+    let mut seen_earlier = false;
+    let mut total = 0;
+    U::visit_factors(1, &mut |_, other, e| {
+        if other == id {
+            seen_earlier |= i < index;
+            total += e;
+        }
+        i += 1;
+    });
+    (!seen_earlier).then_some(total)
+}
+
+/// Writes the factors whose net exponent has the given sign, joined by `·`.
+/// Exponents are written as absolute values when `abs` is set.
+fn write_factor_list<U: Unit>(f: &mut Formatter<'_>, positive: bool, abs: bool) -> Result {
+    let mut first = true;
+    for_each_net_factor::<U>(|symbol, exp| {
+        if (exp > 0) != positive {
+            return Ok(());
+        }
+        if !first {
+            f.write_str("·")?;
+        }
+        first = false;
+        f.write_str(symbol)?;
+        let exp = if abs { exp.abs() } else { exp };
+        if exp != 1 {
+            write_superscript(f, exp)?;
+        }
+        Ok(())
+    })
+}
+
+/// Default [`Unit::fmt_symbol`]: `numerator/denominator` with collapsed
+/// exponents, or negative exponents when there is no numerator.
+fn write_factors<U: Unit>(f: &mut Formatter<'_>) -> Result {
+    let (numer, denom) = factor_counts::<U>();
+    if denom == 0 || numer == 0 {
+        // `m²`, or `s⁻¹` rather than `1/s`.
+        write_factor_list::<U>(f, true, false)?;
+        return write_factor_list::<U>(f, false, false);
+    }
+    write_factor_list::<U>(f, true, false)?;
+    f.write_str("/")?;
+    if denom > 1 {
+        f.write_str("(")?;
+        write_factor_list::<U>(f, false, true)?;
+        f.write_str(")")
+    } else {
+        write_factor_list::<U>(f, false, true)
+    }
+}
+
+/// Number of distinct factors with positive and negative net exponents.
+fn factor_counts<U: Unit>() -> (usize, usize) {
+    let (mut numer, mut denom) = (0, 0);
+    let _ = for_each_net_factor::<U>(|_, exp| {
+        if exp > 0 {
+            numer += 1;
+        } else {
+            denom += 1;
+        }
+        Ok(())
+    });
+    (numer, denom)
+}
+
+/// Writes the `" <symbol>"` suffix used by `Quantity` formatting, or nothing
+/// when every factor of `U` cancels out.
+pub(crate) fn write_unit_suffix<U: Unit>(f: &mut Formatter<'_>) -> Result {
+    if factor_counts::<U>() == (0, 0) {
+        return Ok(());
+    }
+    f.write_str(" ")?;
+    U::fmt_symbol(f)
+}
+
+/// Exact positive rational conversion factor `numer / denom`.
+///
+/// Values are always kept in lowest terms with both parts strictly positive,
+/// so two ratios compare equal exactly when they denote the same fraction.
+///
+/// ```rust
+/// use qtty_core::{ExactRatio, Unit};
+/// use qtty_core::length::{Kilometer, Meter};
+///
+/// let km = Kilometer::EXACT_RATIO.unwrap();
+/// assert_eq!(km, ExactRatio::new(1_000, 1));
+/// assert_eq!(ExactRatio::new(10, 4), ExactRatio::new(5, 2));
+/// assert_eq!(Meter::EXACT_RATIO.unwrap().checked_div(km), Some(ExactRatio::new(1, 1_000)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExactRatio {
+    numer: i128,
+    denom: i128,
+}
+
+impl ExactRatio {
+    /// The ratio `1 / 1`.
+    pub const ONE: Self = Self { numer: 1, denom: 1 };
+
+    /// Creates the reduced ratio `numer / denom`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either part is zero or negative. In a `const` context (such
+    /// as a derived `EXACT_RATIO`) this is a compile-time error.
+    pub const fn new(numer: i128, denom: i128) -> Self {
+        assert!(
+            numer > 0 && denom > 0,
+            "exact unit ratios must be strictly positive"
+        );
+        let g = gcd(numer, denom);
+        Self {
+            numer: numer / g,
+            denom: denom / g,
+        }
+    }
+
+    /// Numerator, in lowest terms.
+    #[inline]
+    pub const fn numer(self) -> i128 {
+        self.numer
+    }
+
+    /// Denominator, in lowest terms.
+    #[inline]
+    pub const fn denom(self) -> i128 {
+        self.denom
+    }
+
+    /// Returns `denom / numer`.
+    #[inline]
+    pub const fn recip(self) -> Self {
+        Self {
+            numer: self.denom,
+            denom: self.numer,
+        }
+    }
+
+    /// Multiplies two ratios, returning `None` if the reduced result does not
+    /// fit in `i128`.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cross-reduce first so the products are already in lowest terms.
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        let numer = match (self.numer / g1).checked_mul(rhs.numer / g2) {
+            Some(n) => n,
+            None => return None,
+        };
+        let denom = match (self.denom / g2).checked_mul(rhs.denom / g1) {
+            Some(d) => d,
+            None => return None,
+        };
+        Some(Self { numer, denom })
+    }
+
+    /// Divides two ratios, returning `None` if the reduced result does not
+    /// fit in `i128`.
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip())
+    }
+
+    /// Raises the ratio to the integer power `n`, returning `None` if the
+    /// result does not fit in `i128`.
+    pub const fn checked_powi(self, n: i32) -> Option<Self> {
+        let base = if n < 0 { self.recip() } else { self };
+        let mut result = Self::ONE;
+        let mut e = n.unsigned_abs();
+        while e > 0 {
+            result = match result.checked_mul(base) {
+                Some(r) => r,
+                None => return None,
+            };
+            e -= 1;
+        }
+        Some(result)
+    }
+
+    /// Nearest `f64` approximation of the ratio.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.numer as f64 / self.denom as f64
+    }
+}
+
+impl Display for ExactRatio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.denom == 1 {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+const fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// Exact factor converting values in a unit with ratio `from` into a unit with
+/// ratio `to`, if both are known and the quotient fits in `i128`.
+#[inline]
+pub(crate) const fn exact_conversion(
+    from: Option<ExactRatio>,
+    to: Option<ExactRatio>,
+) -> Option<ExactRatio> {
+    match (from, to) {
+        (Some(from), Some(to)) => from.checked_div(to),
+        _ => None,
+    }
 }
 
 /// Unit representing the division of two other units.
@@ -65,10 +409,16 @@ where
     <N::Dim as DimDiv<D::Dim>>::Output: Dimension,
 {
     const RATIO: f64 = N::RATIO / D::RATIO;
+    const EXACT_RATIO: Option<ExactRatio> = exact_conversion(N::EXACT_RATIO, D::EXACT_RATIO);
     type Dim = <N::Dim as DimDiv<D::Dim>>::Output;
-    // Generic const-string composition is not yet available; formatted symbols
-    // are provided by Display/LowerExp/UpperExp impls for Quantity<Per<...>, S>.
+    // Generic const-string composition is not yet available; the symbol is
+    // rendered from `visit_factors` by `Unit::fmt_symbol`.
     const SYMBOL: &'static str = "";
+
+    fn visit_factors(exp: i32, visit: &mut dyn FnMut(&'static str, TypeId, i32)) {
+        N::visit_factors(exp, visit);
+        D::visit_factors(-exp, visit);
+    }
 }
 
 impl<N: Unit, D: Unit, S: Scalar + Display> Display for Quantity<Per<N, D>, S>
@@ -78,7 +428,7 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         Display::fmt(&self.value(), f)?;
-        write!(f, " {}/{}", N::SYMBOL, D::SYMBOL)
+        write_unit_suffix::<Per<N, D>>(f)
     }
 }
 
@@ -89,7 +439,7 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         LowerExp::fmt(&self.value(), f)?;
-        write!(f, " {}/{}", N::SYMBOL, D::SYMBOL)
+        write_unit_suffix::<Per<N, D>>(f)
     }
 }
 
@@ -100,7 +450,7 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         UpperExp::fmt(&self.value(), f)?;
-        write!(f, " {}/{}", N::SYMBOL, D::SYMBOL)
+        write_unit_suffix::<Per<N, D>>(f)
     }
 }
 
@@ -118,10 +468,19 @@ where
     <A::Dim as DimMul<B::Dim>>::Output: Dimension,
 {
     const RATIO: f64 = A::RATIO * B::RATIO;
+    const EXACT_RATIO: Option<ExactRatio> = match (A::EXACT_RATIO, B::EXACT_RATIO) {
+        (Some(a), Some(b)) => a.checked_mul(b),
+        _ => None,
+    };
     type Dim = <A::Dim as DimMul<B::Dim>>::Output;
-    // Generic const-string composition is not yet available; formatted symbols
-    // are provided by Display/LowerExp/UpperExp impls for Quantity<Prod<...>, S>.
+    // Generic const-string composition is not yet available; the symbol is
+    // rendered from `visit_factors` by `Unit::fmt_symbol`.
     const SYMBOL: &'static str = "";
+
+    fn visit_factors(exp: i32, visit: &mut dyn FnMut(&'static str, TypeId, i32)) {
+        A::visit_factors(exp, visit);
+        B::visit_factors(exp, visit);
+    }
 }
 
 impl<A: Unit, B: Unit, S: Scalar + Display> Display for Quantity<Prod<A, B>, S>
@@ -131,7 +490,7 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         Display::fmt(&self.value(), f)?;
-        write!(f, " {}·{}", A::SYMBOL, B::SYMBOL)
+        write_unit_suffix::<Prod<A, B>>(f)
     }
 }
 
@@ -142,7 +501,7 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         LowerExp::fmt(&self.value(), f)?;
-        write!(f, " {}·{}", A::SYMBOL, B::SYMBOL)
+        write_unit_suffix::<Prod<A, B>>(f)
     }
 }
 
@@ -153,7 +512,81 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         UpperExp::fmt(&self.value(), f)?;
-        write!(f, " {}·{}", A::SYMBOL, B::SYMBOL)
+        write_unit_suffix::<Prod<A, B>>(f)
+    }
+}
+
+/// Unit representing another unit raised to a type-level integer power.
+///
+/// `Pow<U, N>` corresponds to `U^N`, where `N` is a [`typenum`] integer
+/// (`P3`, `N2`, …). Its dimension multiplies every exponent of `U::Dim` by
+/// `N`, and its ratio is `U::RATIO^N`. This is the unit produced by
+/// [`Quantity::powi`] for exponents outside `1..=3` (which reuse `U` and the
+/// [`Prod`] shapes produced by multiplication).
+///
+/// ```rust
+/// use qtty_core::time::{Second, Seconds};
+/// use qtty_core::typenum::{N2, P4};
+/// use qtty_core::{Pow, Quantity};
+///
+/// let t = Seconds::new(2.0);
+/// let inv: Quantity<Pow<Second, N2>> = t.powi::<N2>();
+/// assert_eq!(inv.value(), 0.25);
+/// assert_eq!(inv.to_string(), "0.25 s⁻²");
+/// assert_eq!(t.powi::<P4>().to_string(), "16 s⁴");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Pow<U: Unit, N: Integer>(PhantomData<(U, N)>);
+
+impl<U: Unit, N: Integer + PartialEq + Debug> Unit for Pow<U, N>
+where
+    U::Dim: DimPow<N>,
+{
+    const RATIO: f64 = powi_f64(U::RATIO, N::I32);
+    const EXACT_RATIO: Option<ExactRatio> = match U::EXACT_RATIO {
+        Some(r) => r.checked_powi(N::I32),
+        None => None,
+    };
+    type Dim = <U::Dim as DimPow<N>>::Output;
+    // Generic const-string composition is not yet available; the symbol is
+    // rendered from `visit_factors` by `Unit::fmt_symbol`.
+    const SYMBOL: &'static str = "";
+
+    fn visit_factors(exp: i32, visit: &mut dyn FnMut(&'static str, TypeId, i32)) {
+        U::visit_factors(exp * N::I32, visit);
+    }
+}
+
+impl<U: Unit, N: Integer + PartialEq + Debug, S: Scalar + Display> Display
+    for Quantity<Pow<U, N>, S>
+where
+    U::Dim: DimPow<N>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.value(), f)?;
+        write_unit_suffix::<Pow<U, N>>(f)
+    }
+}
+
+impl<U: Unit, N: Integer + PartialEq + Debug, S: Scalar + LowerExp> LowerExp
+    for Quantity<Pow<U, N>, S>
+where
+    U::Dim: DimPow<N>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerExp::fmt(&self.value(), f)?;
+        write_unit_suffix::<Pow<U, N>>(f)
+    }
+}
+
+impl<U: Unit, N: Integer + PartialEq + Debug, S: Scalar + UpperExp> UpperExp
+    for Quantity<Pow<U, N>, S>
+where
+    U::Dim: DimPow<N>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        UpperExp::fmt(&self.value(), f)?;
+        write_unit_suffix::<Pow<U, N>>(f)
     }
 }
 
@@ -229,6 +662,129 @@ mod tests {
         assert!(s.ends_with("km·s"), "Expected 'km·s' suffix, got: {s}");
     }
 
+    // ── Pow: Display, LowerExp, UpperExp ──────────────────────────────────────
+
+    #[test]
+    fn pow_display_uses_superscript_exponent() {
+        use typenum::{N1, P4};
+
+        let qty: Quantity<Pow<Meter, P4>> = Quantity::new(2.0);
+        assert_eq!(format!("{qty}"), "2 m⁴");
+        assert_eq!(format!("{qty:.1e}"), "2.0e0 m⁴");
+        assert_eq!(format!("{qty:.1E}"), "2.0E0 m⁴");
+
+        let qty: Quantity<Pow<Second, N1>> = Quantity::new(0.5);
+        assert_eq!(format!("{qty}"), "0.5 s⁻¹");
+    }
+
+    // ── Composite symbols ─────────────────────────────────────────────────────
+
+    fn symbol<U: Unit>() -> String {
+        UnitSymbol::<U>::new().to_string()
+    }
+
+    #[test]
+    fn composite_symbols_nest_and_collapse() {
+        use crate::units::energy::Joule;
+        use crate::units::mass::Kilogram;
+        use crate::units::temperature::Kelvin;
+        use typenum::{N1, P2};
+
+        assert_eq!(symbol::<Meter>(), "m");
+        assert_eq!(symbol::<Prod<Meter, Meter>>(), "m²");
+        assert_eq!(symbol::<Prod<Prod<Meter, Meter>, Meter>>(), "m³");
+        assert_eq!(symbol::<Per<Per<Meter, Second>, Second>>(), "m/s²");
+        assert_eq!(symbol::<Per<Meter, Prod<Second, Second>>>(), "m/s²");
+        assert_eq!(symbol::<Per<Joule, Prod<Kilogram, Kelvin>>>(), "J/(kg·K)");
+        assert_eq!(symbol::<Prod<Kilometer, Meter>>(), "km·m");
+        assert_eq!(symbol::<Pow<Second, N1>>(), "s⁻¹");
+        assert_eq!(symbol::<Per<Pow<Meter, P2>, Pow<Second, P2>>>(), "m²/s²");
+        assert_eq!(symbol::<Prod<Per<Meter, Second>, Second>>(), "m");
+    }
+
+    #[test]
+    fn composite_display_uses_rendered_symbol() {
+        let qty: Quantity<Per<Per<Meter, Second>, Second>> = Quantity::new(9.81);
+        assert_eq!(format!("{qty}"), "9.81 m/s²");
+        assert_eq!(format!("{qty:.1e}"), "9.8e0 m/s²");
+        assert_eq!(format!("{qty:.1E}"), "9.8E0 m/s²");
+
+        let qty: Quantity<Prod<Meter, Meter>> = Quantity::new(4.0);
+        assert_eq!(format!("{qty}"), "4 m²");
+
+        // Fully cancelled units print the bare value.
+        let qty: Quantity<Per<Meter, Meter>> = Quantity::new(0.5);
+        assert_eq!(format!("{qty}"), "0.5");
+    }
+
+    #[cfg(feature = "astro")]
+    #[test]
+    fn distinct_units_sharing_a_symbol_do_not_cancel() {
+        use crate::units::angular::HourAngle;
+        use crate::units::time::Hour;
+
+        assert_eq!(symbol::<Per<Hour, Hour>>(), "");
+        assert_eq!(symbol::<Per<Hour, HourAngle>>(), "h/h");
+        assert_eq!(symbol::<Prod<Hour, HourAngle>>(), "h·h");
+
+        let qty: Quantity<Per<Hour, HourAngle>> = Quantity::new(2.0);
+        assert_eq!(format!("{qty}"), "2 h/h");
+    }
+
+    // ── ExactRatio ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn exact_ratio_is_reduced_and_displays() {
+        let r = ExactRatio::new(3_600, 60);
+        assert_eq!((r.numer(), r.denom()), (60, 1));
+        assert_eq!(r.to_string(), "60");
+        assert_eq!(ExactRatio::new(10, 18).to_string(), "5/9");
+        assert_eq!(ExactRatio::new(2, 3).recip(), ExactRatio::new(3, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly positive")]
+    fn exact_ratio_rejects_zero() {
+        let _ = ExactRatio::new(0, 1);
+    }
+
+    #[test]
+    fn exact_ratio_checked_ops_detect_overflow() {
+        let big = ExactRatio::new(i128::MAX, 1);
+        assert_eq!(
+            big.checked_mul(ExactRatio::new(1, 2)),
+            Some(ExactRatio::new(i128::MAX, 2))
+        );
+        assert_eq!(big.checked_mul(ExactRatio::new(2, 1)), None);
+        assert_eq!(big.checked_div(big), Some(ExactRatio::ONE));
+    }
+
+    #[test]
+    fn exact_ratio_checked_powi() {
+        let km = ExactRatio::new(1_000, 1);
+        assert_eq!(km.checked_powi(0), Some(ExactRatio::ONE));
+        assert_eq!(km.checked_powi(3), Some(ExactRatio::new(1_000_000_000, 1)));
+        assert_eq!(km.checked_powi(-2), Some(ExactRatio::new(1, 1_000_000)));
+        assert_eq!(km.checked_powi(13), None);
+    }
+
+    #[test]
+    fn composite_units_combine_exact_ratios() {
+        use crate::units::angular::Radian;
+        use crate::units::time::Hour;
+
+        assert_eq!(
+            <Per<Kilometer, Hour>>::EXACT_RATIO,
+            Some(ExactRatio::new(5, 18))
+        );
+        assert_eq!(
+            <Prod<Kilometer, Kilometer>>::EXACT_RATIO,
+            Some(ExactRatio::new(1_000_000, 1))
+        );
+        assert_eq!(Radian::EXACT_RATIO, None);
+        assert_eq!(<Per<Meter, Radian>>::EXACT_RATIO, None);
+    }
+
     // ── Unitless: LowerExp, UpperExp ──────────────────────────────────────────
     // (tests removed — Unitless is no longer a type; same-unit division returns S)
 }
@@ -15,6 +15,10 @@
 //! - `Per<N, D> * D → N`
 //! - `D * Per<N, D> → N`
 //!
+//! Integer powers and roots are described by [`UnitPow`] and [`UnitRoot`]:
+//! `U^1 → U`, `U^2 → Prod<U, U>`, `U^3 → Prod<Prod<U, U>, U>`, and any other
+//! exponent produces [`Pow<U, N>`](Pow). Roots undo those shapes.
+//!
 //! For all built-in unit pairs (plain marker types), fallback impls produce the
 //! default composite types:
 //!
@@ -27,10 +31,13 @@
 //! register their own custom units into the same fallback tables without
 //! regenerating built-in/built-in impls.
 
-use crate::dimension::{DimDiv, DimMul, Dimension};
+use crate::dimension::{DimDiv, DimMul, DimPow, Dimension};
 use crate::quantity::Quantity;
 use crate::scalar::Scalar;
-use crate::unit::{Per, Prod, Unit};
+use crate::unit::{Per, Pow, Prod, Unit};
+use core::fmt::Debug;
+use typenum::{Bit, Integer, NInt, NonZero, PInt, PartialDiv, PartialQuot, UInt, Unsigned};
+use typenum::{P1, P2, P3, P4, Z0};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Core traits
@@ -86,6 +93,144 @@ where
     type Root = U;
 }
 
+impl<U: Unit, M: Integer + PartialEq + Debug> UnitSqrt for Pow<U, M>
+where
+    U::Dim: DimPow<M>,
+    Pow<U, M>: UnitRoot<P2>,
+{
+    type Root = <Pow<U, M> as UnitRoot<P2>>::Root;
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Integer powers and roots
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Determines the unit type produced by raising a quantity of unit `Self` to
+/// the type-level integer power `N`.
+///
+/// Implemented for every unit and every [`typenum`] integer exponent. Small
+/// positive exponents reuse the shapes produced by multiplication, so
+/// `Meters::powi::<P2>()` is a [`SquareMeters`](crate::area::SquareMeters):
+///
+/// | `N`            | `Output`                   |
+/// |----------------|----------------------------|
+/// | `P1`           | `U`                        |
+/// | `P2`           | `Prod<U, U>`               |
+/// | `P3`           | `Prod<Prod<U, U>, U>`      |
+/// | anything else  | [`Pow<U, N>`](Pow)         |
+pub trait UnitPow<N: Integer>: Unit {
+    /// The resulting unit type.
+    type Output: Unit;
+}
+
+impl<U: Unit> UnitPow<P1> for U {
+    type Output = U;
+}
+
+impl<U: Unit> UnitPow<P2> for U
+where
+    U::Dim: DimMul<U::Dim>,
+{
+    type Output = Prod<U, U>;
+}
+
+impl<U: Unit> UnitPow<P3> for U
+where
+    U::Dim: DimMul<U::Dim>,
+    <U::Dim as DimMul<U::Dim>>::Output: DimMul<U::Dim>,
+{
+    type Output = Prod<Prod<U, U>, U>;
+}
+
+// N ≥ 4: three or more binary digits.
+impl<U, Ux, Bx, By, Bz> UnitPow<PInt<UInt<UInt<UInt<Ux, Bx>, By>, Bz>>> for U
+where
+    U: Unit,
+    Ux: Unsigned,
+    Bx: Bit,
+    By: Bit,
+    Bz: Bit,
+    UInt<UInt<UInt<Ux, Bx>, By>, Bz>: Unsigned + NonZero + PartialEq + Debug,
+    U::Dim: DimPow<PInt<UInt<UInt<UInt<Ux, Bx>, By>, Bz>>>,
+{
+    type Output = Pow<U, PInt<UInt<UInt<UInt<Ux, Bx>, By>, Bz>>>;
+}
+
+impl<U: Unit, Un: Unsigned + NonZero + PartialEq + Debug> UnitPow<NInt<Un>> for U
+where
+    U::Dim: DimPow<NInt<Un>>,
+{
+    type Output = Pow<U, NInt<Un>>;
+}
+
+impl<U: Unit> UnitPow<Z0> for U
+where
+    U::Dim: DimPow<Z0>,
+{
+    type Output = Pow<U, Z0>;
+}
+
+/// Inverse of [`UnitPow`]: maps a unit that is the `N`-th power of another
+/// unit back to that root unit.
+///
+/// Implemented for:
+///
+/// - [`Pow<U, M>`](Pow) whenever `N` divides `M` exactly (the root is
+///   `U^(M/N)`, normalised through [`UnitPow`]);
+/// - the product shapes produced by multiplication: `Prod<U, U>` (`N = 2`),
+///   `Prod<Prod<U, U>, U>` and `Prod<U, Prod<U, U>>` (`N = 3`), and
+///   `Prod<Prod<U, U>, Prod<U, U>>` (`N = 4`);
+/// - named volume units such as [`CubicMeter`](crate::volume::CubicMeter)
+///   (`N = 3`).
+///
+/// Used by [`Quantity::cbrt`](crate::Quantity::cbrt) and
+/// [`Quantity::root`](crate::Quantity::root).
+pub trait UnitRoot<N: Integer>: Unit {
+    /// The unit whose `N`-th power is `Self`.
+    type Root: Unit;
+}
+
+impl<U, M, N> UnitRoot<N> for Pow<U, M>
+where
+    U: Unit + UnitPow<PartialQuot<M, N>>,
+    M: Integer + PartialEq + Debug + PartialDiv<N>,
+    N: Integer,
+    PartialQuot<M, N>: Integer,
+    U::Dim: DimPow<M>,
+{
+    type Root = <U as UnitPow<PartialQuot<M, N>>>::Output;
+}
+
+impl<U: Unit> UnitRoot<P2> for Prod<U, U>
+where
+    U::Dim: DimMul<U::Dim>,
+{
+    type Root = U;
+}
+
+impl<U: Unit> UnitRoot<P3> for Prod<Prod<U, U>, U>
+where
+    U::Dim: DimMul<U::Dim>,
+    <U::Dim as DimMul<U::Dim>>::Output: DimMul<U::Dim>,
+{
+    type Root = U;
+}
+
+impl<U: Unit> UnitRoot<P3> for Prod<U, Prod<U, U>>
+where
+    U::Dim: DimMul<U::Dim> + DimMul<<U::Dim as DimMul<U::Dim>>::Output>,
+{
+    type Root = U;
+}
+
+impl<U: Unit> UnitRoot<P4> for Prod<Prod<U, U>, Prod<U, U>>
+where
+    U::Dim: DimMul<U::Dim>,
+    <U::Dim as DimMul<U::Dim>>::Output: DimMul<<U::Dim as DimMul<U::Dim>>::Output>,
+{
+    type Root = U;
+}
+
 // Marker for plain built-in units. This lets built-in multiplication use a
 // single generic impl instead of an O(n²) generated impl table.
 pub(crate) trait BuiltinUnit: Unit {}
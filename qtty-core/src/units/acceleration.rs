@@ -67,7 +67,13 @@ pub type Accel<L, T> = Quantity<Per<L, Prod<T, T>>>;
 
 /// Metre per second squared — SI coherent unit of acceleration.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "m/s²", dimension = Acceleration, ratio = 1.0)]
+#[unit(
+    symbol = "m/s²", dimension = Acceleration, ratio = 1.0, exact_ratio = 1,
+    long_name = "metre per second squared",
+    plural = "metres per second squared",
+    system = "SI",
+    aliases = ["meter per second squared", "meters per second squared"],
+)]
 pub struct MeterPerSecondSquared;
 /// A quantity measured in metres per second squared.
 pub type MetersPerSecondSquared = Quantity<MeterPerSecondSquared>;
@@ -76,7 +82,10 @@ pub const METER_PER_SECOND_SQUARED: MetersPerSecondSquared = MetersPerSecondSqua
 
 /// Standard gravity (g₀ = 9.806 65 m/s², exact by definition).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "g₀", dimension = Acceleration, ratio = 9.80665)]
+#[unit(
+    symbol = "g₀", dimension = Acceleration, ratio = 9.80665, exact_ratio = 980_665 / 100_000,
+    system = "other",
+)]
 pub struct StandardGravity;
 /// A quantity measured in standard gravities.
 pub type StandardGravities = Quantity<StandardGravity>;
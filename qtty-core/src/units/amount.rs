@@ -34,7 +34,7 @@ impl<T: Unit<Dim = AmountOfSubstance>> AmountUnit for T {}
 /// Redefined in the 2019 SI revision as exactly 6.022 140 76 × 10²³ elementary
 /// entities (Avogadro number).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mol", dimension = AmountOfSubstance, ratio = 1.0)]
+#[unit(symbol = "mol", dimension = AmountOfSubstance, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Mole;
 /// Type alias shorthand for [`Mole`].
 pub type Mol = Mole;
@@ -44,10 +44,13 @@ pub type Moles = Quantity<Mol>;
 pub const MOLE: Moles = Moles::new(1.0);
 
 macro_rules! si_mole {
-    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident) => {
+    ($name:ident, $sym:literal, $ratio:expr, $exact:expr, $alias:ident, $qty:ident, $one:ident) => {
         #[doc = concat!("SI-prefixed mole unit (", stringify!($ratio), " mol).")]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-        #[unit(symbol = $sym, dimension = AmountOfSubstance, ratio = $ratio)]
+        #[unit(
+            symbol = $sym, dimension = AmountOfSubstance, ratio = $ratio, exact_ratio = $exact,
+            system = "SI",
+        )]
         pub struct $name;
         #[doc = concat!("Type alias shorthand for [`", stringify!($name), "`].")]
         pub type $alias = $name;
@@ -58,10 +61,34 @@ macro_rules! si_mole {
     };
 }
 
-si_mole!(Nanomole, "nmol", 1e-9, Nmol, Nanomoles, NANOMOLE);
-si_mole!(Micromole, "µmol", 1e-6, Umol, Micromoles, MICROMOLE);
-si_mole!(Millimole, "mmol", 1e-3, Mmol, Millimoles, MILLIMOLE);
-si_mole!(Kilomole, "kmol", 1e3, Kmol, Kilomoles, KILOMOLE);
+si_mole!(
+    Nanomole,
+    "nmol",
+    1e-9,
+    1 / 1_000_000_000,
+    Nmol,
+    Nanomoles,
+    NANOMOLE
+);
+si_mole!(
+    Micromole,
+    "µmol",
+    1e-6,
+    1 / 1_000_000,
+    Umol,
+    Micromoles,
+    MICROMOLE
+);
+si_mole!(
+    Millimole,
+    "mmol",
+    1e-3,
+    1 / 1_000,
+    Mmol,
+    Millimoles,
+    MILLIMOLE
+);
+si_mole!(Kilomole, "kmol", 1e3, 1_000, Kmol, Kilomoles, KILOMOLE);
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Unit inventory macro
@@ -6,7 +6,10 @@ use qtty_derive::Unit;
 
 /// Arcminute (`1/60` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "′", dimension = Angular, ratio = 1.0 / 60.0)]
+#[unit(
+    symbol = "′", dimension = Angular, ratio = 1.0 / 60.0, exact_ratio = 1 / 60,
+    system = "SI-accepted", aliases = ["arcmin"],
+)]
 pub struct Arcminute;
 /// Alias for [`Arcminute`] (minute of angle, MOA).
 pub type MOA = Arcminute;
@@ -19,7 +22,10 @@ pub const ARCM: Arcminutes = Arcminutes::new(1.0);
 
 /// Arcsecond (`1/3600` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "″", dimension = Angular, ratio = 1.0 / 3600.0)]
+#[unit(
+    symbol = "″", dimension = Angular, ratio = 1.0 / 3600.0, exact_ratio = 1 / 3_600,
+    system = "SI-accepted", aliases = ["arcsec"],
+)]
 pub struct Arcsecond;
 /// Type alias shorthand for [`Arcsecond`].
 pub type Arcs = Arcsecond;
@@ -30,7 +36,10 @@ pub const ARCS: Arcseconds = Arcseconds::new(1.0);
 
 /// Milliarcsecond (`1/3_600_000` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mas", dimension = Angular, ratio = 1.0 / 3_600_000.0)]
+#[unit(
+    symbol = "mas", dimension = Angular, ratio = 1.0 / 3_600_000.0, exact_ratio = 1 / 3_600_000,
+    long_name = "milliarcsecond", system = "astronomical",
+)]
 pub struct MilliArcsecond;
 /// Type alias shorthand for [`MilliArcsecond`].
 pub type Mas = MilliArcsecond;
@@ -41,7 +50,13 @@ pub const MAS: MilliArcseconds = MilliArcseconds::new(1.0);
 
 /// Microarcsecond (`1/3_600_000_000` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "μas", dimension = Angular, ratio = 1.0 / 3_600_000_000.0)]
+#[unit(
+    symbol = "μas",
+    dimension = Angular,
+    ratio = 1.0 / 3_600_000_000.0,
+    exact_ratio = 1 / 3_600_000_000,
+    long_name = "microarcsecond", system = "astronomical",
+)]
 pub struct MicroArcsecond;
 /// Type alias shorthand for [`MicroArcsecond`].
 pub type Uas = MicroArcsecond;
@@ -52,7 +67,7 @@ pub const UAS: MicroArcseconds = MicroArcseconds::new(1.0);
 
 /// Hour angle hour (`15` degrees).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "h", dimension = Angular, ratio = 15.0)]
+#[unit(symbol = "h", dimension = Angular, ratio = 15.0, exact_ratio = 15, system = "astronomical")]
 pub struct HourAngle;
 /// Type alias shorthand for [`HourAngle`].
 pub type Hms = HourAngle;
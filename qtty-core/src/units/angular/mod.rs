@@ -107,6 +107,10 @@ pub use astro::*;
 mod navigation;
 #[cfg(feature = "navigation")]
 pub use navigation::*;
+pub mod sexagesimal;
+
+#[cfg(feature = "rand")]
+pub use crate::feature_rand::UniformDirection;
 
 impl<U: AngularUnit + Copy> Quantity<U> {
     /// Constant representing τ radians (2π rad == 360°).
@@ -426,7 +430,7 @@ impl<U: AngularUnit + Copy, S: Transcendental> Quantity<U, S> {
 
 /// Degree.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "°", dimension = Angular, ratio = 1.0)]
+#[unit(symbol = "°", dimension = Angular, ratio = 1.0, exact_ratio = 1, system = "SI-accepted")]
 pub struct Degree;
 /// Type alias shorthand for [`Degree`].
 pub type Deg = Degree;
@@ -437,7 +441,7 @@ pub const DEG: Degrees = Degrees::new(1.0);
 
 /// Radian.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "rad", dimension = Angular, ratio = 180.0 / core::f64::consts::PI)]
+#[unit(symbol = "rad", dimension = Angular, ratio = 180.0 / core::f64::consts::PI, system = "SI")]
 pub struct Radian;
 /// Type alias shorthand for [`Radian`].
 pub type Rad = Radian;
@@ -448,7 +452,10 @@ pub const RAD: Radians = Radians::new(1.0);
 
 /// Milliradian (`1/1000` radian).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mrad", dimension = Angular, ratio = (180.0 / core::f64::consts::PI) / 1_000.0)]
+#[unit(
+    symbol = "mrad", dimension = Angular, ratio = (180.0 / core::f64::consts::PI) / 1_000.0,
+    system = "SI",
+)]
 pub struct Milliradian;
 /// Type alias shorthand for [`Milliradian`].
 pub type Mrad = Milliradian;
@@ -459,7 +466,10 @@ pub const MRAD: Milliradians = Milliradians::new(1.0);
 
 /// Turn (full revolution; `360` degrees).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "tr", dimension = Angular, ratio = 360.0)]
+#[unit(
+    symbol = "tr", dimension = Angular, ratio = 360.0, exact_ratio = 360,
+    system = "other", aliases = ["revolution", "revolutions"],
+)]
 pub struct Turn;
 /// Convenience alias for a turn quantity.
 pub type Turns = Quantity<Turn>;
@@ -6,7 +6,10 @@ use qtty_derive::Unit;
 
 /// Gradian (also called gon; `1/400` of a full turn = `0.9` degree).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "gon", dimension = Angular, ratio = 0.9)]
+#[unit(
+    symbol = "gon", dimension = Angular, ratio = 0.9, exact_ratio = 9 / 10,
+    system = "other", aliases = ["grad", "grads"],
+)]
 pub struct Gradian;
 /// Type alias shorthand for [`Gradian`].
 pub type Gon = Gradian;
@@ -0,0 +1,618 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Sexagesimal (base-60) angle notation.
+//!
+//! [`Dms`] splits an angle into degrees, arcminutes and arcseconds; [`Hms`]
+//! splits it into hours, minutes and seconds of hour angle (1ʰ = 15°). Both
+//! store the sign separately from the components, so angles between −1° and
+//! 0° such as `-00°30′00″` keep their sign.
+//!
+//! Both types implement [`Display`] and [`FromStr`]:
+//!
+//! * `{}` uses symbols (`12°34′56.7″`, `05h30m00s`) and `{:#}` uses colons
+//!   (`12:34:56.7`). `{:+}` always prints the sign.
+//! * A precision (`{:.2}`) rounds the seconds first, carrying into minutes
+//!   and degrees/hours: `59.9999″` becomes the next minute.
+//! * Parsing accepts an optional `+`, `-` or `−` sign, followed by up to three
+//!   components separated by `:`, whitespace or designators (`°`/`d`,
+//!   `′`/`'`/`m`, `″`/`"`/`s` for [`Dms`]; `h`/`ʰ`, `m`/`ᵐ`, `s`/`ˢ` for
+//!   [`Hms`]). Only the last component may have a fractional part.
+//!
+//! ```rust
+//! use qtty_core::angular::sexagesimal::{Dms, Hms};
+//! use qtty_core::angular::Degrees;
+//!
+//! let dec: Dms = "-00°30′".parse().unwrap();
+//! assert_eq!(dec.to_degrees(), Degrees::new(-0.5));
+//! assert_eq!(dec.to_string(), "-00°30′00″");
+//!
+//! let ra = Degrees::new(82.5).to_hms();
+//! assert_eq!(ra.to_string(), "05h30m00s");
+//! assert_eq!(format!("{ra:#}"), "05:30:00");
+//! ```
+//!
+//! [`Hms`] here is a component struct; it is unrelated to the `Hms` alias of
+//! the `HourAngle` unit exported by the `astro` feature.
+
+use super::{AngularUnit, Degrees};
+use crate::scalar::{Real, Scalar};
+use crate::Quantity;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Component structs
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// An angle split into sign, degrees, arcminutes and arcseconds.
+///
+/// Values produced by [`Dms::from_angle`], [`Dms::round_seconds`] and parsing
+/// always have `minutes < 60` and `seconds < 60`. The fields are public, so a
+/// hand-built value may break that; [`Dms::to_degrees`] still sums the
+/// components as given.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dms {
+    /// Whether the angle is negative.
+    pub negative: bool,
+    /// Whole degrees.
+    pub degrees: u32,
+    /// Whole arcminutes.
+    pub minutes: u32,
+    /// Arcseconds, including any fractional part.
+    pub seconds: f64,
+}
+
+/// An angle split into sign, hours, minutes and seconds of hour angle.
+///
+/// One hour is 15°. The same invariants as [`Dms`] apply to `minutes` and
+/// `seconds`; `hours` is not wrapped to 24.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hms {
+    /// Whether the angle is negative.
+    pub negative: bool,
+    /// Whole hours.
+    pub hours: u32,
+    /// Whole minutes.
+    pub minutes: u32,
+    /// Seconds, including any fractional part.
+    pub seconds: f64,
+}
+
+impl Dms {
+    /// Creates a value from its components.
+    #[inline]
+    pub const fn new(negative: bool, degrees: u32, minutes: u32, seconds: f64) -> Self {
+        Self {
+            negative,
+            degrees,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Splits a finite angle into degrees, arcminutes and arcseconds.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::sexagesimal::Dms;
+    /// use qtty_core::angular::Degrees;
+    ///
+    /// let dms = Dms::from_angle(Degrees::new(-12.5));
+    /// assert_eq!(dms, Dms::new(true, 12, 30, 0.0));
+    /// ```
+    pub fn from_angle<U: AngularUnit>(angle: Quantity<U>) -> Self {
+        let value = angle.to::<super::Degree>().value();
+        let (major, minutes, seconds) = split(Scalar::abs(value));
+        Self::new(value < 0.0, major, minutes, seconds)
+    }
+
+    /// Returns the angle in degrees.
+    #[inline]
+    pub const fn to_degrees(self) -> Degrees {
+        let sign = if self.negative { -1 } else { 1 };
+        Degrees::from_dms_sign(sign, self.degrees, self.minutes, self.seconds)
+    }
+
+    /// Returns the angle in any angular unit.
+    #[inline]
+    pub fn to_angle<U: AngularUnit>(self) -> Quantity<U> {
+        self.to_degrees().to()
+    }
+
+    /// Rounds the arcseconds to `decimals` digits, carrying a rounded-up
+    /// `60″` into the minutes and a resulting `60′` into the degrees.
+    ///
+    /// ```rust
+    /// use qtty_core::angular::sexagesimal::Dms;
+    ///
+    /// let dms = Dms::new(false, 12, 59, 59.9999).round_seconds(2);
+    /// assert_eq!(dms, Dms::new(false, 13, 0, 0.0));
+    /// ```
+    pub fn round_seconds(self, decimals: u32) -> Self {
+        let (degrees, minutes, seconds) =
+            round_parts(self.degrees, self.minutes, self.seconds, decimals);
+        Self::new(self.negative, degrees, minutes, seconds)
+    }
+}
+
+impl Hms {
+    /// Creates a value from its components.
+    #[inline]
+    pub const fn new(negative: bool, hours: u32, minutes: u32, seconds: f64) -> Self {
+        Self {
+            negative,
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+
+    /// Splits a finite angle into hours, minutes and seconds (1ʰ = 15°).
+    ///
+    /// ```rust
+    /// use qtty_core::angular::sexagesimal::Hms;
+    /// use qtty_core::angular::Degrees;
+    ///
+    /// let hms = Hms::from_angle(Degrees::new(83.633));
+    /// assert_eq!((hms.hours, hms.minutes), (5, 34));
+    /// ```
+    pub fn from_angle<U: AngularUnit>(angle: Quantity<U>) -> Self {
+        let value = angle.to::<super::Degree>().value();
+        let (major, minutes, seconds) = split(Scalar::abs(value) / 15.0);
+        Self::new(value < 0.0, major, minutes, seconds)
+    }
+
+    /// Returns the angle in degrees.
+    #[inline]
+    pub const fn to_degrees(self) -> Degrees {
+        let total = self.hours as f64 + self.minutes as f64 / 60.0 + self.seconds / 3600.0;
+        let sign = if self.negative { -1.0 } else { 1.0 };
+        Degrees::new(sign * total * 15.0)
+    }
+
+    /// Returns the angle in any angular unit.
+    #[inline]
+    pub fn to_angle<U: AngularUnit>(self) -> Quantity<U> {
+        self.to_degrees().to()
+    }
+
+    /// Rounds the seconds to `decimals` digits, carrying a rounded-up `60s`
+    /// into the minutes and a resulting `60m` into the hours.
+    pub fn round_seconds(self, decimals: u32) -> Self {
+        let (hours, minutes, seconds) =
+            round_parts(self.hours, self.minutes, self.seconds, decimals);
+        Self::new(self.negative, hours, minutes, seconds)
+    }
+}
+
+impl<U: AngularUnit + Copy> Quantity<U> {
+    /// Splits this angle into degrees, arcminutes and arcseconds.
+    ///
+    /// Shorthand for [`Dms::from_angle`].
+    #[inline]
+    pub fn to_dms(self) -> Dms {
+        Dms::from_angle(self)
+    }
+
+    /// Splits this angle into hours, minutes and seconds (1ʰ = 15°).
+    ///
+    /// Shorthand for [`Hms::from_angle`].
+    #[inline]
+    pub fn to_hms(self) -> Hms {
+        Hms::from_angle(self)
+    }
+}
+
+/// Splits a non-negative magnitude into whole units, whole minutes and
+/// seconds, normalising the rare case where rounding yields `60`.
+fn split(magnitude: f64) -> (u32, u32, f64) {
+    let major = Real::floor(magnitude);
+    let minutes_f = (magnitude - major) * 60.0;
+    let minutes = Real::floor(minutes_f);
+    let seconds = (minutes_f - minutes) * 60.0;
+    carry(major as u32, minutes as u32, seconds)
+}
+
+/// Rounds `seconds` to `decimals` digits and carries overflow upwards.
+fn round_parts(major: u32, minutes: u32, seconds: f64, decimals: u32) -> (u32, u32, f64) {
+    // Seconds never need more than 17 significant digits.
+    let factor = crate::dynamic::powi_f64(10.0, Ord::min(decimals, 17) as i32);
+    carry(major, minutes, Real::round(seconds * factor) / factor)
+}
+
+fn carry(mut major: u32, mut minutes: u32, mut seconds: f64) -> (u32, u32, f64) {
+    if seconds >= 60.0 {
+        seconds -= 60.0;
+        minutes += 1;
+    }
+    if minutes >= 60 {
+        minutes -= 60;
+        major = major.saturating_add(1);
+    }
+    (major, minutes, seconds)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Formatting
+// ─────────────────────────────────────────────────────────────────────────────
+
+const DMS_MARKS: [&str; 3] = ["°", "′", "″"];
+const HMS_MARKS: [&str; 3] = ["h", "m", "s"];
+const COLON_MARKS: [&str; 3] = [":", ":", ""];
+
+/// Writes `[sign]MM<m>mm<m>ss<m>`, rounding the seconds to the formatter's
+/// precision when one is given.
+fn write_parts(
+    f: &mut Formatter<'_>,
+    negative: bool,
+    (major, minutes, seconds): (u32, u32, f64),
+    marks: [&str; 3],
+) -> fmt::Result {
+    let marks = if f.alternate() { COLON_MARKS } else { marks };
+    if negative {
+        f.write_str("-")?;
+    } else if f.sign_plus() {
+        f.write_str("+")?;
+    }
+    write!(f, "{major:02}{}{minutes:02}{}", marks[0], marks[1])?;
+    match f.precision() {
+        Some(p) => {
+            let width = if p == 0 { 2 } else { p + 3 };
+            write!(f, "{seconds:0width$.p$}")?;
+        }
+        None => {
+            if seconds < 10.0 {
+                f.write_str("0")?;
+            }
+            write!(f, "{seconds}")?;
+        }
+    }
+    f.write_str(marks[2])
+}
+
+impl Display for Dms {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let dms = match f.precision() {
+            Some(p) => self.round_seconds(p as u32),
+            None => *self,
+        };
+        let parts = (dms.degrees, dms.minutes, dms.seconds);
+        write_parts(f, dms.negative, parts, DMS_MARKS)
+    }
+}
+
+impl Display for Hms {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let hms = match f.precision() {
+            Some(p) => self.round_seconds(p as u32),
+            None => *self,
+        };
+        let parts = (hms.hours, hms.minutes, hms.seconds);
+        write_parts(f, hms.negative, parts, HMS_MARKS)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Parsing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Error returned when a sexagesimal string cannot be parsed.
+///
+/// Positions are byte offsets into the (untrimmed) input string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseSexagesimalError {
+    /// The input contains no components.
+    Empty,
+    /// The component starting at `position` is not a number.
+    InvalidNumber {
+        /// Byte offset of the component.
+        position: usize,
+    },
+    /// An unexpected character (or the end of input) was found at `position`.
+    UnexpectedChar {
+        /// Byte offset of the unexpected character.
+        position: usize,
+    },
+    /// The minutes or seconds starting at `position` are 60 or more, or the
+    /// leading component does not fit in a `u32`.
+    OutOfRange {
+        /// Byte offset of the component.
+        position: usize,
+    },
+}
+
+impl Display for ParseSexagesimalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty sexagesimal string"),
+            Self::InvalidNumber { position } => write!(f, "invalid number at byte {position}"),
+            Self::UnexpectedChar { position } => {
+                write!(f, "unexpected character at byte {position}")
+            }
+            Self::OutOfRange { position } => {
+                write!(f, "component out of range at byte {position}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSexagesimalError {}
+
+const DMS_DESIGNATORS: [&[char]; 3] = [&['°', 'd'], &['′', '\'', 'm'], &['″', '"', 's']];
+const HMS_DESIGNATORS: [&[char]; 3] = [&['h', 'ʰ'], &['m', 'ᵐ'], &['s', 'ˢ']];
+
+/// Parses `[sign] a [sep b [sep c]]` into a sign and normalised components.
+///
+/// A designator assigns its number to that slot (slots must increase, so
+/// `12°56″` skips the minutes); `:` and whitespace assign to the next slot.
+fn parse_parts(
+    s: &str,
+    designators: [&[char]; 3],
+) -> Result<(bool, (u32, u32, f64)), ParseSexagesimalError> {
+    use ParseSexagesimalError as E;
+
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        return Err(E::Empty);
+    }
+    let mut pos = s.len() - s.trim_start().len();
+    let end = pos + trimmed.len();
+
+    let negative = match trimmed.chars().next() {
+        Some(c @ ('+' | '-' | '−')) => {
+            pos += c.len_utf8();
+            c != '+'
+        }
+        _ => false,
+    };
+    if pos == end {
+        return Err(E::UnexpectedChar { position: end });
+    }
+
+    let mut values = [0.0_f64; 3];
+    let mut slot = 0;
+    let mut fractional_slot = None;
+    while pos < end {
+        if slot == 3 || fractional_slot.is_some() {
+            return Err(E::UnexpectedChar { position: pos });
+        }
+
+        let rest = &s[pos..end];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let text = &rest[..len];
+        let value: f64 = text
+            .parse()
+            .map_err(|_| E::InvalidNumber { position: pos })?;
+        let number_pos = pos;
+        pos += len;
+
+        let next = s[pos..end].chars().next();
+        let designated = next.and_then(|c| designators.iter().position(|d| d.contains(&c)));
+        let target = match (next, designated) {
+            (Some(c), Some(k)) if k >= slot => {
+                pos += c.len_utf8();
+                k
+            }
+            (None, _) => slot,
+            (Some(':'), None) => {
+                pos += 1;
+                if pos == end {
+                    return Err(E::UnexpectedChar { position: end });
+                }
+                slot
+            }
+            (Some(c), None) if c.is_whitespace() => slot,
+            _ => return Err(E::UnexpectedChar { position: pos }),
+        };
+        pos = end - s[pos..end].trim_start().len();
+
+        let limit = if target == 0 { u32::MAX as f64 } else { 60.0 };
+        if value >= limit {
+            return Err(E::OutOfRange {
+                position: number_pos,
+            });
+        }
+        if text.contains('.') {
+            fractional_slot = Some(target);
+        }
+        values[target] = value;
+        slot = target + 1;
+    }
+
+    let [major, minutes, seconds] = values;
+    let parts = match fractional_slot {
+        Some(0) => split(major),
+        Some(1) => {
+            let whole = Real::floor(minutes);
+            carry(major as u32, whole as u32, (minutes - whole) * 60.0)
+        }
+        _ => (major as u32, minutes as u32, seconds),
+    };
+    Ok((negative, parts))
+}
+
+impl FromStr for Dms {
+    type Err = ParseSexagesimalError;
+
+    /// Parses notations such as `+12°34′56.7″`, `12d34m56.7s`, `12:34:56.7`,
+    /// `12 34 56.7` or `-00°30′`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, (degrees, minutes, seconds)) = parse_parts(s, DMS_DESIGNATORS)?;
+        Ok(Self::new(negative, degrees, minutes, seconds))
+    }
+}
+
+impl FromStr for Hms {
+    type Err = ParseSexagesimalError;
+
+    /// Parses notations such as `05h30m00s`, `5ʰ30ᵐ`, `05:30:00.25` or
+    /// `05 30 00`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, (hours, minutes, seconds)) = parse_parts(s, HMS_DESIGNATORS)?;
+        Ok(Self::new(negative, hours, minutes, seconds))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::units::angular::{Radian, Radians};
+    use approx::assert_abs_diff_eq;
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Splitting and rounding
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn splits_degrees_and_hours() {
+        let dms = Degrees::new(12.582_416_666_666_667).to_dms();
+        assert_eq!((dms.negative, dms.degrees, dms.minutes), (false, 12, 34));
+        assert_abs_diff_eq!(dms.seconds, 56.7, epsilon = 1e-9);
+
+        assert_eq!(Degrees::new(82.5).to_hms(), Hms::new(false, 5, 30, 0.0));
+        assert_eq!(Degrees::new(-0.5).to_dms(), Dms::new(true, 0, 30, 0.0));
+    }
+
+    #[test]
+    fn split_round_trips_through_angle() {
+        for deg in [-179.999_999, -33.866_666, -0.25, 0.0, 1e-9, 45.0, 359.999_9] {
+            let back = Degrees::new(deg).to_dms().to_degrees();
+            assert_abs_diff_eq!(back.value(), deg, epsilon = 1e-12);
+            let back = Degrees::new(deg).to_hms().to_degrees();
+            assert_abs_diff_eq!(back.value(), deg, epsilon = 1e-12);
+        }
+        let r = Radians::new(1.0);
+        let back: Radians = r.to_dms().to_angle::<Radian>();
+        assert_abs_diff_eq!(back.value(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn rounding_carries_into_minutes_and_degrees() {
+        assert_eq!(
+            Dms::new(false, 12, 34, 59.9999).round_seconds(3),
+            Dms::new(false, 12, 35, 0.0)
+        );
+        assert_eq!(
+            Dms::new(true, 0, 59, 59.96).round_seconds(1),
+            Dms::new(true, 1, 0, 0.0)
+        );
+        assert_eq!(
+            Hms::new(false, 23, 59, 59.5).round_seconds(0),
+            Hms::new(false, 24, 0, 0.0)
+        );
+        assert_eq!(Dms::new(false, 1, 2, 3.456).round_seconds(2).seconds, 3.46);
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Display
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn display_symbol_and_colon_forms() {
+        let dms = Dms::new(false, 12, 34, 56.7);
+        assert_eq!(dms.to_string(), "12°34′56.7″");
+        assert_eq!(format!("{dms:+}"), "+12°34′56.7″");
+        assert_eq!(format!("{dms:#}"), "12:34:56.7");
+        assert_eq!(Dms::new(true, 0, 30, 0.0).to_string(), "-00°30′00″");
+
+        let hms = Hms::new(false, 5, 30, 0.0);
+        assert_eq!(hms.to_string(), "05h30m00s");
+        assert_eq!(format!("{hms:#}"), "05:30:00");
+        assert_eq!(Hms::new(false, 5, 30, 4.25).to_string(), "05h30m04.25s");
+    }
+
+    #[test]
+    fn display_precision_rounds_with_carry() {
+        let dms = Dms::new(false, 12, 59, 59.9999);
+        assert_eq!(format!("{dms:.2}"), "13°00′00.00″");
+        assert_eq!(format!("{dms:.0}"), "13°00′00″");
+        assert_eq!(format!("{:.1}", Dms::new(false, 1, 2, 3.04)), "01°02′03.0″");
+        assert_eq!(
+            format!("{:#.3}", Hms::new(true, 1, 2, 3.0)),
+            "-01:02:03.000"
+        );
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Parsing
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn parses_common_dms_notations() {
+        let expected = Dms::new(false, 12, 34, 56.7);
+        for s in [
+            "+12°34′56.7″",
+            "12°34'56.7\"",
+            "12d34m56.7s",
+            "12:34:56.7",
+            "12 34 56.7",
+            " 12° 34′ 56.7″ ",
+        ] {
+            assert_eq!(s.parse::<Dms>(), Ok(expected), "{s}");
+        }
+        assert_eq!("-00°30′".parse(), Ok(Dms::new(true, 0, 30, 0.0)));
+        assert_eq!("−00:30".parse(), Ok(Dms::new(true, 0, 30, 0.0)));
+        assert_eq!("12°56″".parse(), Ok(Dms::new(false, 12, 0, 56.0)));
+        assert_eq!("12.5°".parse(), Ok(Dms::new(false, 12, 30, 0.0)));
+        assert_eq!("12°30.5′".parse(), Ok(Dms::new(false, 12, 30, 30.0)));
+    }
+
+    #[test]
+    fn parses_common_hms_notations() {
+        let expected = Hms::new(false, 5, 30, 0.0);
+        for s in ["05h30m00s", "5ʰ30ᵐ00ˢ", "05:30:00", "05 30 00", "5h30m"] {
+            assert_eq!(s.parse::<Hms>(), Ok(expected), "{s}");
+        }
+        let hms: Hms = "05h34m31.94s".parse().unwrap();
+        assert_abs_diff_eq!(hms.to_degrees().value(), 83.633_083, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        for dms in [Dms::new(true, 0, 30, 0.0), Dms::new(false, 123, 4, 5.678)] {
+            assert_eq!(dms.to_string().parse(), Ok(dms));
+            assert_eq!(format!("{dms:#}").parse(), Ok(dms));
+        }
+        let hms = Hms::new(false, 23, 59, 59.25);
+        assert_eq!(hms.to_string().parse(), Ok(hms));
+    }
+
+    #[test]
+    fn parse_errors_report_position() {
+        use ParseSexagesimalError as E;
+        assert_eq!("".parse::<Dms>(), Err(E::Empty));
+        assert_eq!("  ".parse::<Dms>(), Err(E::Empty));
+        assert_eq!("-".parse::<Dms>(), Err(E::UnexpectedChar { position: 1 }));
+        assert_eq!("12:".parse::<Dms>(), Err(E::UnexpectedChar { position: 3 }));
+        assert_eq!(
+            "12::3".parse::<Dms>(),
+            Err(E::InvalidNumber { position: 3 })
+        );
+        assert_eq!("12:60".parse::<Dms>(), Err(E::OutOfRange { position: 3 }));
+        assert_eq!(
+            "12.5:30".parse::<Dms>(),
+            Err(E::UnexpectedChar { position: 5 })
+        );
+        assert_eq!(
+            "12:30:10:5".parse::<Dms>(),
+            Err(E::UnexpectedChar { position: 9 })
+        );
+        assert_eq!(
+            "30′12°".parse::<Dms>(),
+            Err(E::UnexpectedChar { position: 7 })
+        );
+        assert_eq!("12x".parse::<Dms>(), Err(E::UnexpectedChar { position: 2 }));
+        assert_eq!(
+            "05h30°".parse::<Hms>(),
+            Err(E::UnexpectedChar { position: 5 })
+        );
+        assert_eq!(
+            E::OutOfRange { position: 3 }.to_string(),
+            "component out of range at byte 3"
+        );
+    }
+}
@@ -6,28 +6,43 @@ use qtty_derive::Unit;
 
 /// Square inch (`6.4516e-4 m²`, exact: `0.0254² m²`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "in²", dimension = Area, ratio = 6.4516e-4)]
+#[unit(
+    symbol = "in²", dimension = Area, ratio = 6.4516e-4, exact_ratio = 64_516 / 100_000_000,
+    system = "customary",
+)]
 pub struct SquareInch;
 /// A quantity measured in square inches.
 pub type SquareInches = Quantity<SquareInch>;
 
 /// Square foot (`0.09290304 m²`, exact: `0.3048² m²`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ft²", dimension = Area, ratio = 0.09290304)]
+#[unit(
+    symbol = "ft²", dimension = Area, ratio = 0.09290304, exact_ratio = 9_290_304 / 100_000_000,
+    plural = "square feet", system = "customary",
+)]
 pub struct SquareFoot;
 /// A quantity measured in square feet.
 pub type SquareFeet = Quantity<SquareFoot>;
 
 /// Square yard (`0.83612736 m²`, exact: `0.9144² m²`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "yd²", dimension = Area, ratio = 0.83612736)]
+#[unit(
+    symbol = "yd²", dimension = Area, ratio = 0.83612736, exact_ratio = 83_612_736 / 100_000_000,
+    system = "customary",
+)]
 pub struct SquareYard;
 /// A quantity measured in square yards.
 pub type SquareYards = Quantity<SquareYard>;
 
 /// Square mile (`2_589_988.110336 m²`, exact: `1609.344² m²`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mi²", dimension = Area, ratio = 2_589_988.110_336)]
+#[unit(
+    symbol = "mi²",
+    dimension = Area,
+    ratio = 2_589_988.110_336,
+    exact_ratio = 2_589_988_110_336 / 1_000_000,
+    system = "customary",
+)]
 pub struct SquareMile;
 /// A quantity measured in square miles.
 pub type SquareMiles = Quantity<SquareMile>;
@@ -10,21 +10,27 @@ use qtty_derive::Unit;
 
 /// Hectare (`10 000 m²`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ha", dimension = Area, ratio = 1e4)]
+#[unit(symbol = "ha", dimension = Area, ratio = 1e4, exact_ratio = 10_000, system = "SI-accepted")]
 pub struct Hectare;
 /// A quantity measured in hectares.
 pub type Hectares = Quantity<Hectare>;
 
 /// Are (`100 m²`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "a", dimension = Area, ratio = 100.0)]
+#[unit(symbol = "a", dimension = Area, ratio = 100.0, exact_ratio = 100, system = "other")]
 pub struct Are;
 /// A quantity measured in ares.
 pub type Ares = Quantity<Are>;
 
 /// Acre (exactly `4046.8564224 m²`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ac", dimension = Area, ratio = 4_046.856_422_4)]
+#[unit(
+    symbol = "ac",
+    dimension = Area,
+    ratio = 4_046.856_422_4,
+    exact_ratio = 40_468_564_224 / 10_000_000,
+    system = "customary",
+)]
 pub struct Acre;
 /// A quantity measured in acres.
 pub type Acres = Quantity<Acre>;
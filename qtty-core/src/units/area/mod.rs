@@ -291,18 +291,18 @@ mod tests {
     #[test]
     #[cfg(feature = "std")]
     fn squared_unit_formatter_output() {
-        // Display: "value sym·sym"
-        assert_eq!(format!("{}", SquareMeters::new(2.5)), "2.5 m·m");
+        // Display: repeated factors collapse into an exponent.
+        assert_eq!(format!("{}", SquareMeters::new(2.5)), "2.5 m²");
         // LowerExp uses scientific notation on the value, same symbol layout.
-        assert_eq!(format!("{:e}", SquareMeters::new(1234.0)), "1.234e3 m·m");
+        assert_eq!(format!("{:e}", SquareMeters::new(1234.0)), "1.234e3 m²");
     }
 
     #[test]
     #[cfg(feature = "std")]
     fn symbols_are_correct() {
-        // Prod-based aliases inherit the Prod Display ("m·m");
-        // SYMBOL is empty but Display writes component symbols.
-        assert_eq!(format!("{}", SquareMeters::new(1.0)), "1 m·m");
+        // Prod-based aliases inherit the Prod Display ("m²");
+        // SYMBOL is empty but Display renders the component symbols.
+        assert_eq!(format!("{}", SquareMeters::new(1.0)), "1 m²");
         #[cfg(feature = "land-area")]
         assert_eq!(Hectare::SYMBOL, "ha");
         #[cfg(feature = "land-area")]
@@ -31,7 +31,13 @@ impl<T: Unit<Dim = Density>> DensityUnit for T {}
 
 /// Kilogram per cubic metre — SI coherent derived unit of mass density (kg·m⁻³).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "kg/m³", dimension = Density, ratio = 1.0)]
+#[unit(
+    symbol = "kg/m³", dimension = Density, ratio = 1.0, exact_ratio = 1,
+    long_name = "kilogram per cubic metre",
+    plural = "kilograms per cubic metre",
+    system = "SI",
+    aliases = ["kilogram per cubic meter", "kilograms per cubic meter"],
+)]
 pub struct KilogramPerCubicMeter;
 /// Type alias shorthand for [`KilogramPerCubicMeter`].
 pub type KgM3 = KilogramPerCubicMeter;
@@ -42,7 +48,13 @@ pub const KILOGRAM_PER_CUBIC_METER: KilogramsPerCubicMeter = KilogramsPerCubicMe
 
 /// Gram per cubic centimetre — 1 g/cm³ = 1 000 kg/m³ (exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "g/cm³", dimension = Density, ratio = 1_000.0)]
+#[unit(
+    symbol = "g/cm³", dimension = Density, ratio = 1_000.0, exact_ratio = 1_000,
+    long_name = "gram per cubic centimetre",
+    plural = "grams per cubic centimetre",
+    system = "CGS",
+    aliases = ["gram per cubic centimeter", "grams per cubic centimeter"],
+)]
 pub struct GramPerCubicCentimeter;
 /// Type alias shorthand for [`GramPerCubicCentimeter`].
 pub type GCm3 = GramPerCubicCentimeter;
@@ -53,7 +65,13 @@ pub const GRAM_PER_CUBIC_CENTIMETER: GramsPerCubicCentimeter = GramsPerCubicCent
 
 /// Gram per millilitre — numerically identical to g/cm³ (1 g/mL = 1 000 kg/m³).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "g/mL", dimension = Density, ratio = 1_000.0)]
+#[unit(
+    symbol = "g/mL", dimension = Density, ratio = 1_000.0, exact_ratio = 1_000,
+    long_name = "gram per millilitre",
+    plural = "grams per millilitre",
+    system = "SI-accepted",
+    aliases = ["gram per milliliter", "grams per milliliter"],
+)]
 pub struct GramPerMilliliter;
 /// Type alias shorthand for [`GramPerMilliliter`].
 pub type GmL = GramPerMilliliter;
@@ -69,7 +87,10 @@ pub const GRAM_PER_MILLILITER: GramsPerMilliliter = GramsPerMilliliter::new(1.0)
 /// Pound per cubic foot — 1 lb/ft³ ≈ 16.018 463 373 kg/m³.
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "lb/ft³", dimension = Density, ratio = 16.018_463_373)]
+#[unit(
+    symbol = "lb/ft³", dimension = Density, ratio = 16.018_463_373,
+    plural = "pounds per cubic foot", system = "customary",
+)]
 pub struct PoundPerCubicFoot;
 /// Type alias shorthand for [`PoundPerCubicFoot`].
 #[cfg(feature = "customary")]
@@ -38,7 +38,10 @@ pub use crate::dimension::{
 
 /// Ampere — SI base unit of electric current (A).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "A", dimension = Current, ratio = 1.0)]
+#[unit(
+    symbol = "A", dimension = Current, ratio = 1.0, exact_ratio = 1,
+    system = "SI", aliases = ["amp", "amps"],
+)]
 pub struct Ampere;
 /// Type alias shorthand for [`Ampere`].
 pub type Amp = Ampere;
@@ -49,7 +52,10 @@ pub const AMPERE: Amperes = Amperes::new(1.0);
 
 /// Microampere — 1 µA = 10⁻⁶ A.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "µA", dimension = Current, ratio = 1e-6)]
+#[unit(
+    symbol = "µA", dimension = Current, ratio = 1e-6, exact_ratio = 1 / 1_000_000,
+    system = "SI",
+)]
 pub struct Microampere;
 /// Type alias shorthand for [`Microampere`].
 pub type UAmps = Microampere;
@@ -60,7 +66,7 @@ pub const MICROAMPERE: Microamperes = Microamperes::new(1.0);
 
 /// Milliampere — 1 mA = 10⁻³ A.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mA", dimension = Current, ratio = 1e-3)]
+#[unit(symbol = "mA", dimension = Current, ratio = 1e-3, exact_ratio = 1 / 1_000, system = "SI")]
 pub struct Milliampere;
 /// Type alias shorthand for [`Milliampere`].
 pub type MAmps = Milliampere;
@@ -71,7 +77,7 @@ pub const MILLIAMPERE: Milliamperes = Milliamperes::new(1.0);
 
 /// Kiloampere — 1 kA = 10³ A.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "kA", dimension = Current, ratio = 1e3)]
+#[unit(symbol = "kA", dimension = Current, ratio = 1e3, exact_ratio = 1_000, system = "SI")]
 pub struct Kiloampere;
 /// Type alias shorthand for [`Kiloampere`].
 pub type KAmps = Kiloampere;
@@ -103,7 +109,7 @@ ampere_units!(crate::assert_units_are_builtin);
 
 /// Coulomb — SI coherent derived unit of electric charge (A·s).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "C", dimension = Charge, ratio = 1.0)]
+#[unit(symbol = "C", dimension = Charge, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Coulomb;
 /// Type alias shorthand for [`Coulomb`].
 pub type Coul = Coulomb;
@@ -114,7 +120,7 @@ pub const COULOMB: Coulombs = Coulombs::new(1.0);
 
 /// Millicoulomb — 1 mC = 10⁻³ C.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mC", dimension = Charge, ratio = 1e-3)]
+#[unit(symbol = "mC", dimension = Charge, ratio = 1e-3, exact_ratio = 1 / 1_000, system = "SI")]
 pub struct Millicoulomb;
 /// A quantity measured in millicoulombs.
 pub type Millicoulombs = Quantity<Millicoulomb>;
@@ -123,7 +129,7 @@ pub const MILLICOULOMB: Millicoulombs = Millicoulombs::new(1.0);
 
 /// Microcoulomb — 1 µC = 10⁻⁶ C.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "µC", dimension = Charge, ratio = 1e-6)]
+#[unit(symbol = "µC", dimension = Charge, ratio = 1e-6, exact_ratio = 1 / 1_000_000, system = "SI")]
 pub struct Microcoulomb;
 /// A quantity measured in microcoulombs.
 pub type Microcoulombs = Quantity<Microcoulomb>;
@@ -132,7 +138,7 @@ pub const MICROCOULOMB: Microcoulombs = Microcoulombs::new(1.0);
 
 /// Kilocoulomb — 1 kC = 10³ C.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "kC", dimension = Charge, ratio = 1e3)]
+#[unit(symbol = "kC", dimension = Charge, ratio = 1e3, exact_ratio = 1_000, system = "SI")]
 pub struct Kilocoulomb;
 /// A quantity measured in kilocoulombs.
 pub type Kilocoulombs = Quantity<Kilocoulomb>;
@@ -162,7 +168,7 @@ coulomb_units!(crate::assert_units_are_builtin);
 
 /// Volt — SI coherent derived unit of voltage (kg·m²·s⁻³·A⁻¹).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "V", dimension = Voltage, ratio = 1.0)]
+#[unit(symbol = "V", dimension = Voltage, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Volt;
 /// A quantity measured in volts.
 pub type Volts = Quantity<Volt>;
@@ -171,7 +177,10 @@ pub const VOLT: Volts = Volts::new(1.0);
 
 /// Microvolt — 1 µV = 10⁻⁶ V.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "µV", dimension = Voltage, ratio = 1e-6)]
+#[unit(
+    symbol = "µV", dimension = Voltage, ratio = 1e-6, exact_ratio = 1 / 1_000_000,
+    system = "SI",
+)]
 pub struct Microvolt;
 /// A quantity measured in microvolts.
 pub type Microvolts = Quantity<Microvolt>;
@@ -180,7 +189,7 @@ pub const MICROVOLT: Microvolts = Microvolts::new(1.0);
 
 /// Millivolt — 1 mV = 10⁻³ V.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mV", dimension = Voltage, ratio = 1e-3)]
+#[unit(symbol = "mV", dimension = Voltage, ratio = 1e-3, exact_ratio = 1 / 1_000, system = "SI")]
 pub struct Millivolt;
 /// A quantity measured in millivolts.
 pub type Millivolts = Quantity<Millivolt>;
@@ -189,7 +198,7 @@ pub const MILLIVOLT: Millivolts = Millivolts::new(1.0);
 
 /// Kilovolt — 1 kV = 10³ V.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "kV", dimension = Voltage, ratio = 1e3)]
+#[unit(symbol = "kV", dimension = Voltage, ratio = 1e3, exact_ratio = 1_000, system = "SI")]
 pub struct Kilovolt;
 /// A quantity measured in kilovolts.
 pub type Kilovolts = Quantity<Kilovolt>;
@@ -198,7 +207,7 @@ pub const KILOVOLT: Kilovolts = Kilovolts::new(1.0);
 
 /// Megavolt — 1 MV = 10⁶ V.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "MV", dimension = Voltage, ratio = 1e6)]
+#[unit(symbol = "MV", dimension = Voltage, ratio = 1e6, exact_ratio = 1_000_000, system = "SI")]
 pub struct Megavolt;
 /// A quantity measured in megavolts.
 pub type Megavolts = Quantity<Megavolt>;
@@ -228,7 +237,7 @@ volt_units!(crate::assert_units_are_builtin);
 
 /// Ohm — SI coherent derived unit of electrical resistance (V/A).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Ω", dimension = Resistance, ratio = 1.0)]
+#[unit(symbol = "Ω", dimension = Resistance, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Ohm;
 /// A quantity measured in ohms.
 pub type Ohms = Quantity<Ohm>;
@@ -237,7 +246,7 @@ pub const OHM: Ohms = Ohms::new(1.0);
 
 /// Milliohm — 1 mΩ = 10⁻³ Ω.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mΩ", dimension = Resistance, ratio = 1e-3)]
+#[unit(symbol = "mΩ", dimension = Resistance, ratio = 1e-3, exact_ratio = 1 / 1_000, system = "SI")]
 pub struct Milliohm;
 /// A quantity measured in milliohms.
 pub type Milliohms = Quantity<Milliohm>;
@@ -246,7 +255,7 @@ pub const MILLIOHM: Milliohms = Milliohms::new(1.0);
 
 /// Kilohm — 1 kΩ = 10³ Ω.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "kΩ", dimension = Resistance, ratio = 1e3)]
+#[unit(symbol = "kΩ", dimension = Resistance, ratio = 1e3, exact_ratio = 1_000, system = "SI")]
 pub struct Kilohm;
 /// A quantity measured in kilohms.
 pub type Kilohms = Quantity<Kilohm>;
@@ -255,7 +264,10 @@ pub const KILOHM: Kilohms = Kilohms::new(1.0);
 
 /// Megaohm — 1 MΩ = 10⁶ Ω.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "MΩ", dimension = Resistance, ratio = 1e6)]
+#[unit(
+    symbol = "MΩ", dimension = Resistance, ratio = 1e6, exact_ratio = 1_000_000,
+    system = "SI", aliases = ["megohm", "megohms"],
+)]
 pub struct Megaohm;
 /// A quantity measured in megaohms.
 pub type Megaohms = Quantity<Megaohm>;
@@ -285,7 +297,7 @@ ohm_units!(crate::assert_units_are_builtin);
 
 /// Farad — SI coherent derived unit of electrical capacitance (C/V).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "F", dimension = Capacitance, ratio = 1.0)]
+#[unit(symbol = "F", dimension = Capacitance, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Farad;
 /// A quantity measured in farads.
 pub type Farads = Quantity<Farad>;
@@ -294,7 +306,10 @@ pub const FARAD: Farads = Farads::new(1.0);
 
 /// Picofarad — 1 pF = 10⁻¹² F.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "pF", dimension = Capacitance, ratio = 1e-12)]
+#[unit(
+    symbol = "pF", dimension = Capacitance, ratio = 1e-12, exact_ratio = 1 / 10_i128.pow(12),
+    system = "SI",
+)]
 pub struct Picofarad;
 /// A quantity measured in picofarads.
 pub type Picofarads = Quantity<Picofarad>;
@@ -303,7 +318,10 @@ pub const PICOFARAD: Picofarads = Picofarads::new(1.0);
 
 /// Nanofarad — 1 nF = 10⁻⁹ F.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "nF", dimension = Capacitance, ratio = 1e-9)]
+#[unit(
+    symbol = "nF", dimension = Capacitance, ratio = 1e-9, exact_ratio = 1 / 1_000_000_000,
+    system = "SI",
+)]
 pub struct Nanofarad;
 /// A quantity measured in nanofarads.
 pub type Nanofarads = Quantity<Nanofarad>;
@@ -312,7 +330,10 @@ pub const NANOFARAD: Nanofarads = Nanofarads::new(1.0);
 
 /// Microfarad — 1 µF = 10⁻⁶ F.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "µF", dimension = Capacitance, ratio = 1e-6)]
+#[unit(
+    symbol = "µF", dimension = Capacitance, ratio = 1e-6, exact_ratio = 1 / 1_000_000,
+    system = "SI",
+)]
 pub struct Microfarad;
 /// A quantity measured in microfarads.
 pub type Microfarads = Quantity<Microfarad>;
@@ -321,7 +342,10 @@ pub const MICROFARAD: Microfarads = Microfarads::new(1.0);
 
 /// Millifarad — 1 mF = 10⁻³ F.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mF", dimension = Capacitance, ratio = 1e-3)]
+#[unit(
+    symbol = "mF", dimension = Capacitance, ratio = 1e-3, exact_ratio = 1 / 1_000,
+    system = "SI",
+)]
 pub struct Millifarad;
 /// A quantity measured in millifarads.
 pub type Millifarads = Quantity<Millifarad>;
@@ -351,7 +375,7 @@ farad_units!(crate::assert_units_are_builtin);
 
 /// Henry — SI coherent derived unit of electrical inductance (V·s/A).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "H", dimension = Inductance, ratio = 1.0)]
+#[unit(symbol = "H", dimension = Inductance, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Henry;
 /// A quantity measured in henries.
 pub type Henries = Quantity<Henry>;
@@ -360,7 +384,10 @@ pub const HENRY: Henries = Henries::new(1.0);
 
 /// Microhenry — 1 µH = 10⁻⁶ H.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "µH", dimension = Inductance, ratio = 1e-6)]
+#[unit(
+    symbol = "µH", dimension = Inductance, ratio = 1e-6, exact_ratio = 1 / 1_000_000,
+    system = "SI",
+)]
 pub struct Microhenry;
 /// A quantity measured in microhenries.
 pub type Microhenries = Quantity<Microhenry>;
@@ -369,7 +396,7 @@ pub const MICROHENRY: Microhenries = Microhenries::new(1.0);
 
 /// Millihenry — 1 mH = 10⁻³ H.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mH", dimension = Inductance, ratio = 1e-3)]
+#[unit(symbol = "mH", dimension = Inductance, ratio = 1e-3, exact_ratio = 1 / 1_000, system = "SI")]
 pub struct Millihenry;
 /// A quantity measured in millihenries.
 pub type Millihenries = Quantity<Millihenry>;
@@ -399,7 +426,7 @@ henry_units!(crate::assert_units_are_builtin);
 
 /// Weber — SI coherent derived unit of magnetic flux (V·s).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Wb", dimension = MagneticFlux, ratio = 1.0)]
+#[unit(symbol = "Wb", dimension = MagneticFlux, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Weber;
 /// A quantity measured in webers.
 pub type Webers = Quantity<Weber>;
@@ -408,7 +435,10 @@ pub const WEBER: Webers = Webers::new(1.0);
 
 /// Milliweber — 1 mWb = 10⁻³ Wb.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mWb", dimension = MagneticFlux, ratio = 1e-3)]
+#[unit(
+    symbol = "mWb", dimension = MagneticFlux, ratio = 1e-3, exact_ratio = 1 / 1_000,
+    system = "SI",
+)]
 pub struct Milliweber;
 /// A quantity measured in milliwebers.
 pub type Milliwebers = Quantity<Milliweber>;
@@ -438,7 +468,7 @@ weber_units!(crate::assert_units_are_builtin);
 
 /// Tesla — SI coherent derived unit of magnetic flux density (Wb/m²).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "T", dimension = MagneticFluxDensity, ratio = 1.0)]
+#[unit(symbol = "T", dimension = MagneticFluxDensity, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Tesla;
 /// A quantity measured in teslas.
 pub type Teslas = Quantity<Tesla>;
@@ -447,7 +477,10 @@ pub const TESLA: Teslas = Teslas::new(1.0);
 
 /// Millitesla — 1 mT = 10⁻³ T.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mT", dimension = MagneticFluxDensity, ratio = 1e-3)]
+#[unit(
+    symbol = "mT", dimension = MagneticFluxDensity, ratio = 1e-3, exact_ratio = 1 / 1_000,
+    system = "SI",
+)]
 pub struct Millitesla;
 /// A quantity measured in milliteslas.
 pub type Milliteslas = Quantity<Millitesla>;
@@ -456,7 +489,10 @@ pub const MILLITESLA: Milliteslas = Milliteslas::new(1.0);
 
 /// Microtesla — 1 µT = 10⁻⁶ T.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "µT", dimension = MagneticFluxDensity, ratio = 1e-6)]
+#[unit(
+    symbol = "µT", dimension = MagneticFluxDensity, ratio = 1e-6, exact_ratio = 1 / 1_000_000,
+    system = "SI",
+)]
 pub struct Microtesla;
 /// A quantity measured in microteslas.
 pub type Microteslas = Quantity<Microtesla>;
@@ -37,7 +37,7 @@ impl<T: Unit<Dim = Energy>> EnergyUnit for T {}
 
 /// Joule — SI coherent derived unit of energy (kg·m²/s²).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "J", dimension = Energy, ratio = 1.0)]
+#[unit(symbol = "J", dimension = Energy, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Joule;
 /// A quantity measured in joules.
 pub type Joules = Quantity<Joule>;
@@ -45,10 +45,13 @@ pub type Joules = Quantity<Joule>;
 pub const JOULE: Joules = Joules::new(1.0);
 
 macro_rules! si_joule {
-    ($name:ident, $sym:literal, $ratio:expr, $qty:ident, $one:ident) => {
+    ($name:ident, $sym:literal, $ratio:expr, $exact:expr, $qty:ident, $one:ident) => {
         #[doc = concat!("SI-prefixed joule unit (", stringify!($ratio), " J).")]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-        #[unit(symbol = $sym, dimension = Energy, ratio = $ratio)]
+        #[unit(
+                            symbol = $sym, dimension = Energy, ratio = $ratio, exact_ratio = $exact,
+                            system = "SI",
+                        )]
         pub struct $name;
         #[doc = concat!("A quantity measured in ", stringify!($name), "s.")]
         pub type $qty = Quantity<$name>;
@@ -57,18 +60,49 @@ macro_rules! si_joule {
     };
 }
 
-si_joule!(Nanojoule, "nJ", 1e-9, Nanojoules, NANOJOULE);
-si_joule!(Picojoule, "pJ", 1e-12, Picojoules, PICOJOULE);
-si_joule!(Microjoule, "µJ", 1e-6, Microjoules, MICROJOULE);
-si_joule!(Millijoule, "mJ", 1e-3, Millijoules, MILLIJOULE);
-si_joule!(Kilojoule, "kJ", 1e3, Kilojoules, KILOJOULE);
-si_joule!(Megajoule, "MJ", 1e6, Megajoules, MEGAJOULE);
-si_joule!(Gigajoule, "GJ", 1e9, Gigajoules, GIGAJOULE);
-si_joule!(Terajoule, "TJ", 1e12, Terajoules, TERAJOULE);
+si_joule!(
+    Nanojoule,
+    "nJ",
+    1e-9,
+    1 / 1_000_000_000,
+    Nanojoules,
+    NANOJOULE
+);
+si_joule!(
+    Picojoule,
+    "pJ",
+    1e-12,
+    1 / 10_i128.pow(12),
+    Picojoules,
+    PICOJOULE
+);
+si_joule!(
+    Microjoule,
+    "µJ",
+    1e-6,
+    1 / 1_000_000,
+    Microjoules,
+    MICROJOULE
+);
+si_joule!(Millijoule, "mJ", 1e-3, 1 / 1_000, Millijoules, MILLIJOULE);
+si_joule!(Kilojoule, "kJ", 1e3, 1_000, Kilojoules, KILOJOULE);
+si_joule!(Megajoule, "MJ", 1e6, 1_000_000, Megajoules, MEGAJOULE);
+si_joule!(Gigajoule, "GJ", 1e9, 1_000_000_000, Gigajoules, GIGAJOULE);
+si_joule!(
+    Terajoule,
+    "TJ",
+    1e12,
+    10_i128.pow(12),
+    Terajoules,
+    TERAJOULE
+);
 
 /// Watt-hour — 1 Wh = 3 600 J (exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Wh", dimension = Energy, ratio = 3_600.0)]
+#[unit(
+    symbol = "Wh", dimension = Energy, ratio = 3_600.0, exact_ratio = 3_600,
+    long_name = "watt-hour", system = "other", aliases = ["watt hour", "watt hours"],
+)]
 pub struct WattHour;
 /// A quantity measured in watt-hours.
 pub type WattHours = Quantity<WattHour>;
@@ -77,7 +111,10 @@ pub const WATT_HOUR: WattHours = WattHours::new(1.0);
 
 /// Kilowatt-hour — 1 kWh = 3 600 000 J (exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "kWh", dimension = Energy, ratio = 3_600_000.0)]
+#[unit(
+    symbol = "kWh", dimension = Energy, ratio = 3_600_000.0, exact_ratio = 3_600_000,
+    long_name = "kilowatt-hour", system = "other", aliases = ["kilowatt hour", "kilowatt hours"],
+)]
 pub struct KilowattHour;
 /// A quantity measured in kilowatt-hours.
 pub type KilowattHours = Quantity<KilowattHour>;
@@ -91,7 +128,10 @@ pub const KILOWATT_HOUR: KilowattHours = KilowattHours::new(1.0);
 /// Erg — CGS unit of energy (1 erg = 10⁻⁷ J).
 #[cfg(feature = "fundamental-physics")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "erg", dimension = Energy, ratio = 1e-7)]
+#[unit(
+    symbol = "erg", dimension = Energy, ratio = 1e-7, exact_ratio = 1 / 10_000_000,
+    system = "CGS",
+)]
 pub struct Erg;
 /// A quantity measured in ergs.
 #[cfg(feature = "fundamental-physics")]
@@ -100,7 +140,13 @@ pub type Ergs = Quantity<Erg>;
 /// Electronvolt — 1 eV = 1.602 176 634 × 10⁻¹⁹ J (exact, 2019 SI redefinition).
 #[cfg(feature = "fundamental-physics")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "eV", dimension = Energy, ratio = 1.602_176_634e-19)]
+#[unit(
+    symbol = "eV",
+    dimension = Energy,
+    ratio = 1.602_176_634e-19,
+    exact_ratio = 1_602_176_634 / 10_i128.pow(28),
+    system = "SI-accepted", aliases = ["electron volt", "electron volts"],
+)]
 pub struct Electronvolt;
 /// A quantity measured in electronvolts.
 #[cfg(feature = "fundamental-physics")]
@@ -109,7 +155,13 @@ pub type Electronvolts = Quantity<Electronvolt>;
 /// Kilo-electronvolt (1 keV = 10³ eV).
 #[cfg(feature = "fundamental-physics")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "keV", dimension = Energy, ratio = 1.602_176_634e-16)]
+#[unit(
+    symbol = "keV",
+    dimension = Energy,
+    ratio = 1.602_176_634e-16,
+    exact_ratio = 1_602_176_634 / 10_i128.pow(25),
+    system = "SI-accepted",
+)]
 pub struct Kiloelectronvolt;
 /// A quantity measured in kilo-electronvolts.
 #[cfg(feature = "fundamental-physics")]
@@ -118,7 +170,13 @@ pub type Kiloelectronvolts = Quantity<Kiloelectronvolt>;
 /// Mega-electronvolt (1 MeV = 10⁶ eV).
 #[cfg(feature = "fundamental-physics")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "MeV", dimension = Energy, ratio = 1.602_176_634e-13)]
+#[unit(
+    symbol = "MeV",
+    dimension = Energy,
+    ratio = 1.602_176_634e-13,
+    exact_ratio = 1_602_176_634 / 10_i128.pow(22),
+    system = "SI-accepted",
+)]
 pub struct Megaelectronvolt;
 /// A quantity measured in mega-electronvolts.
 #[cfg(feature = "fundamental-physics")]
@@ -127,7 +185,10 @@ pub type Megaelectronvolts = Quantity<Megaelectronvolt>;
 /// Thermochemical calorie (1 cal_th = 4.184 J, exact).
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "cal", dimension = Energy, ratio = 4.184)]
+#[unit(
+    symbol = "cal", dimension = Energy, ratio = 4.184, exact_ratio = 4_184 / 1_000,
+    system = "other",
+)]
 pub struct Calorie;
 /// A quantity measured in (thermochemical) calories.
 #[cfg(feature = "customary")]
@@ -136,7 +197,7 @@ pub type Calories = Quantity<Calorie>;
 /// Kilocalorie (1 kcal = 4184 J, exact).
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "kcal", dimension = Energy, ratio = 4184.0)]
+#[unit(symbol = "kcal", dimension = Energy, ratio = 4184.0, exact_ratio = 4_184, system = "other")]
 pub struct Kilocalorie;
 /// A quantity measured in kilocalories.
 #[cfg(feature = "customary")]
@@ -145,7 +206,13 @@ pub type Kilocalories = Quantity<Kilocalorie>;
 /// British Thermal Unit — 1 BTU ≈ 1 055.05585262 J (ISO 31-4).
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "BTU", dimension = Energy, ratio = 1_055.05585262)]
+#[unit(
+    symbol = "BTU",
+    dimension = Energy,
+    ratio = 1_055.05585262,
+    exact_ratio = 105_505_585_262 / 100_000_000,
+    long_name = "British thermal unit", system = "customary",
+)]
 pub struct BritishThermalUnit;
 /// A quantity measured in British thermal units.
 #[cfg(feature = "customary")]
@@ -154,7 +221,13 @@ pub type BritishThermalUnits = Quantity<BritishThermalUnit>;
 /// Therm — 1 therm = 100 000 BTU = 105 505 585.262 J.
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "therm", dimension = Energy, ratio = 105_505_585.262)]
+#[unit(
+    symbol = "therm",
+    dimension = Energy,
+    ratio = 105_505_585.262,
+    exact_ratio = 105_505_585_262 / 1_000,
+    system = "customary",
+)]
 pub struct Therm;
 /// A quantity measured in therms.
 #[cfg(feature = "customary")]
@@ -36,7 +36,7 @@ impl<T: Unit<Dim = Force>> ForceUnit for T {}
 
 /// Newton — SI coherent derived unit of force (kg·m/s²).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "N", dimension = Force, ratio = 1.0)]
+#[unit(symbol = "N", dimension = Force, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Newton;
 /// Type alias shorthand for [`Newton`].
 pub type N = Newton;
@@ -46,10 +46,13 @@ pub type Newtons = Quantity<N>;
 pub const NEWTON: Newtons = Newtons::new(1.0);
 
 macro_rules! si_newton {
-    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident) => {
+    ($name:ident, $sym:literal, $ratio:expr, $exact:expr, $alias:ident, $qty:ident, $one:ident) => {
         #[doc = concat!("SI-prefixed newton unit (", stringify!($ratio), " N).")]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-        #[unit(symbol = $sym, dimension = Force, ratio = $ratio)]
+        #[unit(
+                            symbol = $sym, dimension = Force, ratio = $ratio, exact_ratio = $exact,
+                            system = "SI",
+                        )]
         pub struct $name;
         #[doc = concat!("Type alias shorthand for [`", stringify!($name), "`].")]
         pub type $alias = $name;
@@ -60,11 +63,43 @@ macro_rules! si_newton {
     };
 }
 
-si_newton!(Micronewton, "µN", 1e-6, Un, Micronewtons, MICRONEWTON);
-si_newton!(Millinewton, "mN", 1e-3, Mn, Millinewtons, MILLINEWTON);
-si_newton!(Kilonewton, "kN", 1e3, Kn, Kilonewtons, KILONEWTON);
-si_newton!(Meganewton, "MN", 1e6, MN, Meganewtons, MEGANEWTON);
-si_newton!(Giganewton, "GN", 1e9, GN, Giganewtons, GIGANEWTON);
+si_newton!(
+    Micronewton,
+    "µN",
+    1e-6,
+    1 / 1_000_000,
+    Un,
+    Micronewtons,
+    MICRONEWTON
+);
+si_newton!(
+    Millinewton,
+    "mN",
+    1e-3,
+    1 / 1_000,
+    Mn,
+    Millinewtons,
+    MILLINEWTON
+);
+si_newton!(Kilonewton, "kN", 1e3, 1_000, Kn, Kilonewtons, KILONEWTON);
+si_newton!(
+    Meganewton,
+    "MN",
+    1e6,
+    1_000_000,
+    MN,
+    Meganewtons,
+    MEGANEWTON
+);
+si_newton!(
+    Giganewton,
+    "GN",
+    1e9,
+    1_000_000_000,
+    GN,
+    Giganewtons,
+    GIGANEWTON
+);
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Feature-gated units
@@ -73,7 +108,7 @@ si_newton!(Giganewton, "GN", 1e9, GN, Giganewtons, GIGANEWTON);
 /// Dyne — CGS unit of force (1 dyn = 10⁻⁵ N).
 #[cfg(feature = "fundamental-physics")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "dyn", dimension = Force, ratio = 1e-5)]
+#[unit(symbol = "dyn", dimension = Force, ratio = 1e-5, exact_ratio = 1 / 100_000, system = "CGS")]
 pub struct Dyne;
 /// A quantity measured in dynes.
 #[cfg(feature = "fundamental-physics")]
@@ -85,7 +120,16 @@ pub type Dynes = Quantity<Dyne>;
 /// `g₀ = 9.806 65 m/s²`. NIST SP 1247 conversion factor.
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "lbf", dimension = Force, ratio = 4.448_221_615_260_5)]
+#[unit(
+    symbol = "lbf",
+    dimension = Force,
+    ratio = 4.448_221_615_260_5,
+    exact_ratio = 44_482_216_152_605 / 10_i128.pow(13),
+    long_name = "pound-force",
+    plural = "pounds-force",
+    system = "customary",
+    aliases = ["pound force", "pounds force"],
+)]
 pub struct PoundForce;
 /// A quantity measured in pounds-force.
 #[cfg(feature = "customary")]
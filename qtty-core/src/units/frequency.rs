@@ -31,7 +31,10 @@ impl<T: Unit<Dim = Frequency>> FrequencyUnit for T {}
 
 /// Hertz — SI coherent derived unit of frequency (s⁻¹).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Hz", dimension = Frequency, ratio = 1.0)]
+#[unit(
+    symbol = "Hz", dimension = Frequency, ratio = 1.0, exact_ratio = 1,
+    plural = "hertz", system = "SI",
+)]
 pub struct Hertz;
 /// Type alias shorthand for [`Hertz`].
 pub type Hz = Hertz;
@@ -41,10 +44,13 @@ pub type Hertzs = Quantity<Hz>;
 pub const HERTZ: Hertzs = Hertzs::new(1.0);
 
 macro_rules! si_hertz {
-    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident) => {
+    ($name:ident, $sym:literal, $long:literal, $ratio:expr, $exact:expr, $alias:ident, $qty:ident, $one:ident) => {
         #[doc = concat!("SI-prefixed hertz unit (", stringify!($ratio), " Hz).")]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-        #[unit(symbol = $sym, dimension = Frequency, ratio = $ratio)]
+        #[unit(
+                    symbol = $sym, dimension = Frequency, ratio = $ratio, exact_ratio = $exact,
+                    long_name = $long, plural = $long, system = "SI",
+                )]
         pub struct $name;
         #[doc = concat!("Type alias shorthand for [`", stringify!($name), "`].")]
         pub type $alias = $name;
@@ -55,11 +61,56 @@ macro_rules! si_hertz {
     };
 }
 
-si_hertz!(Millihertz, "mHz", 1e-3, MHzm, Millihertzs, MILLIHERTZ);
-si_hertz!(Kilohertz, "kHz", 1e3, KHz, Kilohertzs, KILOHERTZ);
-si_hertz!(Megahertz, "MHz", 1e6, MHz, Megahertzs, MEGAHERTZ);
-si_hertz!(Gigahertz, "GHz", 1e9, GHz, Gigahertzs, GIGAHERTZ);
-si_hertz!(Terahertz, "THz", 1e12, THz, Terahertzs, TERAHERTZ);
+si_hertz!(
+    Millihertz,
+    "mHz",
+    "millihertz",
+    1e-3,
+    1 / 1_000,
+    MHzm,
+    Millihertzs,
+    MILLIHERTZ
+);
+si_hertz!(
+    Kilohertz,
+    "kHz",
+    "kilohertz",
+    1e3,
+    1_000,
+    KHz,
+    Kilohertzs,
+    KILOHERTZ
+);
+si_hertz!(
+    Megahertz,
+    "MHz",
+    "megahertz",
+    1e6,
+    1_000_000,
+    MHz,
+    Megahertzs,
+    MEGAHERTZ
+);
+si_hertz!(
+    Gigahertz,
+    "GHz",
+    "gigahertz",
+    1e9,
+    1_000_000_000,
+    GHz,
+    Gigahertzs,
+    GIGAHERTZ
+);
+si_hertz!(
+    Terahertz,
+    "THz",
+    "terahertz",
+    1e12,
+    10_i128.pow(12),
+    THz,
+    Terahertzs,
+    TERAHERTZ
+);
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Unit inventory macro
@@ -0,0 +1,223 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Runtime view of the built-in unit inventories.
+//!
+//! The per-module inventory macros (`length_units!`, `time_units!`, …) are the
+//! single source of truth for which unit markers exist. This module replays
+//! every enabled inventory into a static table of [`UnitInfo`] records, exposed
+//! publicly through [`catalog`](crate::catalog). String-based lookups (see the
+//! `parse` feature) and the SI-prefix auto-scaling formatter
+//! ([`EngineeringDisplay`](crate::EngineeringDisplay)) read the same table, so
+//! they stay in sync with the type-level catalog.
+//!
+//! Inventories are listed in a fixed order: always-available families first
+//! (length, time, mass, …), then feature-gated families. When two units share
+//! a symbol (e.g. `h` for both [`Hour`](crate::time::Hour) and the `astro`
+//! hour-angle), the first one listed wins.
+
+use crate::catalog::UnitInfo;
+
+use crate::units::acceleration::*;
+use crate::units::angular::*;
+use crate::units::area::*;
+use crate::units::energy::*;
+use crate::units::force::*;
+use crate::units::length::*;
+use crate::units::mass::*;
+use crate::units::power::*;
+use crate::units::pressure::*;
+use crate::units::solid_angle::*;
+use crate::units::temperature::*;
+use crate::units::time::*;
+use crate::units::volume::*;
+
+#[cfg(feature = "chemistry")]
+use crate::units::amount::*;
+#[cfg(feature = "density")]
+use crate::units::density::*;
+#[cfg(feature = "electrical")]
+use crate::units::electrical::*;
+#[cfg(feature = "frequency")]
+use crate::units::frequency::*;
+#[cfg(feature = "astro")]
+use crate::units::length::nominal::*;
+#[cfg(feature = "photometry")]
+use crate::units::photometry::*;
+#[cfg(feature = "radiometry")]
+use crate::units::radiometry::*;
+
+/// SI prefixes and their decimal exponents. `da` precedes `d` so the longer
+/// prefix is tried first.
+pub(crate) const SI_PREFIXES: &[(&str, i32)] = &[
+    ("Q", 30),
+    ("R", 27),
+    ("Y", 24),
+    ("Z", 21),
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("h", 2),
+    ("da", 1),
+    ("d", -1),
+    ("c", -2),
+    ("m", -3),
+    ("µ", -6),
+    ("μ", -6),
+    ("u", -6),
+    ("n", -9),
+    ("p", -12),
+    ("f", -15),
+    ("a", -18),
+    ("z", -21),
+    ("y", -24),
+    ("r", -27),
+    ("q", -30),
+];
+
+/// Expands to the `UNITS` table of one inventory family module.
+macro_rules! entries {
+    ($($unit:ident),+ $(,)?) => {
+        pub(super) const UNITS: &[UnitInfo] = &[
+            $(UnitInfo::of::<$unit>(stringify!($unit), FEATURE)),+
+        ];
+    };
+}
+
+/// Declares one private module per inventory macro, holding that family's
+/// `UNITS`, and collects them into [`FAMILIES`] in declaration order.
+macro_rules! families {
+    ($($(#[$cfg:meta])* $inventory:ident => $feature:expr;)+) => {
+        $(
+            $(#[$cfg])*
+            mod $inventory {
+                use super::*;
+
+                const FEATURE: Option<&str> = $feature;
+                crate::$inventory!(entries);
+            }
+        )+
+
+        /// Every enabled inventory family, in lookup order.
+        pub(crate) static FAMILIES: &[&[UnitInfo]] = &[$($(#[$cfg])* $inventory::UNITS),+];
+    };
+}
+
+families! {
+    length_units => None;
+    time_units => None;
+    mass_units => None;
+    temperature_units => None;
+    angular_units => None;
+    area_units => None;
+    volume_units => None;
+    solid_angle_units => None;
+    acceleration_units => None;
+    force_units => None;
+    energy_units => None;
+    power_units => None;
+    pressure_units => None;
+
+    #[cfg(feature = "astro")]
+    length_astro_units => Some("astro");
+    #[cfg(feature = "astro")]
+    length_nominal_units => Some("astro");
+    #[cfg(feature = "astro")]
+    time_astro_units => Some("astro");
+    #[cfg(feature = "astro")]
+    mass_astro_units => Some("astro");
+    #[cfg(feature = "astro")]
+    power_astro_units => Some("astro");
+    #[cfg(feature = "astro")]
+    angular_astro_units => Some("astro");
+    #[cfg(feature = "astro")]
+    solid_angle_astro_units => Some("astro");
+
+    #[cfg(feature = "navigation")]
+    length_navigation_units => Some("navigation");
+    #[cfg(feature = "navigation")]
+    angular_navigation_units => Some("navigation");
+
+    #[cfg(feature = "land-area")]
+    area_land_area_units => Some("land-area");
+
+    #[cfg(feature = "customary")]
+    length_customary_units => Some("customary");
+    #[cfg(feature = "customary")]
+    mass_customary_units => Some("customary");
+    #[cfg(feature = "customary")]
+    area_customary_units => Some("customary");
+    #[cfg(feature = "customary")]
+    volume_customary_units => Some("customary");
+    #[cfg(feature = "customary")]
+    force_customary_units => Some("customary");
+    #[cfg(feature = "customary")]
+    energy_customary_units => Some("customary");
+    #[cfg(feature = "customary")]
+    power_customary_units => Some("customary");
+    #[cfg(feature = "customary")]
+    pressure_customary_units => Some("customary");
+
+    #[cfg(feature = "fundamental-physics")]
+    length_fundamental_physics_units => Some("fundamental-physics");
+    #[cfg(feature = "fundamental-physics")]
+    mass_fundamental_physics_units => Some("fundamental-physics");
+    #[cfg(feature = "fundamental-physics")]
+    force_fundamental_physics_units => Some("fundamental-physics");
+    #[cfg(feature = "fundamental-physics")]
+    energy_fundamental_physics_units => Some("fundamental-physics");
+    #[cfg(feature = "fundamental-physics")]
+    power_fundamental_physics_units => Some("fundamental-physics");
+
+    #[cfg(feature = "julian-time")]
+    time_julian_time_units => Some("julian-time");
+
+    #[cfg(feature = "radiometry")]
+    radiance_units => Some("radiometry");
+    #[cfg(feature = "radiometry")]
+    spectral_radiance_units => Some("radiometry");
+    #[cfg(feature = "radiometry")]
+    photon_radiance_units => Some("radiometry");
+    #[cfg(feature = "radiometry")]
+    spectral_photon_radiance_units => Some("radiometry");
+    #[cfg(feature = "radiometry")]
+    inverse_solid_angle_units => Some("radiometry");
+
+    #[cfg(feature = "photometry")]
+    candela_units => Some("photometry");
+    #[cfg(feature = "photometry")]
+    lumen_units => Some("photometry");
+    #[cfg(feature = "photometry")]
+    lux_units => Some("photometry");
+
+    #[cfg(feature = "frequency")]
+    frequency_units => Some("frequency");
+
+    #[cfg(feature = "chemistry")]
+    amount_units => Some("chemistry");
+
+    #[cfg(feature = "electrical")]
+    ampere_units => Some("electrical");
+    #[cfg(feature = "electrical")]
+    coulomb_units => Some("electrical");
+    #[cfg(feature = "electrical")]
+    volt_units => Some("electrical");
+    #[cfg(feature = "electrical")]
+    ohm_units => Some("electrical");
+    #[cfg(feature = "electrical")]
+    farad_units => Some("electrical");
+    #[cfg(feature = "electrical")]
+    henry_units => Some("electrical");
+    #[cfg(feature = "electrical")]
+    weber_units => Some("electrical");
+    #[cfg(feature = "electrical")]
+    tesla_units => Some("electrical");
+
+    #[cfg(feature = "density")]
+    density_units => Some("density");
+    #[cfg(all(feature = "density", feature = "customary"))]
+    density_customary_units => Some("customary");
+}
@@ -11,7 +11,10 @@ use qtty_derive::Unit;
 
 /// Astronomical unit (au). Exact (IAU 2012): metres per au.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "au", dimension = Length, ratio = 149_597_870_700.0)]
+#[unit(
+    symbol = "au", dimension = Length, ratio = 149_597_870_700.0, exact_ratio = 149_597_870_700,
+    system = "astronomical",
+)]
 pub struct AstronomicalUnit;
 /// Type alias shorthand for [`AstronomicalUnit`].
 pub type Au = AstronomicalUnit;
@@ -29,7 +32,13 @@ const METERS_PER_LIGHT_YEAR: f64 = SPEED_OF_LIGHT_M_PER_S * SECONDS_PER_JULIAN_Y
 
 /// Light-year (ly): distance light travels in one Julian year (`365.25 d`) at `c = 299_792_458 m/s`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ly", dimension = Length, ratio = METERS_PER_LIGHT_YEAR)]
+#[unit(
+    symbol = "ly",
+    dimension = Length,
+    ratio = METERS_PER_LIGHT_YEAR,
+    exact_ratio = 9_460_730_472_580_800,
+    long_name = "light-year", system = "astronomical", aliases = ["light year", "light years"],
+)]
 pub struct LightYear;
 /// Type alias shorthand for [`LightYear`].
 pub type Ly = LightYear;
@@ -40,7 +49,10 @@ pub const LY: LightYears = LightYears::new(1.0);
 
 /// Parsec (pc): `pc = au * 648000 / π` (exact given au).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "pc", dimension = Length, ratio = 149_597_870_700.0 * (648_000.0 / PI))]
+#[unit(
+    symbol = "pc", dimension = Length, ratio = 149_597_870_700.0 * (648_000.0 / PI),
+    system = "astronomical",
+)]
 pub struct Parsec;
 /// Type alias shorthand for [`Parsec`].
 pub type Pc = Parsec;
@@ -51,7 +63,10 @@ pub const PC: Parsecs = Parsecs::new(1.0);
 
 /// Kiloparsec (kpc): `1e3 pc`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "kpc", dimension = Length, ratio = 1_000.0 * 149_597_870_700.0 * (648_000.0 / PI))]
+#[unit(
+    symbol = "kpc", dimension = Length, ratio = 1_000.0 * 149_597_870_700.0 * (648_000.0 / PI),
+    system = "astronomical",
+)]
 pub struct Kiloparsec;
 /// A quantity measured in kiloparsecs.
 pub type Kiloparsecs = Quantity<Kiloparsec>;
@@ -60,7 +75,10 @@ pub const KPC: Kiloparsecs = Kiloparsecs::new(1.0);
 
 /// Megaparsec (Mpc): `1e6 pc`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Mpc", dimension = Length, ratio = 1_000_000.0 * 149_597_870_700.0 * (648_000.0 / PI))]
+#[unit(
+    symbol = "Mpc", dimension = Length, ratio = 1_000_000.0 * 149_597_870_700.0 * (648_000.0 / PI),
+    system = "astronomical",
+)]
 pub struct Megaparsec;
 /// A quantity measured in megaparsecs.
 pub type Megaparsecs = Quantity<Megaparsec>;
@@ -69,7 +87,12 @@ pub const MPC: Megaparsecs = Megaparsecs::new(1.0);
 
 /// Gigaparsec (Gpc): `1e9 pc`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Gpc", dimension = Length, ratio = 1_000_000_000.0 * 149_597_870_700.0 * (648_000.0 / PI))]
+#[unit(
+    symbol = "Gpc",
+    dimension = Length,
+    ratio = 1_000_000_000.0 * 149_597_870_700.0 * (648_000.0 / PI),
+    system = "astronomical",
+)]
 pub struct Gigaparsec;
 /// A quantity measured in gigaparsecs.
 pub type Gigaparsecs = Quantity<Gigaparsec>;
@@ -89,7 +112,10 @@ pub mod nominal {
 
     /// Solar radius (R☉). Nominal value: metres per R☉.
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-    #[unit(symbol = "Rsun", dimension = Length, ratio = 695_700_000.0)]
+    #[unit(
+        symbol = "Rsun", dimension = Length, ratio = 695_700_000.0,
+        plural = "solar radii", system = "astronomical",
+    )]
     pub struct SolarRadius;
     /// A quantity measured in solar radii.
     pub type SolarRadiuses = Quantity<SolarRadius>;
@@ -98,7 +124,10 @@ pub mod nominal {
 
     /// Earth mean radius (nominal).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-    #[unit(symbol = "Rearth", dimension = Length, ratio = 6_371_000.0)]
+    #[unit(
+        symbol = "Rearth", dimension = Length, ratio = 6_371_000.0,
+        long_name = "Earth radius", plural = "Earth radii", system = "astronomical",
+    )]
     pub struct EarthRadius;
     /// A quantity measured in Earth radii.
     pub type EarthRadii = Quantity<EarthRadius>;
@@ -107,7 +136,12 @@ pub mod nominal {
 
     /// Earth equatorial radius (WGS84).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-    #[unit(symbol = "Rearth_eq", dimension = Length, ratio = 6_378_137.0)]
+    #[unit(
+        symbol = "Rearth_eq", dimension = Length, ratio = 6_378_137.0,
+        long_name = "Earth equatorial radius",
+        plural = "Earth equatorial radii",
+        system = "astronomical",
+    )]
     pub struct EarthEquatorialRadius;
     /// A quantity measured in Earth equatorial radii.
     pub type EarthEquatorialRadii = Quantity<EarthEquatorialRadius>;
@@ -116,7 +150,10 @@ pub mod nominal {
 
     /// Earth polar radius.
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-    #[unit(symbol = "Rearth_p", dimension = Length, ratio = 6_356_752.314_2)]
+    #[unit(
+        symbol = "Rearth_p", dimension = Length, ratio = 6_356_752.314_2,
+        long_name = "Earth polar radius", plural = "Earth polar radii", system = "astronomical",
+    )]
     pub struct EarthPolarRadius;
     /// A quantity measured in Earth polar radii.
     pub type EarthPolarRadii = Quantity<EarthPolarRadius>;
@@ -125,7 +162,10 @@ pub mod nominal {
 
     /// Lunar radius (mean, nominal).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-    #[unit(symbol = "Rmoon", dimension = Length, ratio = 1_737_400.0)]
+    #[unit(
+        symbol = "Rmoon", dimension = Length, ratio = 1_737_400.0,
+        plural = "lunar radii", system = "astronomical",
+    )]
     pub struct LunarRadius;
     /// A quantity measured in lunar radii.
     pub type LunarRadii = Quantity<LunarRadius>;
@@ -134,7 +174,10 @@ pub mod nominal {
 
     /// Jupiter equatorial radius (nominal).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-    #[unit(symbol = "Rjup", dimension = Length, ratio = 71_492_000.0)]
+    #[unit(
+        symbol = "Rjup", dimension = Length, ratio = 71_492_000.0,
+        long_name = "Jupiter radius", plural = "Jupiter radii", system = "astronomical",
+    )]
     pub struct JupiterRadius;
     /// A quantity measured in Jupiter radii.
     pub type JupiterRadii = Quantity<JupiterRadius>;
@@ -143,7 +186,7 @@ pub mod nominal {
 
     /// Lunar distance (Earth–Moon mean distance, LD).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-    #[unit(symbol = "LD", dimension = Length, ratio = 384_400_000.0)]
+    #[unit(symbol = "LD", dimension = Length, ratio = 384_400_000.0, system = "astronomical")]
     pub struct LunarDistance;
     /// A quantity measured in lunar distances.
     pub type LunarDistances = Quantity<LunarDistance>;
@@ -152,7 +195,10 @@ pub mod nominal {
 
     /// Solar diameter (twice the solar radius).
     #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-    #[unit(symbol = "Dsun", dimension = Length, ratio = 2.0 * 695_700_000.0)]
+    #[unit(
+        symbol = "Dsun", dimension = Length, ratio = 2.0 * 695_700_000.0,
+        system = "astronomical",
+    )]
     pub struct SolarDiameter;
     /// A quantity measured in solar diameters.
     pub type SolarDiameters = Quantity<SolarDiameter>;
@@ -6,7 +6,10 @@ use qtty_derive::Unit;
 
 /// Inch (`0.0254 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "in", dimension = Length, ratio = 254.0 / 10_000.0)]
+#[unit(
+    symbol = "in", dimension = Length, ratio = 254.0 / 10_000.0, exact_ratio = 254 / 10_000,
+    system = "customary",
+)]
 pub struct Inch;
 /// A quantity measured in inches.
 pub type Inches = Quantity<Inch>;
@@ -15,7 +18,10 @@ pub const INCH: Inches = Inches::new(1.0);
 
 /// Foot (`0.3048 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ft", dimension = Length, ratio = 3048.0 / 10_000.0)]
+#[unit(
+    symbol = "ft", dimension = Length, ratio = 3048.0 / 10_000.0, exact_ratio = 3_048 / 10_000,
+    plural = "feet", system = "customary",
+)]
 pub struct Foot;
 /// A quantity measured in feet.
 pub type Feet = Quantity<Foot>;
@@ -24,7 +30,10 @@ pub const FT: Feet = Feet::new(1.0);
 
 /// Yard (`0.9144 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "yd", dimension = Length, ratio = 9144.0 / 10_000.0)]
+#[unit(
+    symbol = "yd", dimension = Length, ratio = 9144.0 / 10_000.0, exact_ratio = 9_144 / 10_000,
+    system = "customary",
+)]
 pub struct Yard;
 /// A quantity measured in yards.
 pub type Yards = Quantity<Yard>;
@@ -33,7 +42,13 @@ pub const YD: Yards = Yards::new(1.0);
 
 /// (Statute) mile (`1609.344 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mi", dimension = Length, ratio = 1_609_344.0 / 1_000.0)]
+#[unit(
+    symbol = "mi",
+    dimension = Length,
+    ratio = 1_609_344.0 / 1_000.0,
+    exact_ratio = 1_609_344 / 1_000,
+    system = "customary",
+)]
 pub struct Mile;
 /// A quantity measured in miles.
 pub type Miles = Quantity<Mile>;
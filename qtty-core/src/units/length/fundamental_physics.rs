@@ -10,7 +10,10 @@ use qtty_derive::Unit;
 
 /// Bohr radius (`a0`). CODATA 2022 value in metres.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "a0", dimension = Length, ratio = 5.291_772_105_44e-11)]
+#[unit(
+    symbol = "a0", dimension = Length, ratio = 5.291_772_105_44e-11,
+    long_name = "Bohr radius", plural = "Bohr radii", system = "atomic",
+)]
 pub struct BohrRadius;
 /// A quantity measured in Bohr radii.
 pub type BohrRadii = Quantity<BohrRadius>;
@@ -19,7 +22,10 @@ pub const A0: BohrRadii = BohrRadii::new(1.0);
 
 /// Classical electron radius (`re`). CODATA 2022 value in metres.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "re", dimension = Length, ratio = 2.817_940_320_5e-15)]
+#[unit(
+    symbol = "re", dimension = Length, ratio = 2.817_940_320_5e-15,
+    plural = "classical electron radii", system = "atomic",
+)]
 pub struct ClassicalElectronRadius;
 /// A quantity measured in classical electron radii.
 pub type ClassicalElectronRadii = Quantity<ClassicalElectronRadius>;
@@ -28,7 +34,10 @@ pub const RE: ClassicalElectronRadii = ClassicalElectronRadii::new(1.0);
 
 /// Planck length (`lp`). CODATA 2022 value in metres.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "lp", dimension = Length, ratio = 1.616_255e-35)]
+#[unit(
+    symbol = "lp", dimension = Length, ratio = 1.616_255e-35,
+    long_name = "Planck length", system = "natural",
+)]
 pub struct PlanckLength;
 /// A quantity measured in Planck lengths.
 pub type PlanckLengths = Quantity<PlanckLength>;
@@ -37,7 +46,12 @@ pub const LP: PlanckLengths = PlanckLengths::new(1.0);
 
 /// Reduced Compton wavelength of the electron (`lambda_bar_e`). CODATA 2022 value in metres.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "lambda_bar_e", dimension = Length, ratio = 3.861_592_674_4e-13)]
+#[unit(
+    symbol = "lambda_bar_e", dimension = Length, ratio = 3.861_592_674_4e-13,
+    long_name = "reduced Compton wavelength of the electron",
+    plural = "reduced Compton wavelengths of the electron",
+    system = "atomic",
+)]
 pub struct ElectronReducedComptonWavelength;
 /// A quantity measured in reduced Compton wavelengths of the electron.
 pub type ElectronReducedComptonWavelengths = Quantity<ElectronReducedComptonWavelength>;
@@ -99,7 +99,10 @@ pub use fundamental_physics::*;
 
 /// Metre (SI base unit).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "m", dimension = Length, ratio = 1.0)]
+#[unit(
+    symbol = "m", dimension = Length, ratio = 1.0, exact_ratio = 1,
+    long_name = "metre", system = "SI", aliases = ["meter", "meters"],
+)]
 pub struct Meter;
 /// A quantity measured in metres.
 pub type Meters = Quantity<Meter>;
@@ -108,7 +111,10 @@ pub const M: Meters = Meters::new(1.0);
 
 /// Kilometre (`1000 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "km", dimension = Length, ratio = 1_000.0)]
+#[unit(
+    symbol = "km", dimension = Length, ratio = 1_000.0, exact_ratio = 1_000,
+    long_name = "kilometre", system = "SI", aliases = ["kilometer", "kilometers"],
+)]
 pub struct Kilometer;
 /// Type alias shorthand for [`Kilometer`].
 pub type Km = Kilometer;
@@ -119,7 +125,10 @@ pub const KM: Kilometers = Kilometers::new(1.0);
 
 /// Centimetre (`1e-2 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "cm", dimension = Length, ratio = 1e-2)]
+#[unit(
+    symbol = "cm", dimension = Length, ratio = 1e-2, exact_ratio = 1 / 100,
+    long_name = "centimetre", system = "SI", aliases = ["centimeter", "centimeters"],
+)]
 pub struct Centimeter;
 /// Type alias shorthand for [`Centimeter`].
 pub type Cm = Centimeter;
@@ -130,7 +139,10 @@ pub const CM: Centimeters = Centimeters::new(1.0);
 
 /// Millimetre (`1e-3 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mm", dimension = Length, ratio = 1e-3)]
+#[unit(
+    symbol = "mm", dimension = Length, ratio = 1e-3, exact_ratio = 1 / 1_000,
+    long_name = "millimetre", system = "SI", aliases = ["millimeter", "millimeters"],
+)]
 pub struct Millimeter;
 /// Type alias shorthand for [`Millimeter`].
 pub type Mm = Millimeter;
@@ -141,7 +153,12 @@ pub const MM: Millimeters = Millimeters::new(1.0);
 
 /// Micrometre (`1e-6 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "μm", dimension = Length, ratio = 1e-6)]
+#[unit(
+    symbol = "μm", dimension = Length, ratio = 1e-6, exact_ratio = 1 / 1_000_000,
+    long_name = "micrometre",
+    system = "SI",
+    aliases = ["micrometer", "micrometers", "micron", "microns"],
+)]
 pub struct Micrometer;
 /// Type alias shorthand for [`Micrometer`].
 pub type Um = Micrometer;
@@ -152,7 +169,10 @@ pub const UM: Micrometers = Micrometers::new(1.0);
 
 /// Nanometre (`1e-9 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "nm", dimension = Length, ratio = 1e-9)]
+#[unit(
+    symbol = "nm", dimension = Length, ratio = 1e-9, exact_ratio = 1 / 1_000_000_000,
+    long_name = "nanometre", system = "SI", aliases = ["nanometer", "nanometers"],
+)]
 pub struct Nanometer;
 /// Type alias shorthand for [`Nanometer`].
 pub type Nm = Nanometer;
@@ -163,7 +183,10 @@ pub const NM: Nanometers = Nanometers::new(1.0);
 
 /// Picometre (`1e-12 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "pm", dimension = Length, ratio = 1e-12)]
+#[unit(
+    symbol = "pm", dimension = Length, ratio = 1e-12, exact_ratio = 1 / 10_i128.pow(12),
+    long_name = "picometre", system = "SI", aliases = ["picometer", "picometers"],
+)]
 pub struct Picometer;
 /// A quantity measured in picometres.
 pub type Picometers = Quantity<Picometer>;
@@ -172,7 +195,10 @@ pub const PMETER: Picometers = Picometers::new(1.0);
 
 /// Femtometre (`1e-15 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "fm", dimension = Length, ratio = 1e-15)]
+#[unit(
+    symbol = "fm", dimension = Length, ratio = 1e-15, exact_ratio = 1 / 10_i128.pow(15),
+    long_name = "femtometre", system = "SI", aliases = ["femtometer", "femtometers"],
+)]
 pub struct Femtometer;
 /// A quantity measured in femtometres.
 pub type Femtometers = Quantity<Femtometer>;
@@ -181,7 +207,10 @@ pub const FM: Femtometers = Femtometers::new(1.0);
 
 /// Attometre (`1e-18 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "am", dimension = Length, ratio = 1e-18)]
+#[unit(
+    symbol = "am", dimension = Length, ratio = 1e-18, exact_ratio = 1 / 10_i128.pow(18),
+    long_name = "attometre", system = "SI", aliases = ["attometer", "attometers"],
+)]
 pub struct Attometer;
 /// A quantity measured in attometres.
 pub type Attometers = Quantity<Attometer>;
@@ -190,7 +219,10 @@ pub const AM: Attometers = Attometers::new(1.0);
 
 /// Zeptometre (`1e-21 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "zm", dimension = Length, ratio = 1e-21)]
+#[unit(
+    symbol = "zm", dimension = Length, ratio = 1e-21, exact_ratio = 1 / 10_i128.pow(21),
+    long_name = "zeptometre", system = "SI", aliases = ["zeptometer", "zeptometers"],
+)]
 pub struct Zeptometer;
 /// A quantity measured in zeptometres.
 pub type Zeptometers = Quantity<Zeptometer>;
@@ -199,7 +231,10 @@ pub const ZMETER: Zeptometers = Zeptometers::new(1.0);
 
 /// Yoctometre (`1e-24 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ym", dimension = Length, ratio = 1e-24)]
+#[unit(
+    symbol = "ym", dimension = Length, ratio = 1e-24, exact_ratio = 1 / 10_i128.pow(24),
+    long_name = "yoctometre", system = "SI", aliases = ["yoctometer", "yoctometers"],
+)]
 pub struct Yoctometer;
 /// A quantity measured in yoctometres.
 pub type Yoctometers = Quantity<Yoctometer>;
@@ -208,7 +243,10 @@ pub const YMETER: Yoctometers = Yoctometers::new(1.0);
 
 /// Megametre (`1e6 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Mm", dimension = Length, ratio = 1e6)]
+#[unit(
+    symbol = "Mm", dimension = Length, ratio = 1e6, exact_ratio = 1_000_000,
+    long_name = "megametre", system = "SI", aliases = ["megameter", "megameters"],
+)]
 pub struct Megameter;
 /// Type alias shorthand for [`Megameter`].
 pub type MegaMeter = Megameter;
@@ -219,7 +257,10 @@ pub const MEGAMETER: Megameters = Megameters::new(1.0);
 
 /// Decimetre (`1e-1 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "dm", dimension = Length, ratio = 1e-1)]
+#[unit(
+    symbol = "dm", dimension = Length, ratio = 1e-1, exact_ratio = 1 / 10,
+    long_name = "decimetre", system = "SI", aliases = ["decimeter", "decimeters"],
+)]
 pub struct Decimeter;
 /// A quantity measured in decimetres.
 pub type Decimeters = Quantity<Decimeter>;
@@ -228,7 +269,10 @@ pub const DM: Decimeters = Decimeters::new(1.0);
 
 /// Decametre (`1e1 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "dam", dimension = Length, ratio = 1e1)]
+#[unit(
+    symbol = "dam", dimension = Length, ratio = 1e1, exact_ratio = 10,
+    long_name = "decametre", system = "SI", aliases = ["decameter", "decameters"],
+)]
 pub struct Decameter;
 /// A quantity measured in decametres.
 pub type Decameters = Quantity<Decameter>;
@@ -237,7 +281,10 @@ pub const DAM: Decameters = Decameters::new(1.0);
 
 /// Hectometre (`1e2 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "hm", dimension = Length, ratio = 1e2)]
+#[unit(
+    symbol = "hm", dimension = Length, ratio = 1e2, exact_ratio = 100,
+    long_name = "hectometre", system = "SI", aliases = ["hectometer", "hectometers"],
+)]
 pub struct Hectometer;
 /// A quantity measured in hectometres.
 pub type Hectometers = Quantity<Hectometer>;
@@ -246,7 +293,10 @@ pub const HM: Hectometers = Hectometers::new(1.0);
 
 /// Gigametre (`1e9 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Gm", dimension = Length, ratio = 1e9)]
+#[unit(
+    symbol = "Gm", dimension = Length, ratio = 1e9, exact_ratio = 1_000_000_000,
+    long_name = "gigametre", system = "SI", aliases = ["gigameter", "gigameters"],
+)]
 pub struct Gigameter;
 /// A quantity measured in gigametres.
 pub type Gigameters = Quantity<Gigameter>;
@@ -255,7 +305,10 @@ pub const GM: Gigameters = Gigameters::new(1.0);
 
 /// Terametre (`1e12 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Tm", dimension = Length, ratio = 1e12)]
+#[unit(
+    symbol = "Tm", dimension = Length, ratio = 1e12, exact_ratio = 10_i128.pow(12),
+    long_name = "terametre", system = "SI", aliases = ["terameter", "terameters"],
+)]
 pub struct Terameter;
 /// A quantity measured in terametres.
 pub type Terameters = Quantity<Terameter>;
@@ -264,7 +317,10 @@ pub const TM: Terameters = Terameters::new(1.0);
 
 /// Petametre (`1e15 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Pm", dimension = Length, ratio = 1e15)]
+#[unit(
+    symbol = "Pm", dimension = Length, ratio = 1e15, exact_ratio = 10_i128.pow(15),
+    long_name = "petametre", system = "SI", aliases = ["petameter", "petameters"],
+)]
 pub struct Petameter;
 /// A quantity measured in petametres.
 pub type Petameters = Quantity<Petameter>;
@@ -273,7 +329,10 @@ pub const PM: Petameters = Petameters::new(1.0);
 
 /// Exametre (`1e18 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Em", dimension = Length, ratio = 1e18)]
+#[unit(
+    symbol = "Em", dimension = Length, ratio = 1e18, exact_ratio = 10_i128.pow(18),
+    long_name = "exametre", system = "SI", aliases = ["exameter", "exameters"],
+)]
 pub struct Exameter;
 /// A quantity measured in exametres.
 pub type Exameters = Quantity<Exameter>;
@@ -282,7 +341,10 @@ pub const EM: Exameters = Exameters::new(1.0);
 
 /// Zettametre (`1e21 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Zm", dimension = Length, ratio = 1e21)]
+#[unit(
+    symbol = "Zm", dimension = Length, ratio = 1e21, exact_ratio = 10_i128.pow(21),
+    long_name = "zettametre", system = "SI", aliases = ["zettameter", "zettameters"],
+)]
 pub struct Zettameter;
 /// A quantity measured in zettametres.
 pub type Zettameters = Quantity<Zettameter>;
@@ -291,7 +353,10 @@ pub const ZM: Zettameters = Zettameters::new(1.0);
 
 /// Yottametre (`1e24 m`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Ym", dimension = Length, ratio = 1e24)]
+#[unit(
+    symbol = "Ym", dimension = Length, ratio = 1e24, exact_ratio = 10_i128.pow(24),
+    long_name = "yottametre", system = "SI", aliases = ["yottameter", "yottameters"],
+)]
 pub struct Yottameter;
 /// A quantity measured in yottametres.
 pub type Yottameters = Quantity<Yottameter>;
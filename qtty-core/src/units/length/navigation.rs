@@ -6,7 +6,10 @@ use qtty_derive::Unit;
 
 /// Nautical mile (`1852 m` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "nmi", dimension = Length, ratio = 1_852.0)]
+#[unit(
+    symbol = "nmi", dimension = Length, ratio = 1_852.0, exact_ratio = 1_852,
+    system = "navigation",
+)]
 pub struct NauticalMile;
 /// A quantity measured in nautical miles.
 pub type NauticalMiles = Quantity<NauticalMile>;
@@ -15,7 +18,13 @@ pub const NMI: NauticalMiles = NauticalMiles::new(1.0);
 
 /// Chain (`66 ft` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ch", dimension = Length, ratio = 66.0 * (3048.0 / 10_000.0))]
+#[unit(
+    symbol = "ch",
+    dimension = Length,
+    ratio = 66.0 * (3048.0 / 10_000.0),
+    exact_ratio = 66 * 3_048 / 10_000,
+    system = "customary",
+)]
 pub struct Chain;
 /// A quantity measured in chains.
 pub type Chains = Quantity<Chain>;
@@ -24,7 +33,13 @@ pub const CHAIN: Chains = Chains::new(1.0);
 
 /// Rod / pole / perch (`16.5 ft` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "rd", dimension = Length, ratio = 16.5 * (3048.0 / 10_000.0))]
+#[unit(
+    symbol = "rd",
+    dimension = Length,
+    ratio = 16.5 * (3048.0 / 10_000.0),
+    exact_ratio = 165 * 3_048 / 100_000,
+    system = "customary",
+)]
 pub struct Rod;
 /// A quantity measured in rods/poles/perches.
 pub type Rods = Quantity<Rod>;
@@ -33,7 +48,13 @@ pub const ROD: Rods = Rods::new(1.0);
 
 /// Link (`1/100 of a chain`, i.e. `0.66 ft`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "lk", dimension = Length, ratio = (66.0 / 100.0) * (3048.0 / 10_000.0))]
+#[unit(
+    symbol = "lk",
+    dimension = Length,
+    ratio = (66.0 / 100.0) * (3048.0 / 10_000.0),
+    exact_ratio = 66 * 3_048 / 1_000_000,
+    system = "customary",
+)]
 pub struct Link;
 /// A quantity measured in links.
 pub type Links = Quantity<Link>;
@@ -42,7 +63,13 @@ pub const LINK: Links = Links::new(1.0);
 
 /// Fathom (`6 ft` exactly).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ftm", dimension = Length, ratio = 6.0 * (3048.0 / 10_000.0))]
+#[unit(
+    symbol = "ftm",
+    dimension = Length,
+    ratio = 6.0 * (3048.0 / 10_000.0),
+    exact_ratio = 6 * 3_048 / 10_000,
+    system = "customary",
+)]
 pub struct Fathom;
 /// A quantity measured in fathoms.
 pub type Fathoms = Quantity<Fathom>;
@@ -55,7 +82,10 @@ pub const FTM: Fathoms = Fathoms::new(1.0);
 
 /// Earth meridional circumference (approximate mean value).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Cmer", dimension = Length, ratio = 40_007_863.0)]
+#[unit(
+    symbol = "Cmer", dimension = Length, ratio = 40_007_863.0,
+    long_name = "Earth meridional circumference", system = "astronomical",
+)]
 pub struct EarthMeridionalCircumference;
 /// A quantity measured in Earth meridional circumferences.
 pub type EarthMeridionalCircumferences = Quantity<EarthMeridionalCircumference>;
@@ -64,7 +94,10 @@ pub const C_MERIDIONAL: EarthMeridionalCircumferences = EarthMeridionalCircumfer
 
 /// Earth equatorial circumference.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Ceq", dimension = Length, ratio = 40_075_017.0)]
+#[unit(
+    symbol = "Ceq", dimension = Length, ratio = 40_075_017.0,
+    long_name = "Earth equatorial circumference", system = "astronomical",
+)]
 pub struct EarthEquatorialCircumference;
 /// A quantity measured in Earth equatorial circumferences.
 pub type EarthEquatorialCircumferences = Quantity<EarthEquatorialCircumference>;
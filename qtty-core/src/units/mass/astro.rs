@@ -8,7 +8,7 @@ use qtty_derive::Unit;
 ///
 /// This is a **conversion constant** (nominal), not a “best estimate” of the Sun’s true mass.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "M☉", dimension = Mass, ratio = 1.988_416e33)]
+#[unit(symbol = "M☉", dimension = Mass, ratio = 1.988_416e33, system = "astronomical")]
 pub struct SolarMass;
 /// A quantity measured in solar masses.
 pub type SolarMasses = Quantity<SolarMass>;
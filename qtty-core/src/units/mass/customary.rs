@@ -6,7 +6,7 @@ use qtty_derive::Unit;
 
 /// Carat: `1 ct = 0.2 g` (exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ct", dimension = Mass, ratio = 1.0 / 5.0)]
+#[unit(symbol = "ct", dimension = Mass, ratio = 1.0 / 5.0, exact_ratio = 1 / 5, system = "other")]
 pub struct Carat;
 /// Shorthand type alias for [`Carat`].
 pub type Ct = Carat;
@@ -17,7 +17,13 @@ pub const CT: Carats = Carats::new(1.0);
 
 /// Grain: `1 gr = 64.79891 mg` (exact) == `0.064_798_91 g`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "gr", dimension = Mass, ratio = 6_479_891.0 / 100_000_000.0)]
+#[unit(
+    symbol = "gr",
+    dimension = Mass,
+    ratio = 6_479_891.0 / 100_000_000.0,
+    exact_ratio = 6_479_891 / 100_000_000,
+    system = "customary",
+)]
 pub struct Grain;
 /// Shorthand type alias for [`Grain`].
 pub type Gr = Grain;
@@ -28,7 +34,13 @@ pub const GR: Grains = Grains::new(1.0);
 
 /// Avoirdupois pound: `1 lb = 0.45359237 kg` (exact) == `453.59237 g`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "lb", dimension = Mass, ratio = 45_359_237.0 / 100_000.0)]
+#[unit(
+    symbol = "lb",
+    dimension = Mass,
+    ratio = 45_359_237.0 / 100_000.0,
+    exact_ratio = 45_359_237 / 100_000,
+    system = "customary",
+)]
 pub struct Pound;
 /// Shorthand type alias for [`Pound`].
 pub type Lb = Pound;
@@ -39,7 +51,13 @@ pub const LB: Pounds = Pounds::new(1.0);
 
 /// Avoirdupois ounce: `1 oz = 1/16 lb` (exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "oz", dimension = Mass, ratio = (45_359_237.0 / 100_000.0) / 16.0)]
+#[unit(
+    symbol = "oz",
+    dimension = Mass,
+    ratio = (45_359_237.0 / 100_000.0) / 16.0,
+    exact_ratio = 45_359_237 / 1_600_000,
+    system = "customary",
+)]
 pub struct Ounce;
 /// Shorthand type alias for [`Ounce`].
 pub type Oz = Ounce;
@@ -50,7 +68,13 @@ pub const OZ: Ounces = Ounces::new(1.0);
 
 /// Avoirdupois stone: `1 st = 14 lb` (exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "st", dimension = Mass, ratio = (45_359_237.0 / 100_000.0) * 14.0)]
+#[unit(
+    symbol = "st",
+    dimension = Mass,
+    ratio = (45_359_237.0 / 100_000.0) * 14.0,
+    exact_ratio = 45_359_237 * 14 / 100_000,
+    system = "customary",
+)]
 pub struct Stone;
 /// Shorthand type alias for [`Stone`].
 pub type St = Stone;
@@ -61,7 +85,13 @@ pub const ST: Stones = Stones::new(1.0);
 
 /// Short ton (US customary): `2000 lb` (exact given lb).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ton_us", dimension = Mass, ratio = (45_359_237.0 / 100_000.0) * 2000.0)]
+#[unit(
+    symbol = "ton_us",
+    dimension = Mass,
+    ratio = (45_359_237.0 / 100_000.0) * 2000.0,
+    exact_ratio = 45_359_237 * 2_000 / 100_000,
+    system = "customary",
+)]
 pub struct ShortTon;
 /// Quantity measured in short tons (US).
 pub type ShortTons = Quantity<ShortTon>;
@@ -70,7 +100,13 @@ pub const TON_US: ShortTons = ShortTons::new(1.0);
 
 /// Long ton (Imperial): `2240 lb` (exact given lb).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ton_uk", dimension = Mass, ratio = (45_359_237.0 / 100_000.0) * 2240.0)]
+#[unit(
+    symbol = "ton_uk",
+    dimension = Mass,
+    ratio = (45_359_237.0 / 100_000.0) * 2240.0,
+    exact_ratio = 45_359_237 * 2_240 / 100_000,
+    system = "customary",
+)]
 pub struct LongTon;
 /// Quantity measured in long tons (UK).
 pub type LongTons = Quantity<LongTon>;
@@ -8,7 +8,10 @@ use qtty_derive::Unit;
 ///
 /// Stored in grams using the CODATA 2022 recommended value for `m_u` in kilograms, converted by `1 kg = 1000 g`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "u", dimension = Mass, ratio = 1.660_539_068_92e-24)]
+#[unit(
+    symbol = "u", dimension = Mass, ratio = 1.660_539_068_92e-24,
+    system = "SI-accepted", aliases = ["dalton", "daltons"],
+)]
 pub struct AtomicMassUnit;
 /// Type alias shorthand for [`AtomicMassUnit`].
 pub type Dalton = AtomicMassUnit;
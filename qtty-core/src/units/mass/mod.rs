@@ -55,7 +55,7 @@ pub use astro::*;
 
 /// Gram.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "g", dimension = Mass, ratio = 1.0)]
+#[unit(symbol = "g", dimension = Mass, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Gram;
 /// A quantity measured in grams.
 pub type Grams = Quantity<Gram>;
@@ -71,13 +71,17 @@ pub const G: Grams = Grams::new(1.0);
 /// - a constant `$one` equal to `1.0` of that quantity.
 ///
 /// The `$ratio` argument is the conversion factor to grams, i.e.
-/// `$name::RATIO` such that `1 $sym = $ratio g`.
+/// `$name::RATIO` such that `1 $sym = $ratio g`; `$exact` is the same factor
+/// as an integer fraction (see `Unit::EXACT_RATIO`).
 macro_rules! si_gram {
-    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident) => {
+    ($name:ident, $sym:literal, $ratio:expr, $exact:expr, $alias:ident, $qty:ident, $one:ident) => {
         #[doc = concat!("SI mass unit `", stringify!($name), "` with gram-based prefix (symbol `", $sym,"`).")]
         #[doc = concat!("By definition, `1 ", $sym, " = ", stringify!($ratio), " g`.")]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-        #[unit(symbol = $sym, dimension = Mass, ratio = $ratio)]
+        #[unit(
+            symbol = $sym, dimension = Mass, ratio = $ratio, exact_ratio = $exact,
+            system = "SI",
+        )]
         pub struct $name;
 
         #[doc = concat!("Shorthand alias for [`", stringify!($name), "`]." )]
@@ -92,31 +96,98 @@ macro_rules! si_gram {
 }
 
 // Full SI prefix ladder (gram-based)
-si_gram!(Yoctogram, "yg", 1e-24, Yg, Yoctograms, YG);
-si_gram!(Zeptogram, "zg", 1e-21, Zg, Zeptograms, ZG);
-si_gram!(Attogram, "ag", 1e-18, Ag, Attograms, AG);
-si_gram!(Femtogram, "fg", 1e-15, Fg, Femtograms, FG);
-si_gram!(Picogram, "pg", 1e-12, Pg, Picograms, PG);
-si_gram!(Nanogram, "ng", 1e-9, Ng, Nanograms, NG);
-si_gram!(Microgram, "µg", 1e-6, Ug, Micrograms, UG);
-si_gram!(Milligram, "mg", 1e-3, Mg, Milligrams, MG);
-si_gram!(Centigram, "cg", 1e-2, Cg, Centigrams, CG);
-si_gram!(Decigram, "dg", 1e-1, Dg, Decigrams, DG);
-
-si_gram!(Decagram, "dag", 1e1, Dag, Decagrams, DAG);
-si_gram!(Hectogram, "hg", 1e2, Hg, Hectograms, HG);
-si_gram!(Kilogram, "kg", 1e3, Kg, Kilograms, KG);
-si_gram!(Megagram, "Mg", 1e6, MgG, Megagrams, MEGAGRAM);
-si_gram!(Gigagram, "Gg", 1e9, Gg, Gigagrams, GG);
-si_gram!(Teragram, "Tg", 1e12, Tg, Teragrams, TG);
-si_gram!(Petagram, "Pg", 1e15, PgG, Petagrams, PETAGRAM);
-si_gram!(Exagram, "Eg", 1e18, Eg, Exagrams, EG);
-si_gram!(Zettagram, "Zg", 1e21, ZgG, Zettagrams, ZETTAGRAM);
-si_gram!(Yottagram, "Yg", 1e24, YgG, Yottagrams, YOTTAGRAM);
+si_gram!(
+    Yoctogram,
+    "yg",
+    1e-24,
+    1 / 10_i128.pow(24),
+    Yg,
+    Yoctograms,
+    YG
+);
+si_gram!(
+    Zeptogram,
+    "zg",
+    1e-21,
+    1 / 10_i128.pow(21),
+    Zg,
+    Zeptograms,
+    ZG
+);
+si_gram!(
+    Attogram,
+    "ag",
+    1e-18,
+    1 / 10_i128.pow(18),
+    Ag,
+    Attograms,
+    AG
+);
+si_gram!(
+    Femtogram,
+    "fg",
+    1e-15,
+    1 / 10_i128.pow(15),
+    Fg,
+    Femtograms,
+    FG
+);
+si_gram!(
+    Picogram,
+    "pg",
+    1e-12,
+    1 / 10_i128.pow(12),
+    Pg,
+    Picograms,
+    PG
+);
+si_gram!(Nanogram, "ng", 1e-9, 1 / 1_000_000_000, Ng, Nanograms, NG);
+si_gram!(Microgram, "µg", 1e-6, 1 / 1_000_000, Ug, Micrograms, UG);
+si_gram!(Milligram, "mg", 1e-3, 1 / 1_000, Mg, Milligrams, MG);
+si_gram!(Centigram, "cg", 1e-2, 1 / 100, Cg, Centigrams, CG);
+si_gram!(Decigram, "dg", 1e-1, 1 / 10, Dg, Decigrams, DG);
+
+si_gram!(Decagram, "dag", 1e1, 10, Dag, Decagrams, DAG);
+si_gram!(Hectogram, "hg", 1e2, 100, Hg, Hectograms, HG);
+si_gram!(Kilogram, "kg", 1e3, 1_000, Kg, Kilograms, KG);
+si_gram!(Megagram, "Mg", 1e6, 1_000_000, MgG, Megagrams, MEGAGRAM);
+si_gram!(Gigagram, "Gg", 1e9, 1_000_000_000, Gg, Gigagrams, GG);
+si_gram!(Teragram, "Tg", 1e12, 10_i128.pow(12), Tg, Teragrams, TG);
+si_gram!(
+    Petagram,
+    "Pg",
+    1e15,
+    10_i128.pow(15),
+    PgG,
+    Petagrams,
+    PETAGRAM
+);
+si_gram!(Exagram, "Eg", 1e18, 10_i128.pow(18), Eg, Exagrams, EG);
+si_gram!(
+    Zettagram,
+    "Zg",
+    1e21,
+    10_i128.pow(21),
+    ZgG,
+    Zettagrams,
+    ZETTAGRAM
+);
+si_gram!(
+    Yottagram,
+    "Yg",
+    1e24,
+    10_i128.pow(24),
+    YgG,
+    Yottagrams,
+    YOTTAGRAM
+);
 
 /// Tonne (metric ton): `1 t = 1_000_000 g` (exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "t", dimension = Mass, ratio = 1_000_000.0)]
+#[unit(
+    symbol = "t", dimension = Mass, ratio = 1_000_000.0, exact_ratio = 1_000_000,
+    system = "SI-accepted", aliases = ["metric ton", "metric tons"],
+)]
 pub struct Tonne;
 /// Shorthand type alias for [`Tonne`].
 pub type T = Tonne;
@@ -32,6 +32,7 @@ pub mod angular_rate;
 pub mod area;
 pub mod energy;
 pub mod force;
+pub(crate) mod inventory;
 pub mod length;
 pub mod mass;
 #[cfg(feature = "photometry")]
@@ -326,7 +326,7 @@ pub use crate::dimension::{Illuminance, LuminousFlux, LuminousIntensity};
 
 /// Candela — SI base unit of luminous intensity (cd).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "cd", dimension = LuminousIntensity, ratio = 1.0)]
+#[unit(symbol = "cd", dimension = LuminousIntensity, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Candela;
 /// Type alias shorthand for [`Candela`].
 pub type Cd = Candela;
@@ -337,7 +337,7 @@ pub const CANDELA: Candelas = Candelas::new(1.0);
 
 /// Lumen — SI coherent derived unit of luminous flux (cd·sr).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "lm", dimension = LuminousFlux, ratio = 1.0)]
+#[unit(symbol = "lm", dimension = LuminousFlux, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Lumen;
 /// Type alias shorthand for [`Lumen`].
 pub type Lm = Lumen;
@@ -348,7 +348,10 @@ pub const LUMEN: Lumens = Lumens::new(1.0);
 
 /// Millilumen — 1 mlm = 10⁻³ lm.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mlm", dimension = LuminousFlux, ratio = 1e-3)]
+#[unit(
+    symbol = "mlm", dimension = LuminousFlux, ratio = 1e-3, exact_ratio = 1 / 1_000,
+    system = "SI",
+)]
 pub struct Millilumen;
 /// A quantity measured in millilumens.
 pub type Millilumens = Quantity<Millilumen>;
@@ -357,7 +360,7 @@ pub const MILLILUMEN: Millilumens = Millilumens::new(1.0);
 
 /// Kilolumen — 1 klm = 10³ lm.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "klm", dimension = LuminousFlux, ratio = 1e3)]
+#[unit(symbol = "klm", dimension = LuminousFlux, ratio = 1e3, exact_ratio = 1_000, system = "SI")]
 pub struct Kilolumen;
 /// A quantity measured in kilolumens.
 pub type Kilolumens = Quantity<Kilolumen>;
@@ -366,7 +369,10 @@ pub const KILOLUMEN: Kilolumens = Kilolumens::new(1.0);
 
 /// Lux — SI coherent derived unit of illuminance (lm·m⁻²).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "lx", dimension = Illuminance, ratio = 1.0)]
+#[unit(
+    symbol = "lx", dimension = Illuminance, ratio = 1.0, exact_ratio = 1,
+    plural = "lux", system = "SI",
+)]
 pub struct Lux;
 /// Type alias shorthand for [`Lux`].
 pub type Lx = Lux;
@@ -377,7 +383,10 @@ pub const LUX: Luxs = Luxs::new(1.0);
 
 /// Millilux — 1 mlx = 10⁻³ lx.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mlx", dimension = Illuminance, ratio = 1e-3)]
+#[unit(
+    symbol = "mlx", dimension = Illuminance, ratio = 1e-3, exact_ratio = 1 / 1_000,
+    plural = "millilux", system = "SI",
+)]
 pub struct Millilux;
 /// A quantity measured in millilux.
 pub type Milliluxs = Quantity<Millilux>;
@@ -386,7 +395,10 @@ pub const MILLILUX: Milliluxs = Milliluxs::new(1.0);
 
 /// Kilolux — 1 klx = 10³ lx.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "klx", dimension = Illuminance, ratio = 1e3)]
+#[unit(
+    symbol = "klx", dimension = Illuminance, ratio = 1e3, exact_ratio = 1_000,
+    plural = "kilolux", system = "SI",
+)]
 pub struct Kilolux;
 /// A quantity measured in kilolux.
 pub type Kiloluxs = Quantity<Kilolux>;
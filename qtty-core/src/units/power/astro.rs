@@ -8,7 +8,10 @@ use qtty_derive::Unit;
 ///
 /// This is a *nominal reference* value intended for consistent conversion.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "L☉", dimension = Power, ratio = 3.828e26)]
+#[unit(
+    symbol = "L☉", dimension = Power, ratio = 3.828e26, exact_ratio = 3_828 * 10_i128.pow(23),
+    system = "astronomical",
+)]
 pub struct SolarLuminosity;
 /// A quantity measured in solar luminosities.
 pub type SolarLuminosities = Quantity<SolarLuminosity>;
@@ -6,7 +6,13 @@ use qtty_derive::Unit;
 
 /// Metric horsepower (`PS`), defined as exactly `735.49875 W`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "PS", dimension = Power, ratio = 73_549_875.0 / 100_000.0)]
+#[unit(
+    symbol = "PS",
+    dimension = Power,
+    ratio = 73_549_875.0 / 100_000.0,
+    exact_ratio = 73_549_875 / 100_000,
+    long_name = "metric horsepower", plural = "metric horsepower", system = "other",
+)]
 pub struct HorsepowerMetric;
 /// A quantity measured in metric horsepower.
 pub type HorsepowerMetrics = Quantity<HorsepowerMetric>;
@@ -15,7 +21,10 @@ pub const PS: HorsepowerMetrics = HorsepowerMetrics::new(1.0);
 
 /// Electric horsepower (`hp_e`), defined as exactly `746 W`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "hp_e", dimension = Power, ratio = 746.0)]
+#[unit(
+    symbol = "hp_e", dimension = Power, ratio = 746.0, exact_ratio = 746,
+    long_name = "electrical horsepower", plural = "electrical horsepower", system = "other",
+)]
 pub struct HorsepowerElectric;
 /// A quantity measured in electric horsepower.
 pub type HorsepowerElectrics = Quantity<HorsepowerElectric>;
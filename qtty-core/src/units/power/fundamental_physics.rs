@@ -8,7 +8,10 @@ use qtty_derive::Unit;
 ///
 /// Exact: `1 erg = 1e-7 J`, so `1 erg/s = 1e-7 W`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "erg/s", dimension = Power, ratio = 1e-7)]
+#[unit(
+    symbol = "erg/s", dimension = Power, ratio = 1e-7, exact_ratio = 1 / 10_000_000,
+    plural = "ergs per second", system = "CGS",
+)]
 pub struct ErgPerSecond;
 /// One erg/s.
 pub const ERG_PER_S: Quantity<ErgPerSecond> = Quantity::new(1.0);
@@ -57,7 +57,7 @@ pub use astro::*;
 
 /// Watt (SI coherent derived unit).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "W", dimension = Power, ratio = 1.0)]
+#[unit(symbol = "W", dimension = Power, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Watt;
 /// Type alias shorthand for [`Watt`].
 pub type W = Watt;
@@ -67,10 +67,13 @@ pub type Watts = Quantity<W>;
 pub const WATT: Watts = Watts::new(1.0);
 
 macro_rules! si_watt {
-    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident) => {
+    ($name:ident, $sym:literal, $ratio:expr, $exact:expr, $alias:ident, $qty:ident, $one:ident) => {
         #[doc = concat!("SI-prefixed watt unit (", stringify!($ratio), " W).")]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-        #[unit(symbol = $sym, dimension = Power, ratio = $ratio)]
+        #[unit(
+                            symbol = $sym, dimension = Power, ratio = $ratio, exact_ratio = $exact,
+                            system = "SI",
+                        )]
         pub struct $name;
         #[doc = concat!("Type alias shorthand for [`", stringify!($name), "`].")]
         pub type $alias = $name;
@@ -82,26 +85,74 @@ macro_rules! si_watt {
 }
 
 // Full SI prefix ladder on watt
-si_watt!(Yoctowatt, "yW", 1e-24, Yw, Yoctowatts, YW);
-si_watt!(Zeptowatt, "zW", 1e-21, Zw, Zeptowatts, ZW);
-si_watt!(Attowatt, "aW", 1e-18, Aw, Attowatts, AW);
-si_watt!(Femtowatt, "fW", 1e-15, Fw, Femtowatts, FW);
-si_watt!(Picowatt, "pW", 1e-12, Pw, Picowatts, PW);
-si_watt!(Nanowatt, "nW", 1e-9, Nw, Nanowatts, NW);
-si_watt!(Microwatt, "µW", 1e-6, Uw, Microwatts, UW);
-si_watt!(Milliwatt, "mW", 1e-3, Mw, Milliwatts, MW_1);
-
-si_watt!(Deciwatt, "dW", 1e-1, Dw, Deciwatts, DW);
-si_watt!(Decawatt, "daW", 1e1, Daw, Decawatts, DAW);
-si_watt!(Hectowatt, "hW", 1e2, Hw, Hectowatts, HW);
-si_watt!(Kilowatt, "kW", 1e3, Kw, Kilowatts, KW);
-si_watt!(Megawatt, "MW", 1e6, MW, Megawatts, MEGAWATT);
-si_watt!(Gigawatt, "GW", 1e9, GW, Gigawatts, GW_1);
-si_watt!(Terawatt, "TW", 1e12, TW, Terawatts, TW_1);
-si_watt!(Petawatt, "PW", 1e15, PW, Petawatts, PETAWATT);
-si_watt!(Exawatt, "EW", 1e18, EW, Exawatts, EW_1);
-si_watt!(Zettawatt, "ZW", 1e21, ZW, Zettawatts, ZW_1);
-si_watt!(Yottawatt, "YW", 1e24, YW, Yottawatts, YW_1);
+si_watt!(
+    Yoctowatt,
+    "yW",
+    1e-24,
+    1 / 10_i128.pow(24),
+    Yw,
+    Yoctowatts,
+    YW
+);
+si_watt!(
+    Zeptowatt,
+    "zW",
+    1e-21,
+    1 / 10_i128.pow(21),
+    Zw,
+    Zeptowatts,
+    ZW
+);
+si_watt!(
+    Attowatt,
+    "aW",
+    1e-18,
+    1 / 10_i128.pow(18),
+    Aw,
+    Attowatts,
+    AW
+);
+si_watt!(
+    Femtowatt,
+    "fW",
+    1e-15,
+    1 / 10_i128.pow(15),
+    Fw,
+    Femtowatts,
+    FW
+);
+si_watt!(
+    Picowatt,
+    "pW",
+    1e-12,
+    1 / 10_i128.pow(12),
+    Pw,
+    Picowatts,
+    PW
+);
+si_watt!(Nanowatt, "nW", 1e-9, 1 / 1_000_000_000, Nw, Nanowatts, NW);
+si_watt!(Microwatt, "µW", 1e-6, 1 / 1_000_000, Uw, Microwatts, UW);
+si_watt!(Milliwatt, "mW", 1e-3, 1 / 1_000, Mw, Milliwatts, MW_1);
+
+si_watt!(Deciwatt, "dW", 1e-1, 1 / 10, Dw, Deciwatts, DW);
+si_watt!(Decawatt, "daW", 1e1, 10, Daw, Decawatts, DAW);
+si_watt!(Hectowatt, "hW", 1e2, 100, Hw, Hectowatts, HW);
+si_watt!(Kilowatt, "kW", 1e3, 1_000, Kw, Kilowatts, KW);
+si_watt!(Megawatt, "MW", 1e6, 1_000_000, MW, Megawatts, MEGAWATT);
+si_watt!(Gigawatt, "GW", 1e9, 1_000_000_000, GW, Gigawatts, GW_1);
+si_watt!(Terawatt, "TW", 1e12, 10_i128.pow(12), TW, Terawatts, TW_1);
+si_watt!(
+    Petawatt,
+    "PW",
+    1e15,
+    10_i128.pow(15),
+    PW,
+    Petawatts,
+    PETAWATT
+);
+si_watt!(Exawatt, "EW", 1e18, 10_i128.pow(18), EW, Exawatts, EW_1);
+si_watt!(Zettawatt, "ZW", 1e21, 10_i128.pow(21), ZW, Zettawatts, ZW_1);
+si_watt!(Yottawatt, "YW", 1e24, 10_i128.pow(24), YW, Yottawatts, YW_1);
 
 /// Canonical list of always-available (metric SI) power units.
 ///
@@ -39,7 +39,7 @@ impl<T: Unit<Dim = Pressure>> PressureUnit for T {}
 ///
 /// BIPM SI brochure 9th ed., Table 4: 1 Pa = 1 N m⁻¹ = 1 kg m⁻¹ s⁻².
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Pa", dimension = Pressure, ratio = 1.0)]
+#[unit(symbol = "Pa", dimension = Pressure, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Pascal;
 /// Type alias shorthand for [`Pascal`].
 pub type Pa = Pascal;
@@ -49,10 +49,13 @@ pub type Pascals = Quantity<Pa>;
 pub const PASCAL: Pascals = Pascals::new(1.0);
 
 macro_rules! si_pascal {
-    ($name:ident, $sym:literal, $ratio:expr, $alias:ident, $qty:ident, $one:ident) => {
+    ($name:ident, $sym:literal, $ratio:expr, $exact:expr, $alias:ident, $qty:ident, $one:ident) => {
         #[doc = concat!("SI-prefixed pascal unit (", stringify!($ratio), " Pa).")]
         #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-        #[unit(symbol = $sym, dimension = Pressure, ratio = $ratio)]
+        #[unit(
+                    symbol = $sym, dimension = Pressure, ratio = $ratio, exact_ratio = $exact,
+                    system = "SI",
+                )]
         pub struct $name;
         #[doc = concat!("Type alias shorthand for [`", stringify!($name), "`].")]
         pub type $alias = $name;
@@ -68,7 +71,7 @@ macro_rules! si_pascal {
 /// 1 hPa = 100 Pa exactly. Widely used in meteorology and observatory/site
 /// metadata to report atmospheric pressure (standard atmosphere ≈ 1013.25 hPa).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "hPa", dimension = Pressure, ratio = 1e2)]
+#[unit(symbol = "hPa", dimension = Pressure, ratio = 1e2, exact_ratio = 100, system = "SI")]
 pub struct Hectopascal;
 /// Type alias shorthand for [`Hectopascal`].
 pub type HPa = Hectopascal;
@@ -77,10 +80,34 @@ pub type Hectopascals = Quantity<HPa>;
 /// One hectopascal.
 pub const HECTOPASCAL: Hectopascals = Hectopascals::new(1.0);
 
-si_pascal!(Millipascal, "mPa", 1e-3, MilliPa, Millipascals, MILLIPASCAL);
-si_pascal!(Kilopascal, "kPa", 1e3, KPa, Kilopascals, KILOPASCAL);
-si_pascal!(Megapascal, "MPa", 1e6, MPa, Megapascals, MEGAPASCAL);
-si_pascal!(Gigapascal, "GPa", 1e9, GPa, Gigapascals, GIGAPASCAL);
+si_pascal!(
+    Millipascal,
+    "mPa",
+    1e-3,
+    1 / 1_000,
+    MilliPa,
+    Millipascals,
+    MILLIPASCAL
+);
+si_pascal!(Kilopascal, "kPa", 1e3, 1_000, KPa, Kilopascals, KILOPASCAL);
+si_pascal!(
+    Megapascal,
+    "MPa",
+    1e6,
+    1_000_000,
+    MPa,
+    Megapascals,
+    MEGAPASCAL
+);
+si_pascal!(
+    Gigapascal,
+    "GPa",
+    1e9,
+    1_000_000_000,
+    GPa,
+    Gigapascals,
+    GIGAPASCAL
+);
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Non-SI but common: bar
@@ -92,7 +119,7 @@ si_pascal!(Gigapascal, "GPa", 1e9, GPa, Gigapascals, GIGAPASCAL);
 /// and observatory metadata. The millibar (mbar) is numerically identical to the
 /// hectopascal (hPa).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "bar", dimension = Pressure, ratio = 1e5)]
+#[unit(symbol = "bar", dimension = Pressure, ratio = 1e5, exact_ratio = 100_000, system = "other")]
 pub struct Bar;
 /// A quantity measured in bars.
 pub type Bars = Quantity<Bar>;
@@ -106,7 +133,10 @@ pub const BAR: Bars = Bars::new(1.0);
 /// Standard atmosphere — 1 atm = 101 325 Pa (exact, defined by ISO 2533).
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "atm", dimension = Pressure, ratio = 101_325.0)]
+#[unit(
+    symbol = "atm", dimension = Pressure, ratio = 101_325.0, exact_ratio = 101_325,
+    system = "other", aliases = ["standard atmosphere", "standard atmospheres"],
+)]
 pub struct Atmosphere;
 /// Type alias shorthand for [`Atmosphere`].
 #[cfg(feature = "customary")]
@@ -123,7 +153,10 @@ pub const ATMOSPHERE: Atmospheres = Atmospheres::new(1.0);
 /// Defined as exactly 1/760 of a standard atmosphere.
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Torr", dimension = Pressure, ratio = 101_325.0 / 760.0)]
+#[unit(
+    symbol = "Torr", dimension = Pressure, ratio = 101_325.0 / 760.0, exact_ratio = 101_325 / 760,
+    plural = "torr", system = "other",
+)]
 pub struct Torr;
 /// A quantity measured in torr.
 #[cfg(feature = "customary")]
@@ -135,7 +168,13 @@ pub const TORR: Torrs = Torrs::new(1.0);
 /// Conventional millimetre of mercury — 1 mmHg = 101 325/760 Pa (same as Torr).
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mmHg", dimension = Pressure, ratio = 101_325.0 / 760.0)]
+#[unit(
+    symbol = "mmHg", dimension = Pressure, ratio = 101_325.0 / 760.0, exact_ratio = 101_325 / 760,
+    long_name = "millimetre of mercury",
+    plural = "millimetres of mercury",
+    system = "other",
+    aliases = ["millimeter of mercury", "millimeters of mercury"],
+)]
 pub struct MillimeterOfMercury;
 /// Type alias shorthand for [`MillimeterOfMercury`].
 #[cfg(feature = "customary")]
@@ -150,7 +189,10 @@ pub const MILLIMETER_OF_MERCURY: MillimetersOfMercury = MillimetersOfMercury::ne
 /// Pound-force per square inch — 1 psi ≈ 6 894.757 293 Pa.
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "psi", dimension = Pressure, ratio = 6_894.757_293)]
+#[unit(
+    symbol = "psi", dimension = Pressure, ratio = 6_894.757_293,
+    plural = "pounds per square inch", system = "customary",
+)]
 pub struct PoundPerSquareInch;
 /// Type alias shorthand for [`PoundPerSquareInch`].
 #[cfg(feature = "customary")]
@@ -165,7 +207,10 @@ pub const PSI: PoundsPerSquareInch = PoundsPerSquareInch::new(1.0);
 /// Inch of mercury — 1 inHg ≈ 3 386.389 Pa (25.4 × mmHg).
 #[cfg(feature = "customary")]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "inHg", dimension = Pressure, ratio = 3_386.389)]
+#[unit(
+    symbol = "inHg", dimension = Pressure, ratio = 3_386.389,
+    plural = "inches of mercury", system = "customary",
+)]
 pub struct InchOfMercury;
 /// Type alias shorthand for [`InchOfMercury`].
 #[cfg(feature = "customary")]
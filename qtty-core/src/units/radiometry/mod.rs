@@ -71,7 +71,13 @@ impl<T: Unit<Dim = InverseSolidAngle>> InverseSolidAngleUnit for T {}
 ///
 /// Canonical SI unit of radiance. RATIO is `1.0`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "W·m⁻²·sr⁻¹", dimension = Radiance, ratio = 1.0)]
+#[unit(
+    symbol = "W·m⁻²·sr⁻¹", dimension = Radiance, ratio = 1.0, exact_ratio = 1,
+    long_name = "watt per square metre per steradian",
+    plural = "watts per square metre per steradian",
+    system = "SI",
+    aliases = ["watt per square meter per steradian", "watts per square meter per steradian"],
+)]
 pub struct WattPerSquareMeterSteradian;
 /// A quantity measured in watts per square metre per steradian.
 pub type WattsPerSquareMeterSteradian = Quantity<WattPerSquareMeterSteradian>;
@@ -81,7 +87,13 @@ pub type WattsPerSquareMeterSteradian = Quantity<WattPerSquareMeterSteradian>;
 ///
 /// 1 erg·s⁻¹·cm⁻²·sr⁻¹ = 1×10⁻³ W·m⁻²·sr⁻¹.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "erg·s⁻¹·cm⁻²·sr⁻¹", dimension = Radiance, ratio = 1.0e-3)]
+#[unit(
+    symbol = "erg·s⁻¹·cm⁻²·sr⁻¹", dimension = Radiance, ratio = 1.0e-3, exact_ratio = 1 / 1_000,
+    long_name = "erg per second per square centimetre per steradian",
+    plural = "ergs per second per square centimetre per steradian",
+    system = "CGS",
+    aliases = ["erg per second per square centimeter per steradian", "ergs per second per square centimeter per steradian"],
+)]
 pub struct ErgPerSecondSquareCentimeterSteradian;
 /// A quantity measured in erg·s⁻¹·cm⁻²·sr⁻¹.
 pub type ErgsPerSecondSquareCentimeterSteradian = Quantity<ErgPerSecondSquareCentimeterSteradian>;
@@ -95,7 +107,13 @@ pub type ErgsPerSecondSquareCentimeterSteradian = Quantity<ErgPerSecondSquareCen
 ///
 /// Canonical SI unit. RATIO is `1.0`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "W·m⁻²·sr⁻¹·m⁻¹", dimension = SpectralRadiance, ratio = 1.0)]
+#[unit(
+    symbol = "W·m⁻²·sr⁻¹·m⁻¹", dimension = SpectralRadiance, ratio = 1.0, exact_ratio = 1,
+    long_name = "watt per square metre per steradian per metre",
+    plural = "watts per square metre per steradian per metre",
+    system = "SI",
+    aliases = ["watt per square meter per steradian per meter", "watts per square meter per steradian per meter"],
+)]
 pub struct WattPerSquareMeterSteradianMeter;
 /// A quantity measured in W·m⁻²·sr⁻¹·m⁻¹.
 pub type WattsPerSquareMeterSteradianMeter = Quantity<WattPerSquareMeterSteradianMeter>;
@@ -104,7 +122,16 @@ pub type WattsPerSquareMeterSteradianMeter = Quantity<WattPerSquareMeterSteradia
 ///
 /// 1 W·m⁻²·sr⁻¹·nm⁻¹ = 1×10⁹ W·m⁻²·sr⁻¹·m⁻¹.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "W·m⁻²·sr⁻¹·nm⁻¹", dimension = SpectralRadiance, ratio = 1.0e9)]
+#[unit(
+    symbol = "W·m⁻²·sr⁻¹·nm⁻¹",
+    dimension = SpectralRadiance,
+    ratio = 1.0e9,
+    exact_ratio = 1_000_000_000,
+    long_name = "watt per square metre per steradian per nanometre",
+    plural = "watts per square metre per steradian per nanometre",
+    system = "SI",
+    aliases = ["watt per square meter per steradian per nanometer", "watts per square meter per steradian per nanometer"],
+)]
 pub struct WattPerSquareMeterSteradianNanometer;
 /// A quantity measured in W·m⁻²·sr⁻¹·nm⁻¹.
 pub type WattsPerSquareMeterSteradianNanometer = Quantity<WattPerSquareMeterSteradianNanometer>;
@@ -115,7 +142,16 @@ pub type WattsPerSquareMeterSteradianNanometer = Quantity<WattPerSquareMeterSter
 ///
 /// 1 erg·s⁻¹·cm⁻²·sr⁻¹·Å⁻¹ = 1×10⁷ W·m⁻²·sr⁻¹·m⁻¹.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "erg·s⁻¹·cm⁻²·sr⁻¹·Å⁻¹", dimension = SpectralRadiance, ratio = 1.0e7)]
+#[unit(
+    symbol = "erg·s⁻¹·cm⁻²·sr⁻¹·Å⁻¹",
+    dimension = SpectralRadiance,
+    ratio = 1.0e7,
+    exact_ratio = 10_000_000,
+    long_name = "erg per second per square centimetre per steradian per ångström",
+    plural = "ergs per second per square centimetre per steradian per ångström",
+    system = "CGS",
+    aliases = ["erg per second per square centimeter per steradian per ångström", "ergs per second per square centimeter per steradian per ångström"],
+)]
 pub struct ErgPerSecondSquareCentimeterSteradianAngstrom;
 /// A quantity measured in erg·s⁻¹·cm⁻²·sr⁻¹·Å⁻¹.
 pub type ErgsPerSecondSquareCentimeterSteradianAngstrom =
@@ -131,7 +167,13 @@ pub type ErgsPerSecondSquareCentimeterSteradianAngstrom =
 /// Canonical SI form. RATIO is `1.0`. Photons are treated as dimensionless
 /// counts.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ph·m⁻²·s⁻¹·sr⁻¹", dimension = PhotonRadiance, ratio = 1.0)]
+#[unit(
+    symbol = "ph·m⁻²·s⁻¹·sr⁻¹", dimension = PhotonRadiance, ratio = 1.0, exact_ratio = 1,
+    long_name = "photon per square metre per second per steradian",
+    plural = "photons per square metre per second per steradian",
+    system = "SI",
+    aliases = ["photon per square meter per second per steradian", "photons per square meter per second per steradian"],
+)]
 pub struct PhotonPerSquareMeterSecondSteradian;
 /// A quantity measured in ph·m⁻²·s⁻¹·sr⁻¹.
 pub type PhotonsPerSquareMeterSecondSteradian = Quantity<PhotonPerSquareMeterSecondSteradian>;
@@ -141,7 +183,13 @@ pub type PhotonsPerSquareMeterSecondSteradian = Quantity<PhotonPerSquareMeterSec
 ///
 /// 1 ph·cm⁻²·s⁻¹·sr⁻¹ = 1×10⁴ ph·m⁻²·s⁻¹·sr⁻¹.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ph·cm⁻²·s⁻¹·sr⁻¹", dimension = PhotonRadiance, ratio = 1.0e4)]
+#[unit(
+    symbol = "ph·cm⁻²·s⁻¹·sr⁻¹", dimension = PhotonRadiance, ratio = 1.0e4, exact_ratio = 10_000,
+    long_name = "photon per square centimetre per second per steradian",
+    plural = "photons per square centimetre per second per steradian",
+    system = "CGS",
+    aliases = ["photon per square centimeter per second per steradian", "photons per square centimeter per second per steradian"],
+)]
 pub struct PhotonPerSquareCentimeterSecondSteradian;
 /// A quantity measured in ph·cm⁻²·s⁻¹·sr⁻¹.
 pub type PhotonsPerSquareCentimeterSecondSteradian =
@@ -153,7 +201,16 @@ pub type PhotonsPerSquareCentimeterSecondSteradian =
 /// This is the unit reported by the `darknsb` Python pipeline for the
 /// integrated NSB. 1 ph·cm⁻²·ns⁻¹·sr⁻¹ = 1×10¹³ ph·m⁻²·s⁻¹·sr⁻¹.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ph·cm⁻²·ns⁻¹·sr⁻¹", dimension = PhotonRadiance, ratio = 1.0e13)]
+#[unit(
+    symbol = "ph·cm⁻²·ns⁻¹·sr⁻¹",
+    dimension = PhotonRadiance,
+    ratio = 1.0e13,
+    exact_ratio = 10_i128.pow(13),
+    long_name = "photon per square centimetre per nanosecond per steradian",
+    plural = "photons per square centimetre per nanosecond per steradian",
+    system = "CGS",
+    aliases = ["photon per square centimeter per nanosecond per steradian", "photons per square centimeter per nanosecond per steradian"],
+)]
 pub struct PhotonPerSquareCentimeterNanosecondSteradian;
 /// A quantity measured in ph·cm⁻²·ns⁻¹·sr⁻¹.
 pub type PhotonsPerSquareCentimeterNanosecondSteradian =
@@ -168,7 +225,16 @@ pub type PhotonsPerSquareCentimeterNanosecondSteradian =
 ///
 /// Canonical SI form. RATIO is `1.0`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ph·m⁻²·s⁻¹·sr⁻¹·m⁻¹", dimension = SpectralPhotonRadiance, ratio = 1.0)]
+#[unit(
+    symbol = "ph·m⁻²·s⁻¹·sr⁻¹·m⁻¹",
+    dimension = SpectralPhotonRadiance,
+    ratio = 1.0,
+    exact_ratio = 1,
+    long_name = "photon per square metre per second per steradian per metre",
+    plural = "photons per square metre per second per steradian per metre",
+    system = "SI",
+    aliases = ["photon per square meter per second per steradian per meter", "photons per square meter per second per steradian per meter"],
+)]
 pub struct PhotonPerSquareMeterSecondSteradianMeter;
 /// A quantity measured in ph·m⁻²·s⁻¹·sr⁻¹·m⁻¹.
 pub type PhotonsPerSquareMeterSecondSteradianMeter =
@@ -180,7 +246,16 @@ pub type PhotonsPerSquareMeterSecondSteradianMeter =
 /// 1 ph·cm⁻²·s⁻¹·sr⁻¹·Å⁻¹ = 1×10¹⁴ ph·m⁻²·s⁻¹·sr⁻¹·m⁻¹
 /// (10⁴ for cm⁻²→m⁻² and 10¹⁰ for Å⁻¹→m⁻¹).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ph·cm⁻²·s⁻¹·sr⁻¹·Å⁻¹", dimension = SpectralPhotonRadiance, ratio = 1.0e14)]
+#[unit(
+    symbol = "ph·cm⁻²·s⁻¹·sr⁻¹·Å⁻¹",
+    dimension = SpectralPhotonRadiance,
+    ratio = 1.0e14,
+    exact_ratio = 10_i128.pow(14),
+    long_name = "photon per square centimetre per second per steradian per ångström",
+    plural = "photons per square centimetre per second per steradian per ångström",
+    system = "CGS",
+    aliases = ["photon per square centimeter per second per steradian per ångström", "photons per square centimeter per second per steradian per ångström"],
+)]
 pub struct PhotonPerSquareCentimeterSecondSteradianAngstrom;
 /// A quantity measured in ph·cm⁻²·s⁻¹·sr⁻¹·Å⁻¹.
 pub type PhotonsPerSquareCentimeterSecondSteradianAngstrom =
@@ -190,7 +265,16 @@ pub type PhotonsPerSquareCentimeterSecondSteradianAngstrom =
 ///
 /// 1 ph·cm⁻²·s⁻¹·sr⁻¹·nm⁻¹ = 1×10¹³ ph·m⁻²·s⁻¹·sr⁻¹·m⁻¹.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ph·cm⁻²·s⁻¹·sr⁻¹·nm⁻¹", dimension = SpectralPhotonRadiance, ratio = 1.0e13)]
+#[unit(
+    symbol = "ph·cm⁻²·s⁻¹·sr⁻¹·nm⁻¹",
+    dimension = SpectralPhotonRadiance,
+    ratio = 1.0e13,
+    exact_ratio = 10_i128.pow(13),
+    long_name = "photon per square centimetre per second per steradian per nanometre",
+    plural = "photons per square centimetre per second per steradian per nanometre",
+    system = "CGS",
+    aliases = ["photon per square centimeter per second per steradian per nanometer", "photons per square centimeter per second per steradian per nanometer"],
+)]
 pub struct PhotonPerSquareCentimeterSecondSteradianNanometer;
 /// A quantity measured in ph·cm⁻²·s⁻¹·sr⁻¹·nm⁻¹.
 pub type PhotonsPerSquareCentimeterSecondSteradianNanometer =
@@ -201,7 +285,16 @@ pub type PhotonsPerSquareCentimeterSecondSteradianNanometer =
 /// 1 ph·cm⁻²·ns⁻¹·sr⁻¹·nm⁻¹ = 1×10²² ph·m⁻²·s⁻¹·sr⁻¹·m⁻¹
 /// (10⁴ for cm⁻²→m⁻², 10⁹ for ns⁻¹→s⁻¹, and 10⁹ for nm⁻¹→m⁻¹).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ph·cm⁻²·ns⁻¹·sr⁻¹·nm⁻¹", dimension = SpectralPhotonRadiance, ratio = 1.0e22)]
+#[unit(
+    symbol = "ph·cm⁻²·ns⁻¹·sr⁻¹·nm⁻¹",
+    dimension = SpectralPhotonRadiance,
+    ratio = 1.0e22,
+    exact_ratio = 10_i128.pow(22),
+    long_name = "photon per square centimetre per nanosecond per steradian per nanometre",
+    plural = "photons per square centimetre per nanosecond per steradian per nanometre",
+    system = "CGS",
+    aliases = ["photon per square centimeter per nanosecond per steradian per nanometer", "photons per square centimeter per nanosecond per steradian per nanometer"],
+)]
 pub struct PhotonPerSquareCentimeterNanosecondSteradianNanometer;
 /// A quantity measured in ph·cm⁻²·ns⁻¹·sr⁻¹·nm⁻¹.
 pub type PhotonsPerSquareCentimeterNanosecondSteradianNanometer =
@@ -223,7 +316,12 @@ pub type PhotonsPerSquareCentimeterNanosecondSteradianNanometer =
 /// an inverse-solid-angle marker. Domain crates should layer their own
 /// (band-dependent) transform on top.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "S10", dimension = InverseSolidAngle, ratio = 1.0)]
+#[unit(
+    symbol = "S10", dimension = InverseSolidAngle, ratio = 1.0, exact_ratio = 1,
+    long_name = "tenth-magnitude star per square degree",
+    plural = "tenth-magnitude stars per square degree",
+    system = "astronomical",
+)]
 pub struct S10;
 /// A quantity measured in S10 units.
 pub type S10s = Quantity<S10>;
@@ -7,20 +7,43 @@
 //! (`Kelvin::RATIO == 1.0`). Temperature here is the SI base dimension Θ
 //! (thermodynamic temperature), measured on an absolute scale.
 //!
-//! Only the kelvin is provided. Affine-offset scales such as Celsius and
-//! Fahrenheit are intentionally omitted: they require an additive offset that
-//! does not compose linearly with other quantities and would silently break
-//! the [`Quantity`] arithmetic guarantees. Convert affine-offset readings to
-//! kelvin at the boundary before constructing a [`Kelvins`] value.
+//! Only absolute linear scales (kelvin, rankine) are provided as [`Unit`]s.
+//! Affine-offset scales such as Celsius and Fahrenheit require an additive
+//! offset that does not compose linearly with other quantities, so they are
+//! not units. Instead, absolute readings on any scale are modelled by
+//! [`TemperaturePoint`], which keeps points and differences apart:
+//!
+//! - point − point → [`Quantity`] (a temperature *difference*),
+//! - point ± difference → point,
+//! - point + point does not compile.
 //!
 //! ```rust
-//! use qtty_core::temperature::Kelvins;
+//! use qtty_core::temperature::{Celsius, Fahrenheit, Kelvins, TemperaturePoint};
+//!
+//! let room = TemperaturePoint::<Celsius>::new(20.0);
+//! let body = TemperaturePoint::<Fahrenheit>::new(98.6).to::<Celsius>();
+//! let delta: Kelvins = body - room;
+//! assert!((delta.value() - 17.0).abs() < 1e-9);
 //!
 //! let t = Kelvins::new(284.65); // ≈ 11.5 °C
 //! assert!((t.value() - 284.65).abs() < 1e-9);
 //! ```
+//!
+//! Adding two absolute temperatures is rejected at compile time:
+//!
+//! ```rust,compile_fail
+//! use qtty_core::temperature::{Celsius, TemperaturePoint};
+//!
+//! let a = TemperaturePoint::<Celsius>::new(20.0);
+//! let b = TemperaturePoint::<Celsius>::new(5.0);
+//! let _ = a + b;
+//! ```
 
+use crate::scalar::Real;
 use crate::{Quantity, Unit};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 use qtty_derive::Unit;
 
 /// Re-export the temperature dimension from the dimension module.
@@ -39,7 +62,7 @@ impl<T: Unit<Dim = Temperature>> TemperatureUnit for T {}
 /// BIPM SI brochure 9th ed., Table 2: the kelvin (K) is defined by fixing the
 /// numerical value of the Boltzmann constant *k* to 1.380 649 × 10⁻²³ J K⁻¹.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "K", dimension = Temperature, ratio = 1.0)]
+#[unit(symbol = "K", dimension = Temperature, ratio = 1.0, exact_ratio = 1, system = "SI")]
 pub struct Kelvin;
 /// Type alias shorthand for [`Kelvin`].
 pub type K = Kelvin;
@@ -54,7 +77,13 @@ pub const KELVIN: Kelvins = Kelvins::new(1.0);
 /// zero. Used primarily in US engineering. The conversion to kelvin is
 /// exact: `T_K = T_R × 5/9`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "°R", dimension = Temperature, ratio = 5.0 / 9.0)]
+#[unit(
+    symbol = "°R", dimension = Temperature, ratio = 5.0 / 9.0, exact_ratio = 5 / 9,
+    long_name = "degree Rankine",
+    plural = "degrees Rankine",
+    system = "customary",
+    aliases = ["rankine"],
+)]
 pub struct Rankine;
 /// Type alias shorthand for [`Rankine`].
 pub type R = Rankine;
@@ -86,10 +115,194 @@ temperature_units!(crate::impl_unit_cross_unit_ops);
 #[cfg(test)]
 temperature_units!(crate::assert_units_are_builtin);
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Affine temperature scales
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A temperature scale on which absolute readings ([`TemperaturePoint`]s) are
+/// taken.
+///
+/// A scale pairs a linear [`TemperatureUnit`] — used for differences between
+/// two readings — with the reading that corresponds to absolute zero. A reading
+/// `x` on the scale denotes the thermodynamic temperature
+/// `x − ABSOLUTE_ZERO` expressed in [`Self::Unit`](TemperatureScale::Unit).
+pub trait TemperatureScale: Copy + PartialEq + fmt::Debug + 'static {
+    /// Linear unit in which differences between readings are measured.
+    type Unit: TemperatureUnit;
+    /// Reading of absolute zero on this scale, in units of [`Self::Unit`](TemperatureScale::Unit).
+    const ABSOLUTE_ZERO: f64;
+    /// Symbol printed after an absolute reading (e.g. `"°C"`).
+    const SCALE_SYMBOL: &'static str;
+}
+
+impl TemperatureScale for Kelvin {
+    type Unit = Kelvin;
+    const ABSOLUTE_ZERO: f64 = 0.0;
+    const SCALE_SYMBOL: &'static str = "K";
+}
+
+impl TemperatureScale for Rankine {
+    type Unit = Rankine;
+    const ABSOLUTE_ZERO: f64 = 0.0;
+    const SCALE_SYMBOL: &'static str = "°R";
+}
+
+/// Celsius scale (°C): kelvin-sized degrees, `0 °C = 273.15 K` exactly.
+///
+/// This is a [`TemperatureScale`], not a [`Unit`]; differences between Celsius
+/// readings are [`Kelvins`].
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Celsius;
+
+impl TemperatureScale for Celsius {
+    type Unit = Kelvin;
+    const ABSOLUTE_ZERO: f64 = -273.15;
+    const SCALE_SYMBOL: &'static str = "°C";
+}
+
+/// Fahrenheit scale (°F): rankine-sized degrees, `0 °F = 459.67 °R` exactly.
+///
+/// This is a [`TemperatureScale`], not a [`Unit`]; differences between
+/// Fahrenheit readings are [`Rankines`].
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Fahrenheit;
+
+impl TemperatureScale for Fahrenheit {
+    type Unit = Rankine;
+    const ABSOLUTE_ZERO: f64 = -459.67;
+    const SCALE_SYMBOL: &'static str = "°F";
+}
+
+/// An absolute temperature reading on the scale `T`.
+///
+/// Unlike [`Quantity`], a point is not a vector quantity: it can be shifted by
+/// a temperature difference and two points can be subtracted, but two points
+/// cannot be added or scaled.
+///
+/// ```rust
+/// use qtty_core::temperature::{Celsius, Kelvin, Kelvins, TemperaturePoint};
+///
+/// let freezing = TemperaturePoint::<Celsius>::new(0.0);
+/// assert!((freezing.to::<Kelvin>().value() - 273.15).abs() < 1e-12);
+///
+/// let warmer = freezing + Kelvins::new(25.0);
+/// assert!((warmer.value() - 25.0).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct TemperaturePoint<T: TemperatureScale, S: Real = f64>(S, PhantomData<T>);
+
+/// An absolute temperature on the Celsius scale.
+pub type CelsiusTemperature = TemperaturePoint<Celsius>;
+/// An absolute temperature on the Fahrenheit scale.
+pub type FahrenheitTemperature = TemperaturePoint<Fahrenheit>;
+/// An absolute temperature on the kelvin scale.
+pub type KelvinTemperature = TemperaturePoint<Kelvin>;
+/// An absolute temperature on the Rankine scale.
+pub type RankineTemperature = TemperaturePoint<Rankine>;
+
+impl<T: TemperatureScale, S: Real> TemperaturePoint<T, S> {
+    /// Creates a reading of `value` on the scale `T`.
+    #[inline]
+    pub const fn new(value: S) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the raw reading on the scale `T`.
+    #[inline]
+    pub const fn value(self) -> S {
+        self.0
+    }
+
+    /// Creates a reading from a thermodynamic (absolute-zero based) temperature.
+    #[inline]
+    pub fn from_absolute<U: TemperatureUnit>(absolute: Quantity<U, S>) -> Self {
+        let absolute = absolute.to::<T::Unit>().value();
+        Self::new(absolute + S::from_f64(T::ABSOLUTE_ZERO))
+    }
+
+    /// Returns the thermodynamic temperature, i.e. the distance from absolute
+    /// zero, in the scale's difference unit.
+    #[inline]
+    pub fn absolute(self) -> Quantity<T::Unit, S> {
+        Quantity::new(self.0 - S::from_f64(T::ABSOLUTE_ZERO))
+    }
+
+    /// Converts this reading to the scale `T2`, applying both the degree size
+    /// and the zero-point offset.
+    ///
+    /// ```rust
+    /// use qtty_core::temperature::{Celsius, Fahrenheit, TemperaturePoint};
+    ///
+    /// let boiling = TemperaturePoint::<Celsius>::new(100.0);
+    /// assert!((boiling.to::<Fahrenheit>().value() - 212.0).abs() < 1e-9);
+    /// ```
+    #[inline]
+    pub fn to<T2: TemperatureScale>(self) -> TemperaturePoint<T2, S> {
+        TemperaturePoint::from_absolute(self.absolute())
+    }
+}
+
+impl<T: TemperatureScale, S: Real> Sub for TemperaturePoint<T, S> {
+    type Output = Quantity<T::Unit, S>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Quantity::new(self.0 - rhs.0)
+    }
+}
+
+impl<T: TemperatureScale, U: TemperatureUnit, S: Real> Add<Quantity<U, S>>
+    for TemperaturePoint<T, S>
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Quantity<U, S>) -> Self {
+        Self::new(self.0 + rhs.to::<T::Unit>().value())
+    }
+}
+
+impl<T: TemperatureScale, U: TemperatureUnit, S: Real> Sub<Quantity<U, S>>
+    for TemperaturePoint<T, S>
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Quantity<U, S>) -> Self {
+        Self::new(self.0 - rhs.to::<T::Unit>().value())
+    }
+}
+
+impl<T: TemperatureScale, U: TemperatureUnit, S: Real> AddAssign<Quantity<U, S>>
+    for TemperaturePoint<T, S>
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Quantity<U, S>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: TemperatureScale, U: TemperatureUnit, S: Real> SubAssign<Quantity<U, S>>
+    for TemperaturePoint<T, S>
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Quantity<U, S>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: TemperatureScale, S: Real> fmt::Display for TemperaturePoint<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        write!(f, " {}", T::SCALE_SYMBOL)
+    }
+}
+
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use approx::assert_abs_diff_eq;
+    use proptest::prelude::*;
 
     #[test]
     fn kelvin_roundtrip() {
@@ -129,4 +342,88 @@ mod tests {
         let r: Rankines = k.to();
         assert_abs_diff_eq!(r.value(), 491.67, epsilon = 1e-9);
     }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Affine temperature points
+    // ─────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn celsius_to_kelvin_and_fahrenheit() {
+        let t = CelsiusTemperature::new(20.0);
+        assert_abs_diff_eq!(t.to::<Kelvin>().value(), 293.15, epsilon = 1e-12);
+        assert_abs_diff_eq!(t.to::<Fahrenheit>().value(), 68.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(t.to::<Rankine>().value(), 527.67, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn fahrenheit_celsius_crossover() {
+        let t = FahrenheitTemperature::new(-40.0);
+        assert_abs_diff_eq!(t.to::<Celsius>().value(), -40.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn absolute_zero_on_every_scale() {
+        let zero = KelvinTemperature::from_absolute(Kelvins::new(0.0));
+        assert_abs_diff_eq!(zero.to::<Celsius>().value(), -273.15, epsilon = 1e-12);
+        assert_abs_diff_eq!(zero.to::<Fahrenheit>().value(), -459.67, epsilon = 1e-12);
+        assert_abs_diff_eq!(zero.to::<Rankine>().value(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn point_minus_point_is_difference() {
+        let a = CelsiusTemperature::new(25.0);
+        let b = CelsiusTemperature::new(5.0);
+        let d: Kelvins = a - b;
+        assert_abs_diff_eq!(d.value(), 20.0, epsilon = 1e-12);
+
+        let f: Rankines = FahrenheitTemperature::new(50.0) - FahrenheitTemperature::new(32.0);
+        assert_abs_diff_eq!(f.value(), 18.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn point_plus_difference_is_point() {
+        let mut t = CelsiusTemperature::new(10.0);
+        t += Kelvins::new(5.0);
+        assert_abs_diff_eq!(t.value(), 15.0, epsilon = 1e-12);
+
+        // A rankine-sized difference is rescaled to kelvin-sized degrees.
+        let t = t + Rankines::new(9.0);
+        assert_abs_diff_eq!(t.value(), 20.0, epsilon = 1e-12);
+
+        let mut t = t - Kelvins::new(30.0);
+        assert_abs_diff_eq!(t.value(), -10.0, epsilon = 1e-12);
+        t -= Rankines::new(18.0);
+        assert_abs_diff_eq!(t.value(), -20.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn absolute_round_trip() {
+        let t = FahrenheitTemperature::new(98.6);
+        let abs: Kelvins = t.absolute().to();
+        assert_abs_diff_eq!(abs.value(), 310.15, epsilon = 1e-9);
+        let back = FahrenheitTemperature::from_absolute(abs);
+        assert_abs_diff_eq!(back.value(), 98.6, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn point_display_uses_scale_symbol() {
+        assert_eq!(CelsiusTemperature::new(21.5).to_string(), "21.5 °C");
+        assert_eq!(
+            format!("{:.1}", FahrenheitTemperature::new(70.0)),
+            "70.0 °F"
+        );
+        assert_eq!(KelvinTemperature::new(300.0).to_string(), "300 K");
+        assert_eq!(
+            TemperaturePoint::<Rankine, f32>::new(1.0).to_string(),
+            "1 °R"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_celsius_fahrenheit_round_trip(c in -1e6..1e6f64) {
+            let back = CelsiusTemperature::new(c).to::<Fahrenheit>().to::<Celsius>();
+            prop_assert!((back.value() - c).abs() < 1e-9 * c.abs().max(1.0));
+        }
+    }
 }
@@ -10,7 +10,7 @@ use qtty_derive::Unit;
 ///
 /// Convention used: `1 sidereal day ≈ 86_164.0905 s`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "sd", dimension = Time, ratio = 86_164.090_5)]
+#[unit(symbol = "sd", dimension = Time, ratio = 86_164.090_5, system = "astronomical")]
 pub struct SiderealDay;
 /// A quantity measured in sidereal days.
 pub type SiderealDays = Quantity<SiderealDay>;
@@ -21,7 +21,10 @@ pub const SIDEREAL_DAY: SiderealDays = SiderealDays::new(1.0);
 ///
 /// Convention used: `1 synodic month ≈ 29.530590 d`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "synmo", dimension = Time, ratio = 29.530_590 * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "synmo", dimension = Time, ratio = 29.530_590 * SECONDS_PER_DAY,
+    system = "astronomical",
+)]
 pub struct SynodicMonth;
 /// A quantity measured in synodic months.
 pub type SynodicMonths = Quantity<SynodicMonth>;
@@ -32,7 +35,10 @@ pub const SYNODIC_MONTH: SynodicMonths = SynodicMonths::new(1.0);
 ///
 /// Common convention: `1 sidereal year ≈ 365.256363004 d`.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "syr", dimension = Time, ratio = 365.256_363_004 * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "syr", dimension = Time, ratio = 365.256_363_004 * SECONDS_PER_DAY,
+    system = "astronomical",
+)]
 pub struct SiderealYear;
 /// A quantity measured in sidereal years.
 pub type SiderealYears = Quantity<SiderealYear>;
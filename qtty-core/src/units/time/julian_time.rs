@@ -8,7 +8,13 @@ use qtty_derive::Unit;
 
 /// Julian year (`365.25 d`), expressed in seconds.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "a", dimension = Time, ratio = 365.25 * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "a",
+    dimension = Time,
+    ratio = 365.25 * SECONDS_PER_DAY,
+    exact_ratio = 36_525 * 86_400 / 100,
+    long_name = "Julian year", system = "astronomical",
+)]
 pub struct JulianYear;
 /// A quantity measured in Julian years.
 pub type JulianYears = Quantity<JulianYear>;
@@ -17,7 +23,13 @@ pub const JULIAN_YEAR: JulianYears = JulianYears::new(1.0);
 
 /// Julian century (`36_525 d`), expressed in seconds.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "JC", dimension = Time, ratio = 36_525.0 * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "JC",
+    dimension = Time,
+    ratio = 36_525.0 * SECONDS_PER_DAY,
+    exact_ratio = 36_525 * 86_400,
+    long_name = "Julian century", system = "astronomical",
+)]
 pub struct JulianCentury;
 /// A quantity measured in Julian centuries.
 pub type JulianCenturies = Quantity<JulianCentury>;
@@ -82,7 +82,10 @@ pub use astro::*;
 
 /// Attoseconds (`1 as = 10^-18 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "as", dimension = Time, ratio = 1e-18)]
+#[unit(
+    symbol = "as", dimension = Time, ratio = 1e-18, exact_ratio = 1 / 10_i128.pow(18),
+    system = "SI",
+)]
 pub struct Attosecond;
 /// A quantity measured in attoseconds.
 pub type Attoseconds = Quantity<Attosecond>;
@@ -91,7 +94,10 @@ pub const ATTOSEC: Attoseconds = Attoseconds::new(1.0);
 
 /// Femtoseconds (`1 fs = 10^-15 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "fs", dimension = Time, ratio = 1e-15)]
+#[unit(
+    symbol = "fs", dimension = Time, ratio = 1e-15, exact_ratio = 1 / 10_i128.pow(15),
+    system = "SI",
+)]
 pub struct Femtosecond;
 /// A quantity measured in femtoseconds.
 pub type Femtoseconds = Quantity<Femtosecond>;
@@ -100,7 +106,10 @@ pub const FEMTOSEC: Femtoseconds = Femtoseconds::new(1.0);
 
 /// Picoseconds (`1 ps = 10^-12 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ps", dimension = Time, ratio = 1e-12)]
+#[unit(
+    symbol = "ps", dimension = Time, ratio = 1e-12, exact_ratio = 1 / 10_i128.pow(12),
+    system = "SI",
+)]
 pub struct Picosecond;
 /// A quantity measured in picoseconds.
 pub type Picoseconds = Quantity<Picosecond>;
@@ -109,7 +118,10 @@ pub const PICOSEC: Picoseconds = Picoseconds::new(1.0);
 
 /// Nanoseconds (`1 ns = 10^-9 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ns", dimension = Time, ratio = 1e-9)]
+#[unit(
+    symbol = "ns", dimension = Time, ratio = 1e-9, exact_ratio = 1 / 1_000_000_000,
+    system = "SI",
+)]
 pub struct Nanosecond;
 /// A quantity measured in nanoseconds.
 pub type Nanoseconds = Quantity<Nanosecond>;
@@ -118,7 +130,7 @@ pub const NANOSEC: Nanoseconds = Nanoseconds::new(1.0);
 
 /// Microseconds (`1 µs = 10^-6 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "µs", dimension = Time, ratio = 1e-6)]
+#[unit(symbol = "µs", dimension = Time, ratio = 1e-6, exact_ratio = 1 / 1_000_000, system = "SI")]
 pub struct Microsecond;
 /// A quantity measured in microseconds.
 pub type Microseconds = Quantity<Microsecond>;
@@ -127,7 +139,7 @@ pub const MICROSEC: Microseconds = Microseconds::new(1.0);
 
 /// Milliseconds (`1 ms = 10^-3 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ms", dimension = Time, ratio = 1e-3)]
+#[unit(symbol = "ms", dimension = Time, ratio = 1e-3, exact_ratio = 1 / 1_000, system = "SI")]
 pub struct Millisecond;
 /// A quantity measured in milliseconds.
 pub type Milliseconds = Quantity<Millisecond>;
@@ -136,7 +148,7 @@ pub const MILLISEC: Milliseconds = Milliseconds::new(1.0);
 
 /// Centiseconds (`1 cs = 10^-2 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "cs", dimension = Time, ratio = 1e-2)]
+#[unit(symbol = "cs", dimension = Time, ratio = 1e-2, exact_ratio = 1 / 100, system = "SI")]
 pub struct Centisecond;
 /// A quantity measured in centiseconds.
 pub type Centiseconds = Quantity<Centisecond>;
@@ -145,7 +157,7 @@ pub const CENTISEC: Centiseconds = Centiseconds::new(1.0);
 
 /// Deciseconds (`1 ds = 10^-1 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ds", dimension = Time, ratio = 1e-1)]
+#[unit(symbol = "ds", dimension = Time, ratio = 1e-1, exact_ratio = 1 / 10, system = "SI")]
 pub struct Decisecond;
 /// A quantity measured in deciseconds.
 pub type Deciseconds = Quantity<Decisecond>;
@@ -154,7 +166,10 @@ pub const DECISEC: Deciseconds = Deciseconds::new(1.0);
 
 /// Seconds (SI base unit).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "s", dimension = Time, ratio = 1.0)]
+#[unit(
+    symbol = "s", dimension = Time, ratio = 1.0, exact_ratio = 1,
+    system = "SI", aliases = ["sec", "secs"],
+)]
 pub struct Second;
 /// A quantity measured in seconds.
 pub type Seconds = Quantity<Second>;
@@ -165,7 +180,7 @@ pub const SEC: Seconds = Seconds::new(1.0);
 
 /// Decaseconds (`1 das = 10 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "das", dimension = Time, ratio = 10.0)]
+#[unit(symbol = "das", dimension = Time, ratio = 10.0, exact_ratio = 10, system = "SI")]
 pub struct Decasecond;
 /// A quantity measured in decaseconds.
 pub type Decaseconds = Quantity<Decasecond>;
@@ -174,7 +189,7 @@ pub const DECASEC: Decaseconds = Decaseconds::new(1.0);
 
 /// Hectoseconds (`1 hs = 100 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "hs", dimension = Time, ratio = 100.0)]
+#[unit(symbol = "hs", dimension = Time, ratio = 100.0, exact_ratio = 100, system = "SI")]
 pub struct Hectosecond;
 /// A quantity measured in hectoseconds.
 pub type Hectoseconds = Quantity<Hectosecond>;
@@ -183,7 +198,7 @@ pub const HECTOSEC: Hectoseconds = Hectoseconds::new(1.0);
 
 /// Kiloseconds (`1 ks = 1_000 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ks", dimension = Time, ratio = 1_000.0)]
+#[unit(symbol = "ks", dimension = Time, ratio = 1_000.0, exact_ratio = 1_000, system = "SI")]
 pub struct Kilosecond;
 /// A quantity measured in kiloseconds.
 pub type Kiloseconds = Quantity<Kilosecond>;
@@ -192,7 +207,7 @@ pub const KILOSEC: Kiloseconds = Kiloseconds::new(1.0);
 
 /// Megaseconds (`1 Ms = 10^6 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Ms", dimension = Time, ratio = 1e6)]
+#[unit(symbol = "Ms", dimension = Time, ratio = 1e6, exact_ratio = 1_000_000, system = "SI")]
 pub struct Megasecond;
 /// A quantity measured in megaseconds.
 pub type Megaseconds = Quantity<Megasecond>;
@@ -201,7 +216,7 @@ pub const MEGASEC: Megaseconds = Megaseconds::new(1.0);
 
 /// Gigaseconds (`1 Gs = 10^9 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Gs", dimension = Time, ratio = 1e9)]
+#[unit(symbol = "Gs", dimension = Time, ratio = 1e9, exact_ratio = 1_000_000_000, system = "SI")]
 pub struct Gigasecond;
 /// A quantity measured in gigaseconds.
 pub type Gigaseconds = Quantity<Gigasecond>;
@@ -210,7 +225,7 @@ pub const GIGASEC: Gigaseconds = Gigaseconds::new(1.0);
 
 /// Teraseconds (`1 Ts = 10^12 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "Ts", dimension = Time, ratio = 1e12)]
+#[unit(symbol = "Ts", dimension = Time, ratio = 1e12, exact_ratio = 10_i128.pow(12), system = "SI")]
 pub struct Terasecond;
 /// A quantity measured in teraseconds.
 pub type Teraseconds = Quantity<Terasecond>;
@@ -221,7 +236,10 @@ pub const TERASEC: Teraseconds = Teraseconds::new(1.0);
 
 /// Minutes (`60 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "min", dimension = Time, ratio = 60.0)]
+#[unit(
+    symbol = "min", dimension = Time, ratio = 60.0, exact_ratio = 60,
+    system = "SI-accepted", aliases = ["mins"],
+)]
 pub struct Minute;
 /// A quantity measured in minutes.
 pub type Minutes = Quantity<Minute>;
@@ -230,7 +248,10 @@ pub const MIN: Minutes = Minutes::new(1.0);
 
 /// Hours (`3_600 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "h", dimension = Time, ratio = 3_600.0)]
+#[unit(
+    symbol = "h", dimension = Time, ratio = 3_600.0, exact_ratio = 3_600,
+    system = "SI-accepted", aliases = ["hr", "hrs"],
+)]
 pub struct Hour;
 /// A quantity measured in hours.
 pub type Hours = Quantity<Hour>;
@@ -239,7 +260,10 @@ pub const HOUR: Hours = Hours::new(1.0);
 
 /// Mean solar day (`86_400 s` by convention; leap seconds ignored).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "d", dimension = Time, ratio = SECONDS_PER_DAY)]
+#[unit(
+    symbol = "d", dimension = Time, ratio = SECONDS_PER_DAY, exact_ratio = 86_400,
+    system = "SI-accepted",
+)]
 pub struct Day;
 /// A quantity measured in days.
 pub type Days = Quantity<Day>;
@@ -248,7 +272,10 @@ pub const DAY: Days = Days::new(1.0);
 
 /// Week (`7 d = 604_800 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "wk", dimension = Time, ratio = 7.0 * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "wk", dimension = Time, ratio = 7.0 * SECONDS_PER_DAY, exact_ratio = 7 * 86_400,
+    system = "calendar",
+)]
 pub struct Week;
 /// A quantity measured in weeks.
 pub type Weeks = Quantity<Week>;
@@ -257,7 +284,10 @@ pub const WEEK: Weeks = Weeks::new(1.0);
 
 /// Fortnight (`14 d = 1_209_600 s`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "fn", dimension = Time, ratio = 14.0 * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "fn", dimension = Time, ratio = 14.0 * SECONDS_PER_DAY, exact_ratio = 14 * 86_400,
+    system = "calendar",
+)]
 pub struct Fortnight;
 /// A quantity measured in fortnights.
 pub type Fortnights = Quantity<Fortnight>;
@@ -271,7 +301,13 @@ pub const FORTNIGHT: Fortnights = Fortnights::new(1.0);
 /// astronomical mean tropical year (≈ 365.242 19 d).  The two differ by about
 /// 27 seconds per year.
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "yr", dimension = Time, ratio = DAYS_PER_GREGORIAN_YEAR * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "yr",
+    dimension = Time,
+    ratio = DAYS_PER_GREGORIAN_YEAR * SECONDS_PER_DAY,
+    exact_ratio = 3_652_425 * 86_400 / 10_000,
+    system = "calendar",
+)]
 pub struct Year;
 /// A quantity measured in years.
 pub type Years = Quantity<Year>;
@@ -280,7 +316,13 @@ pub const YEAR: Years = Years::new(1.0);
 
 /// Decade (`10` mean Gregorian years).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "dec", dimension = Time, ratio = 10.0 * DAYS_PER_GREGORIAN_YEAR * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "dec",
+    dimension = Time,
+    ratio = 10.0 * DAYS_PER_GREGORIAN_YEAR * SECONDS_PER_DAY,
+    exact_ratio = 3_652_425 * 86_400 / 1_000,
+    system = "calendar",
+)]
 pub struct Decade;
 /// A quantity measured in decades.
 pub type Decades = Quantity<Decade>;
@@ -289,7 +331,13 @@ pub const DECADE: Decades = Decades::new(1.0);
 
 /// Century (`100` mean Gregorian years).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "c", dimension = Time, ratio = 100.0 * DAYS_PER_GREGORIAN_YEAR * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "c",
+    dimension = Time,
+    ratio = 100.0 * DAYS_PER_GREGORIAN_YEAR * SECONDS_PER_DAY,
+    exact_ratio = 3_652_425 * 86_400 / 100,
+    system = "calendar",
+)]
 pub struct Century;
 /// A quantity measured in centuries.
 pub type Centuries = Quantity<Century>;
@@ -298,7 +346,13 @@ pub const CENTURY: Centuries = Centuries::new(1.0);
 
 /// Millennium (`1000` mean Gregorian years).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mill", dimension = Time, ratio = 1000.0 * DAYS_PER_GREGORIAN_YEAR * SECONDS_PER_DAY)]
+#[unit(
+    symbol = "mill",
+    dimension = Time,
+    ratio = 1000.0 * DAYS_PER_GREGORIAN_YEAR * SECONDS_PER_DAY,
+    exact_ratio = 3_652_425 * 86_400 / 10,
+    plural = "millennia", system = "calendar",
+)]
 pub struct Millennium;
 /// A quantity measured in millennia.
 pub type Millennia = Quantity<Millennium>;
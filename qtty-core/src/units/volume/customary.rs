@@ -10,28 +10,52 @@ use qtty_derive::Unit;
 
 /// Cubic inch (`1.6387064e-5 m³`, exact: `0.0254³ m³`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "in³", dimension = Volume, ratio = 1.638_706_4e-5)]
+#[unit(
+    symbol = "in³",
+    dimension = Volume,
+    ratio = 1.638_706_4e-5,
+    exact_ratio = 16_387_064 / 10_i128.pow(12),
+    system = "customary",
+)]
 pub struct CubicInch;
 /// A quantity measured in cubic inches.
 pub type CubicInches = Quantity<CubicInch>;
 
 /// Cubic foot (`0.028316846592 m³`, exact: `0.3048³ m³`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "ft³", dimension = Volume, ratio = 0.028_316_846_592)]
+#[unit(
+    symbol = "ft³",
+    dimension = Volume,
+    ratio = 0.028_316_846_592,
+    exact_ratio = 28_316_846_592 / 10_i128.pow(12),
+    plural = "cubic feet", system = "customary",
+)]
 pub struct CubicFoot;
 /// A quantity measured in cubic feet.
 pub type CubicFeet = Quantity<CubicFoot>;
 
 /// US liquid gallon (`0.003785411784 m³`, exact: `231 in³`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "gal", dimension = Volume, ratio = 0.003_785_411_784)]
+#[unit(
+    symbol = "gal",
+    dimension = Volume,
+    ratio = 0.003_785_411_784,
+    exact_ratio = 3_785_411_784 / 10_i128.pow(12),
+    long_name = "US gallon", system = "customary",
+)]
 pub struct UsGallon;
 /// A quantity measured in US gallons.
 pub type UsGallons = Quantity<UsGallon>;
 
 /// US fluid ounce (`2.95735295625e-5 m³`, exact: `gal / 128`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "fl oz", dimension = Volume, ratio = 2.957_352_956_25e-5)]
+#[unit(
+    symbol = "fl oz",
+    dimension = Volume,
+    ratio = 2.957_352_956_25e-5,
+    exact_ratio = 295_735_295_625 / 10_i128.pow(16),
+    long_name = "US fluid ounce", system = "customary",
+)]
 pub struct UsFluidOunce;
 /// A quantity measured in US fluid ounces.
 pub type UsFluidOunces = Quantity<UsFluidOunce>;
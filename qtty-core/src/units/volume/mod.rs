@@ -85,28 +85,44 @@ pub use customary::*;
 
 /// Cubic metre (SI derived unit of volume).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "m³", dimension = Volume, ratio = 1.0)]
+#[unit(
+    symbol = "m³", dimension = Volume, ratio = 1.0, exact_ratio = 1,
+    long_name = "cubic metre", system = "SI", aliases = ["cubic meter", "cubic meters"],
+)]
 pub struct CubicMeter;
 /// A quantity measured in cubic metres.
 pub type CubicMeters = Quantity<CubicMeter>;
 
 /// Cubic kilometre (`1e9 m³`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "km³", dimension = Volume, ratio = 1e9)]
+#[unit(
+    symbol = "km³", dimension = Volume, ratio = 1e9, exact_ratio = 1_000_000_000,
+    long_name = "cubic kilometre", system = "SI", aliases = ["cubic kilometer", "cubic kilometers"],
+)]
 pub struct CubicKilometer;
 /// A quantity measured in cubic kilometres.
 pub type CubicKilometers = Quantity<CubicKilometer>;
 
 /// Cubic centimetre (`1e-6 m³`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "cm³", dimension = Volume, ratio = 1e-6)]
+#[unit(
+    symbol = "cm³", dimension = Volume, ratio = 1e-6, exact_ratio = 1 / 1_000_000,
+    long_name = "cubic centimetre",
+    system = "SI",
+    aliases = ["cubic centimeter", "cubic centimeters"],
+)]
 pub struct CubicCentimeter;
 /// A quantity measured in cubic centimetres.
 pub type CubicCentimeters = Quantity<CubicCentimeter>;
 
 /// Cubic millimetre (`1e-9 m³`).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mm³", dimension = Volume, ratio = 1e-9)]
+#[unit(
+    symbol = "mm³", dimension = Volume, ratio = 1e-9, exact_ratio = 1 / 1_000_000_000,
+    long_name = "cubic millimetre",
+    system = "SI",
+    aliases = ["cubic millimeter", "cubic millimeters"],
+)]
 pub struct CubicMillimeter;
 /// A quantity measured in cubic millimetres.
 pub type CubicMillimeters = Quantity<CubicMillimeter>;
@@ -117,35 +133,50 @@ pub type CubicMillimeters = Quantity<CubicMillimeter>;
 
 /// Litre (`1e-3 m³`, exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "L", dimension = Volume, ratio = 1e-3)]
+#[unit(
+    symbol = "L", dimension = Volume, ratio = 1e-3, exact_ratio = 1 / 1_000,
+    long_name = "litre", system = "SI-accepted", aliases = ["liter", "liters"],
+)]
 pub struct Liter;
 /// A quantity measured in litres.
 pub type Liters = Quantity<Liter>;
 
 /// Millilitre (`1e-6 m³`, exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "mL", dimension = Volume, ratio = 1e-6)]
+#[unit(
+    symbol = "mL", dimension = Volume, ratio = 1e-6, exact_ratio = 1 / 1_000_000,
+    long_name = "millilitre", system = "SI-accepted", aliases = ["milliliter", "milliliters"],
+)]
 pub struct Milliliter;
 /// A quantity measured in millilitres.
 pub type Milliliters = Quantity<Milliliter>;
 
 /// Microlitre (`1e-9 m³`, exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "µL", dimension = Volume, ratio = 1e-9)]
+#[unit(
+    symbol = "µL", dimension = Volume, ratio = 1e-9, exact_ratio = 1 / 1_000_000_000,
+    long_name = "microlitre", system = "SI-accepted", aliases = ["microliter", "microliters"],
+)]
 pub struct Microliter;
 /// A quantity measured in microlitres.
 pub type Microliters = Quantity<Microliter>;
 
 /// Centilitre (`1e-5 m³`, exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "cL", dimension = Volume, ratio = 1e-5)]
+#[unit(
+    symbol = "cL", dimension = Volume, ratio = 1e-5, exact_ratio = 1 / 100_000,
+    long_name = "centilitre", system = "SI-accepted", aliases = ["centiliter", "centiliters"],
+)]
 pub struct Centiliter;
 /// A quantity measured in centilitres.
 pub type Centiliters = Quantity<Centiliter>;
 
 /// Decilitre (`1e-4 m³`, exact).
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Unit)]
-#[unit(symbol = "dL", dimension = Volume, ratio = 1e-4)]
+#[unit(
+    symbol = "dL", dimension = Volume, ratio = 1e-4, exact_ratio = 1 / 10_000,
+    long_name = "decilitre", system = "SI-accepted", aliases = ["deciliter", "deciliters"],
+)]
 pub struct Deciliter;
 /// A quantity measured in decilitres.
 pub type Deciliters = Quantity<Deciliter>;
@@ -190,6 +221,33 @@ volume_units!(crate::impl_unit_from_conversions);
 #[cfg(feature = "cross-unit-ops")]
 volume_units!(crate::impl_unit_cross_unit_ops);
 
+// Named cubes of a length unit recover that length through `cbrt`/`root::<P3>()`.
+macro_rules! impl_cube_roots {
+    ($($cube:ty => $side:ty),+ $(,)?) => {
+        $(
+            impl crate::unit_arithmetic::UnitRoot<typenum::P3> for $cube {
+                type Root = $side;
+            }
+        )+
+    };
+}
+
+impl_cube_roots!(
+    CubicMeter => crate::length::Meter,
+    CubicKilometer => crate::length::Kilometer,
+    CubicCentimeter => crate::length::Centimeter,
+    CubicMillimeter => crate::length::Millimeter,
+    Liter => crate::length::Decimeter,
+    Milliliter => crate::length::Centimeter,
+    Microliter => crate::length::Millimeter,
+);
+
+#[cfg(feature = "customary")]
+impl_cube_roots!(
+    CubicInch => crate::length::Inch,
+    CubicFoot => crate::length::Foot,
+);
+
 // Compile-time check: every unit in the inventory is registered as BuiltinUnit.
 #[cfg(test)]
 volume_units!(crate::assert_units_are_builtin);
@@ -236,6 +294,25 @@ mod tests {
         assert_abs_diff_eq!(l.value(), 28.316_846_592, epsilon = 1e-6);
     }
 
+    #[test]
+    fn cbrt_recovers_side_length() {
+        use crate::length::{Decimeter, Meter, Meters};
+
+        let side: Quantity<Meter> = CubicMeters::new(8.0).cbrt();
+        assert_abs_diff_eq!(side.value(), 2.0, epsilon = 1e-12);
+
+        let side: Quantity<Decimeter> = Liters::new(-27.0).cbrt();
+        assert_abs_diff_eq!(side.value(), -3.0, epsilon = 1e-12);
+
+        // Cubes built by multiplication or `powi` round-trip the same way.
+        let s = Meters::new(1.5);
+        let r: Meters = (s * s * s).cbrt();
+        assert_abs_diff_eq!(r.value(), 1.5, epsilon = 1e-12);
+        let cube = s.powi::<typenum::P3>();
+        let as_named: CubicMeters = cube.to();
+        assert_abs_diff_eq!(as_named.value(), 3.375, epsilon = 1e-12);
+    }
+
     #[test]
     fn length_times_area_to_volume() {
         use crate::area::{SquareMeter, SquareMeters};
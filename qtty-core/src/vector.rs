@@ -0,0 +1,395 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Two- and three-dimensional vectors of quantities.
+//!
+//! [`QuantityVector3<U, S>`] and [`QuantityVector2<U, S>`] store one
+//! [`Quantity`] per component, all in the same unit `U`. Component-wise
+//! operators keep the unit. Dot and cross products go through [`UnitMul`],
+//! so their results have the same units as `Quantity * Quantity`.
+//!
+//! ```rust
+//! use qtty_core::length::{Kilometer, Meters};
+//! use qtty_core::force::Newtons;
+//! use qtty_core::QuantityVector3;
+//!
+//! let r = QuantityVector3::new(Meters::new(3.0), Meters::new(0.0), Meters::new(4.0));
+//! assert_eq!(r.norm(), Meters::new(5.0));
+//! assert_eq!(r.to::<Kilometer>().x.value(), 0.003);
+//!
+//! let f = QuantityVector3::new(Newtons::new(0.0), Newtons::new(2.0), Newtons::new(0.0));
+//! let torque = r.cross(f);
+//! assert_eq!(torque.x.value(), -8.0);
+//! assert_eq!(r.dot(f).value(), 0.0);
+//! ```
+
+use crate::scalar::Real;
+use crate::unit::{write_unit_suffix, Per};
+use crate::unit_arithmetic::UnitMul;
+use crate::{Quantity, Unit};
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A three-dimensional vector whose components share the unit `U`.
+///
+/// ```rust
+/// use qtty_core::length::Meters;
+/// use qtty_core::QuantityVector3;
+///
+/// let a = QuantityVector3::new(Meters::new(1.0), Meters::new(2.0), Meters::new(2.0));
+/// let unit = a.normalize();
+/// assert!((unit.norm().value() - 1.0).abs() < 1e-12);
+/// assert_eq!(format!("{:.1}", a * 2.0), "(2.0, 4.0, 4.0) m");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuantityVector3<U: Unit, S: Real = f64> {
+    /// The x component.
+    pub x: Quantity<U, S>,
+    /// The y component.
+    pub y: Quantity<U, S>,
+    /// The z component.
+    pub z: Quantity<U, S>,
+}
+
+/// A two-dimensional vector whose components share the unit `U`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuantityVector2<U: Unit, S: Real = f64> {
+    /// The x component.
+    pub x: Quantity<U, S>,
+    /// The y component.
+    pub y: Quantity<U, S>,
+}
+
+impl<U: Unit, S: Real> QuantityVector3<U, S> {
+    /// Creates a vector from its components.
+    #[inline]
+    pub const fn new(x: Quantity<U, S>, y: Quantity<U, S>, z: Quantity<U, S>) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Cross product. The components have the same unit as `Quantity<U> *
+    /// Quantity<V>`.
+    #[inline]
+    pub fn cross<V: Unit>(
+        self,
+        rhs: QuantityVector3<V, S>,
+    ) -> QuantityVector3<<U as UnitMul<V>>::Output, S>
+    where
+        U: UnitMul<V>,
+    {
+        let [ax, ay, az] = self.values();
+        let [bx, by, bz] = rhs.values();
+        QuantityVector3::from_values([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+impl<U: Unit, S: Real> QuantityVector2<U, S> {
+    /// Creates a vector from its components.
+    #[inline]
+    pub const fn new(x: Quantity<U, S>, y: Quantity<U, S>) -> Self {
+        Self { x, y }
+    }
+
+    /// The z component of the cross product of the two vectors extended with
+    /// `z = 0` (also called the perp-dot product).
+    #[inline]
+    pub fn cross<V: Unit>(
+        self,
+        rhs: QuantityVector2<V, S>,
+    ) -> Quantity<<U as UnitMul<V>>::Output, S>
+    where
+        U: UnitMul<V>,
+    {
+        let [ax, ay] = self.values();
+        let [bx, by] = rhs.values();
+        Quantity::new(ax * by - ay * bx)
+    }
+}
+
+/// Implements the operations shared by every vector type.
+macro_rules! impl_quantity_vector {
+    ($vector:ident, $n:literal, $($field:ident),+) => {
+        impl<U: Unit, S: Real> $vector<U, S> {
+            /// The zero vector.
+            #[inline]
+            pub fn zero() -> Self {
+                Self { $($field: Quantity::new(S::ZERO)),+ }
+            }
+
+            /// Creates a vector from raw component values in unit `U`.
+            #[inline]
+            pub fn from_values([$($field),+]: [S; $n]) -> Self {
+                Self { $($field: Quantity::new($field)),+ }
+            }
+
+            /// The raw component values in unit `U`.
+            #[inline]
+            pub fn values(self) -> [S; $n] {
+                [$(self.$field.value()),+]
+            }
+
+            /// The components as an array of quantities.
+            #[inline]
+            pub fn to_array(self) -> [Quantity<U, S>; $n] {
+                [$(self.$field),+]
+            }
+
+            /// Dot product, in the unit of `Quantity<U> * Quantity<V>`.
+            #[inline]
+            pub fn dot<V: Unit>(self, rhs: $vector<V, S>) -> Quantity<<U as UnitMul<V>>::Output, S>
+            where
+                U: UnitMul<V>,
+            {
+                Quantity::new(S::ZERO $(+ self.$field.value() * rhs.$field.value())+)
+            }
+
+            /// Euclidean length, in unit `U`.
+            ///
+            /// Computed with repeated `hypot`, so it does not overflow or
+            /// underflow for large or tiny components.
+            #[inline]
+            pub fn norm(self) -> Quantity<U, S> {
+                Quantity::new(S::ZERO $(.hypot(self.$field.value()))+)
+            }
+
+            /// The dimensionless unit vector in the direction of `self`.
+            ///
+            /// The zero vector has no direction; its components come out NaN.
+            #[inline]
+            pub fn normalize(self) -> $vector<Per<U, U>, S>
+            where
+                Per<U, U>: Unit,
+            {
+                let norm = self.norm().value();
+                $vector { $($field: Quantity::new(self.$field.value() / norm)),+ }
+            }
+
+            /// Converts every component to unit `T`.
+            #[inline]
+            pub fn to<T: Unit<Dim = U::Dim>>(self) -> $vector<T, S> {
+                $vector { $($field: self.$field.to()),+ }
+            }
+        }
+
+        impl<U: Unit, S: Real> From<[Quantity<U, S>; $n]> for $vector<U, S> {
+            #[inline]
+            fn from([$($field),+]: [Quantity<U, S>; $n]) -> Self {
+                Self { $($field),+ }
+            }
+        }
+
+        impl<U: Unit, S: Real> From<$vector<U, S>> for [Quantity<U, S>; $n] {
+            #[inline]
+            fn from(vector: $vector<U, S>) -> Self {
+                vector.to_array()
+            }
+        }
+
+        impl<U: Unit, S: Real> Add for $vector<U, S> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<U: Unit, S: Real> AddAssign for $vector<U, S> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<U: Unit, S: Real> Sub for $vector<U, S> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<U: Unit, S: Real> SubAssign for $vector<U, S> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<U: Unit, S: Real> Neg for $vector<U, S> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<U: Unit, S: Real> Mul<S> for $vector<U, S> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: S) -> Self {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<U: Unit, S: Real> MulAssign<S> for $vector<U, S> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: S) {
+                $(self.$field *= rhs;)+
+            }
+        }
+
+        impl<U: Unit, S: Real> Div<S> for $vector<U, S> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: S) -> Self {
+                Self { $($field: self.$field / rhs),+ }
+            }
+        }
+
+        impl<U: Unit, S: Real> DivAssign<S> for $vector<U, S> {
+            #[inline]
+            fn div_assign(&mut self, rhs: S) {
+                $(self.$field /= rhs;)+
+            }
+        }
+
+        /// Prints `(1, 2, 3) m`. Format flags apply to every component.
+        impl<U: Unit, S: Real> Display for $vector<U, S> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("(")?;
+                for (i, value) in self.values().iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    Display::fmt(value, f)?;
+                }
+                f.write_str(")")?;
+                write_unit_suffix::<U>(f)
+            }
+        }
+    };
+}
+
+impl_quantity_vector!(QuantityVector2, 2, x, y);
+impl_quantity_vector!(QuantityVector3, 3, x, y, z);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::force::Newton;
+    use crate::length::{Kilometer, Kilometers, Meter, Meters};
+    use crate::time::{Second, Seconds};
+    use crate::Prod;
+    use approx::assert_relative_eq;
+
+    fn v3(x: f64, y: f64, z: f64) -> QuantityVector3<Meter> {
+        QuantityVector3::from_values([x, y, z])
+    }
+
+    // ── Component-wise arithmetic ──
+
+    #[test]
+    fn componentwise_operators() {
+        let mut a = v3(1.0, 2.0, 3.0);
+        let b = v3(0.5, 0.5, 0.5);
+        assert_eq!(a + b, v3(1.5, 2.5, 3.5));
+        assert_eq!(a - b, v3(0.5, 1.5, 2.5));
+        assert_eq!(-a, v3(-1.0, -2.0, -3.0));
+        assert_eq!(a * 2.0, v3(2.0, 4.0, 6.0));
+        assert_eq!(a / 2.0, v3(0.5, 1.0, 1.5));
+        a += b;
+        a -= b * 2.0;
+        a *= 2.0;
+        a /= 4.0;
+        assert_eq!(a, v3(0.25, 0.75, 1.25));
+        assert_eq!(QuantityVector3::<Meter>::zero().values(), [0.0; 3]);
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let a = v3(1.0, 2.0, 3.0);
+        let array: [Meters; 3] = a.into();
+        assert_eq!(
+            array,
+            [Meters::new(1.0), Meters::new(2.0), Meters::new(3.0)]
+        );
+        assert_eq!(QuantityVector3::from(array), a);
+    }
+
+    // ── Products and norms ──
+
+    #[test]
+    fn dot_and_cross_use_product_units() {
+        let r = v3(1.0, 0.0, 0.0);
+        let f = QuantityVector3::<Newton>::from_values([0.0, 2.0, 0.0]);
+        let torque = r.cross(f);
+        let _: &QuantityVector3<Prod<Meter, Newton>> = &torque;
+        assert_eq!(torque.values(), [0.0, 0.0, 2.0]);
+
+        let a = v3(1.0, 2.0, 3.0);
+        let area: Quantity<Prod<Meter, Meter>> = a.dot(v3(4.0, -5.0, 6.0));
+        assert_eq!(area.value(), 12.0);
+
+        let t = QuantityVector3::new(Seconds::new(1.0), Seconds::new(1.0), Seconds::new(1.0));
+        let _: Quantity<Prod<Meter, Second>> = a.dot(t);
+    }
+
+    #[test]
+    fn cross_is_orthogonal() {
+        let a = v3(1.0, 2.0, 3.0);
+        let b = v3(-2.0, 0.5, 4.0);
+        let c = a.cross(b);
+        assert_relative_eq!(c.dot(a).value(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(c.dot(b).value(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn norm_and_normalize() {
+        assert_eq!(v3(2.0, 3.0, 6.0).norm(), Meters::new(7.0));
+        let big = v3(1e300, 1e300, 0.0);
+        assert_relative_eq!(big.norm().value(), 1e300 * 2f64.sqrt());
+        let unit = v3(0.0, 3.0, 4.0).normalize();
+        let _: &QuantityVector3<Per<Meter, Meter>> = &unit;
+        assert_relative_eq!(unit.y.value(), 0.6);
+        assert_relative_eq!(unit.z.value(), 0.8);
+        assert!(QuantityVector3::<Meter>::zero().normalize().x.is_nan());
+    }
+
+    #[test]
+    fn conversion_scales_all_components() {
+        let km = QuantityVector3::new(
+            Kilometers::new(1.0),
+            Kilometers::new(2.0),
+            Kilometers::new(3.0),
+        );
+        assert_eq!(km.to::<Meter>(), v3(1000.0, 2000.0, 3000.0));
+        assert_eq!(km.to::<Meter>().to::<Kilometer>(), km);
+    }
+
+    // ── 2D ──
+
+    #[test]
+    fn vector2_operations() {
+        let a = QuantityVector2::<Meter>::from_values([3.0, 4.0]);
+        let b = QuantityVector2::<Meter>::from_values([-4.0, 3.0]);
+        assert_eq!(a.norm(), Meters::new(5.0));
+        assert_eq!(a.dot(b).value(), 0.0);
+        let z: Quantity<Prod<Meter, Meter>> = a.cross(b);
+        assert_eq!(z.value(), 25.0);
+        assert_eq!((a + b).values(), [-1.0, 7.0]);
+        assert_relative_eq!(a.normalize().x.value(), 0.6);
+        assert_eq!(a.to_string(), "(3, 4) m");
+    }
+
+    #[test]
+    fn display_formats() {
+        assert_eq!(v3(1.0, 2.5, -3.0).to_string(), "(1, 2.5, -3) m");
+        assert_eq!(format!("{:.2}", v3(1.0, 2.0, 3.0)), "(1.00, 2.00, 3.00) m");
+        assert_eq!(v3(0.0, 3.0, 4.0).normalize().to_string(), "(0, 0.6, 0.8)");
+    }
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "approx")]
+
+use approx::{
+    abs_diff_eq, abs_diff_ne, assert_abs_diff_eq, assert_relative_eq, assert_relative_ne,
+    assert_ulps_eq, relative_eq,
+};
+use qtty_core::angular::Radians;
+use qtty_core::length::{Meter, Meters};
+use qtty_core::Quantity32;
+
+#[test]
+fn abs_diff_eq_uses_epsilon_in_the_quantity_unit() {
+    assert_abs_diff_eq!(Meters::new(1.0), Meters::new(1.05), epsilon = 0.1);
+    assert!(abs_diff_ne!(
+        Meters::new(1.0),
+        Meters::new(1.2),
+        epsilon = 0.1
+    ));
+    assert!(abs_diff_eq!(
+        Meters::new(f64::MIN_POSITIVE),
+        Meters::new(0.0)
+    ));
+}
+
+#[test]
+fn relative_eq_absorbs_rounding() {
+    assert_relative_eq!(Meters::new(0.1 + 0.2), Meters::new(0.3));
+    assert_relative_eq!(
+        Meters::new(1e9),
+        Meters::new(1e9 + 1.0),
+        max_relative = 1e-8
+    );
+    assert_relative_ne!(
+        Meters::new(1e9),
+        Meters::new(1e9 + 1.0),
+        max_relative = 1e-12
+    );
+    assert!(!relative_eq!(Meters::new(f64::NAN), Meters::new(f64::NAN)));
+}
+
+#[test]
+fn ulps_eq_and_f32_scalars() {
+    let third = Radians::new(1.0 / 3.0);
+    assert_ulps_eq!(third * 3.0, Radians::new(1.0));
+    assert_relative_eq!(
+        Quantity32::<Meter>::new(0.1_f32 + 0.2),
+        Quantity32::<Meter>::new(0.3)
+    );
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use qtty_core::length::{Meter, Meters};
+use qtty_core::time::Second;
+use qtty_core::Quantity;
+
+#[test]
+fn quantities_decode_like_their_scalar() {
+    let bytes = 2.5_f64.to_le_bytes();
+    let mut raw = Unstructured::new(&bytes);
+    let mut typed = Unstructured::new(&bytes);
+    let expected = f64::arbitrary(&mut raw).unwrap();
+    assert_eq!(
+        Meters::arbitrary(&mut typed).unwrap(),
+        Meters::new(expected)
+    );
+    assert_eq!(
+        <Quantity<Second, i32>>::size_hint(0),
+        <i32 as Arbitrary>::size_hint(0)
+    );
+}
+
+#[test]
+fn quantities_compose_inside_tuples_and_vecs() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&bytes);
+    let (a, b): (Quantity<Meter, i16>, Quantity<Second, i8>) =
+        Arbitrary::arbitrary(&mut u).unwrap();
+    let _ = (a.value(), b.value());
+    let many: Vec<Quantity<Meter, i64>> = Arbitrary::arbitrary(&mut u).unwrap();
+    assert!(many.len() <= bytes.len());
+}
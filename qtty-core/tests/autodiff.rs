@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "autodiff")]
+
+use approx::assert_relative_eq;
+use qtty_core::angular::{Degree, Degrees, Radian};
+use qtty_core::area::SquareMeter;
+use qtty_core::autodiff::{self, Dual, Dual1};
+use qtty_core::length::{Kilometer, Kilometers, Meter, Meters};
+use qtty_core::scalar::{Real, Scalar, Transcendental};
+use qtty_core::time::{Second, Seconds};
+use qtty_core::{Per, Prod, Quantity};
+
+/// Central finite difference of a plain `f64` function.
+fn numeric(f: impl Fn(f64) -> f64, x: f64) -> f64 {
+    let h = 1e-6 * x.abs().max(1.0);
+    (f(x + h) - f(x - h)) / (2.0 * h)
+}
+
+/// A dual function, its `f64` counterpart and the evaluation point.
+type Case = (fn(Dual1) -> Dual1, fn(f64) -> f64, f64);
+
+// ── Dual arithmetic ──
+
+#[test]
+fn arithmetic_follows_the_usual_rules() {
+    let x = Dual1::variable(3.0, 0);
+    let c = Dual1::constant(2.0);
+    assert_eq!((x + c).derivative(0), 1.0);
+    assert_eq!((x - c).derivative(0), 1.0);
+    assert_eq!((x * x).derivative(0), 6.0);
+    assert_eq!((c / x).derivative(0), -2.0 / 9.0);
+    assert_eq!((-x).derivative(0), -1.0);
+    assert_eq!((x % c).derivative(0), 1.0);
+    assert_eq!(Scalar::rem_euclid(-x, c).value(), 1.0);
+    assert_eq!(Scalar::rem_euclid(-x, c).derivative(0), -1.0);
+
+    let mut y = x;
+    y *= x;
+    y += c;
+    assert_eq!((y.value(), y.derivative(0)), (11.0, 6.0));
+}
+
+#[test]
+fn constant_zero_keeps_zero_derivatives() {
+    let zero = Dual1::constant(0.0);
+    assert_eq!(Real::sqrt(zero).derivative(0), 0.0);
+    assert_eq!(Real::cbrt(zero).derivative(0), 0.0);
+    assert_eq!(Real::powf(zero, Dual1::constant(0.5)).derivative(0), 0.0);
+    assert_eq!(Real::ln(zero).derivative(0), 0.0);
+
+    // A constant zero term must not poison the gradient of the variable.
+    let x = Dual1::variable(4.0, 0);
+    assert_eq!((Real::sqrt(zero) + x * x).derivative(0), 8.0);
+    assert_eq!((Real::sqrt(zero) * x).derivative(0), 0.0);
+
+    // A variable at zero still has the infinite slope of `sqrt`.
+    assert!(Real::sqrt(Dual1::variable(0.0, 0))
+        .derivative(0)
+        .is_infinite());
+}
+
+#[test]
+fn comparisons_use_the_value_only() {
+    let a = Dual::<2>::variable(1.0, 0);
+    let b = Dual::<2>::variable(1.0, 1);
+    assert_eq!(a, b);
+    assert!(Dual::<2>::constant(0.5) < a);
+    assert_eq!(
+        Scalar::max(a, Dual::constant(2.0)).derivatives(),
+        &[0.0, 0.0]
+    );
+    assert_eq!(Scalar::abs(-a).derivatives(), &[1.0, 0.0]);
+    assert_eq!(a.to_string(), "1");
+}
+
+#[test]
+fn elementary_functions_match_finite_differences() {
+    let cases: [Case; 18] = [
+        (Real::sqrt, f64::sqrt, 2.0),
+        (Real::cbrt, f64::cbrt, 2.0),
+        (Real::ln, f64::ln, 2.0),
+        (Real::log10, f64::log10, 2.0),
+        (Real::log2, f64::log2, 2.0),
+        (Real::exp, f64::exp, 0.7),
+        (Real::exp2, f64::exp2, 0.7),
+        (|x| Real::powi(x, 3), |x| x.powi(3), 1.5),
+        (
+            |x| Real::powf(x, Dual1::constant(2.5)),
+            |x| x.powf(2.5),
+            1.5,
+        ),
+        (
+            |x| Real::powf(Dual1::constant(2.0), x),
+            |x| 2.0_f64.powf(x),
+            1.5,
+        ),
+        (Transcendental::sin, f64::sin, 0.4),
+        (Transcendental::cos, f64::cos, 0.4),
+        (Transcendental::tan, f64::tan, 0.4),
+        (Transcendental::asin, f64::asin, 0.4),
+        (Transcendental::acos, f64::acos, 0.4),
+        (Transcendental::atan, f64::atan, 0.4),
+        (Transcendental::tanh, f64::tanh, 0.4),
+        (Transcendental::acosh, f64::acosh, 1.4),
+    ];
+    for (dual, plain, x) in cases {
+        let result = dual(Dual1::variable(x, 0));
+        assert_relative_eq!(result.value(), plain(x));
+        assert_relative_eq!(result.derivative(0), numeric(plain, x), max_relative = 1e-6);
+    }
+
+    let y = Dual::<2>::variable(1.0, 0);
+    let x = Dual::<2>::variable(2.0, 1);
+    let angle = Transcendental::atan2(y, x);
+    assert_relative_eq!(angle.derivative(0), 2.0 / 5.0);
+    assert_relative_eq!(angle.derivative(1), -1.0 / 5.0);
+    let r = Real::hypot(x, y);
+    assert_relative_eq!(r.derivative(0), 1.0 / 5.0_f64.sqrt());
+    assert_relative_eq!(r.derivative(1), 2.0 / 5.0_f64.sqrt());
+}
+
+// ── Unit-typed derivatives ──
+
+#[test]
+fn derivative_units_are_output_per_input() {
+    let (d, v) = autodiff::derivative(
+        |t: Quantity<Second, Dual1>| -> Quantity<Meter, Dual1> {
+            Quantity::new(t.value() * t.value() * Dual1::constant(3.0))
+        },
+        Seconds::new(2.0),
+    );
+    assert_eq!(d, Meters::new(12.0));
+    let v: Quantity<Per<Meter, Second>> = v;
+    assert_eq!(v.value(), 12.0);
+}
+
+#[test]
+fn unit_conversion_scales_derivatives() {
+    // 1 km of input per unit; output in metres.
+    let (m, dm_dkm) = autodiff::derivative(
+        |x: Quantity<Kilometer, Dual1>| x.to::<Meter>(),
+        Kilometers::new(1.5),
+    );
+    assert_eq!(m, Meters::new(1500.0));
+    assert_eq!(dm_dkm.value(), 1000.0);
+}
+
+#[test]
+fn angular_trigonometry_differentiates_in_degrees() {
+    // d/dθ sin(θ) with θ in degrees is cos(θ)·π/180 per degree.
+    let theta = Quantity::<Degree, Dual1>::variable(Degrees::new(60.0), 0);
+    let s = Quantity::<Per<Meter, Meter>, Dual1>::new(theta.sin());
+    let ds = s.derivative::<Degree>(0);
+    assert_relative_eq!(
+        ds.value(),
+        0.5 * core::f64::consts::PI / 180.0,
+        max_relative = 1e-12
+    );
+
+    let rad = theta.to::<Radian>();
+    assert_relative_eq!(rad.value().derivative(0), core::f64::consts::PI / 180.0);
+}
+
+#[test]
+fn typed_sqrt_and_products_carry_partials() {
+    let x = Quantity::<Meter, Dual<2>>::variable(Meters::new(3.0), 0);
+    let y = Quantity::<Meter, Dual<2>>::variable(Meters::new(4.0), 1);
+    let area: Quantity<SquareMeter, Dual<2>> = Quantity::new((x * x + y * y).value());
+    let r: Quantity<Meter, Dual<2>> = area.sqrt();
+
+    assert_eq!(r.primal(), Meters::new(5.0));
+    assert_relative_eq!(r.derivative::<Meter>(0).value(), 0.6);
+    assert_relative_eq!(r.derivative::<Meter>(1).value(), 0.8);
+
+    let xy: Quantity<Prod<Meter, Meter>, Dual<2>> = x * y;
+    assert_eq!(xy.derivative::<Meter>(0).value(), 4.0);
+}
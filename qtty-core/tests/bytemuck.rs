@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "bytemuck")]
+
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+use core::mem::{align_of, size_of};
+use qtty_core::length::{Kilometer, Meter, Meters};
+use qtty_core::time::Second;
+use qtty_core::{Quantity, Quantity32, QuantityI64};
+
+fn assert_pod<T: Pod>() {}
+
+#[test]
+fn layout_matches_scalar() {
+    assert_eq!(size_of::<Meters>(), size_of::<f64>());
+    assert_eq!(align_of::<Meters>(), align_of::<f64>());
+    assert_eq!(size_of::<Quantity32<Meter>>(), size_of::<f32>());
+    assert_eq!(align_of::<Quantity32<Meter>>(), align_of::<f32>());
+    assert_eq!(size_of::<QuantityI64<Second>>(), size_of::<i64>());
+    assert_eq!(size_of::<[Meters; 7]>(), size_of::<[f64; 7]>());
+}
+
+#[test]
+fn quantities_over_plain_scalars_are_pod() {
+    assert_pod::<Meters>();
+    assert_pod::<Quantity32<Kilometer>>();
+    assert_pod::<Quantity<Second, i8>>();
+}
+
+#[test]
+fn zeroed_is_zero() {
+    let zero: Meters = Zeroable::zeroed();
+    assert_eq!(zero, Meters::new(0.0));
+}
+
+#[test]
+fn casts_byte_buffers() {
+    let raw = [1.5_f64, -2.0, 1e300];
+    let bytes: &[u8] = bytemuck::cast_slice(&raw);
+    let distances: &[Meters] = bytemuck::cast_slice(bytes);
+    assert_eq!(
+        distances,
+        &[Meters::new(1.5), Meters::new(-2.0), Meters::new(1e300)]
+    );
+
+    let back: &[f64] = bytemuck::cast_slice(distances);
+    assert_eq!(back, &raw);
+
+    // Lengths that are not a multiple of the scalar size are rejected.
+    assert!(bytemuck::try_cast_slice::<u8, Meters>(&bytes[..5]).is_err());
+}
+
+#[test]
+fn transparent_wrapper_round_trips() {
+    let mut raw = [1.0_f32, 2.0];
+    let wrapped: &mut [Quantity32<Meter>] = Quantity32::<Meter>::wrap_slice_mut(&mut raw);
+    wrapped[0] = Quantity32::new(5.0);
+    assert_eq!(raw, [5.0, 2.0]);
+
+    let q = Quantity32::<Meter>::new(3.0);
+    assert_eq!(*Quantity32::<Meter>::peel_ref(&q), 3.0);
+    assert_eq!(
+        Quantity32::<Meter>::wrap(4.0_f32),
+        Quantity32::<Meter>::new(4.0)
+    );
+}
@@ -370,6 +370,21 @@ fn display_negative_value() {
     assert_eq!(s, "-99.9 tu");
 }
 
+#[test]
+fn display_alternate_spells_out_unit_name() {
+    use qtty_core::length::{Kilometers, Meters};
+    use qtty_core::time::Hours;
+
+    assert_eq!(format!("{:#}", Kilometers::new(12.5)), "12.5 kilometres");
+    assert_eq!(format!("{:#}", Kilometers::new(1.0)), "1 kilometre");
+    assert_eq!(format!("{:#}", Kilometers::new(-1.0)), "-1 kilometre");
+    assert_eq!(format!("{:#.1}", Meters::new(0.0)), "0.0 metres");
+    assert_eq!(format!("{:#}", Hours::new(2.0)), "2 hours");
+    // Composite units have no long name and keep their symbol.
+    let q: Quantity<Per<TestUnit, DoubleTestUnit>> = Quantity::new(2.5);
+    assert_eq!(format!("{q:#}"), "2.5 tu/dtu");
+}
+
 #[test]
 fn edge_case_zero() {
     let zero = TU::new(0.0);
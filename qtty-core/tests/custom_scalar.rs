@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! A downstream `Real` + `Transcendental` scalar backing quantities through the
+//! unchanged operator, conversion and formatting machinery.
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use qtty_core::angular::{Degree, Radian};
+use qtty_core::length::{Kilometer, Meter};
+use qtty_core::scalar::{Real, Scalar, Transcendental};
+use qtty_core::time::Second;
+use qtty_core::{Per, Quantity};
+
+/// A software-float stand-in: an `f64` behind a newtype the crate has never
+/// seen.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+struct Soft(f64);
+
+impl fmt::Display for Soft {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+macro_rules! binary_ops {
+    ($($op:ident::$f:ident, $aop:ident::$af:ident, $sym:tt;)+) => {$(
+        impl $op for Soft {
+            type Output = Self;
+            fn $f(self, rhs: Self) -> Self {
+                Soft(self.0 $sym rhs.0)
+            }
+        }
+        impl $aop for Soft {
+            fn $af(&mut self, rhs: Self) {
+                self.0 = self.0 $sym rhs.0;
+            }
+        }
+    )+};
+}
+
+binary_ops! {
+    Add::add, AddAssign::add_assign, +;
+    Sub::sub, SubAssign::sub_assign, -;
+    Mul::mul, MulAssign::mul_assign, *;
+    Div::div, DivAssign::div_assign, /;
+}
+
+impl Rem for Soft {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        Soft(self.0 % rhs.0)
+    }
+}
+
+impl Neg for Soft {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Soft(-self.0)
+    }
+}
+
+macro_rules! forward {
+    ($trait:ident: $($f:ident($($arg:ident),*) -> $ret:ident),+ $(,)?) => {$(
+        fn $f(self $(, $arg: Self)*) -> forward!(@ret $ret) {
+            forward!(@wrap $ret, <f64 as $trait>::$f(self.0 $(, $arg.0)*))
+        }
+    )+};
+    (@ret Self) => { Self };
+    (@ret bool) => { bool };
+    (@wrap Self, $e:expr) => { Soft($e) };
+    (@wrap bool, $e:expr) => { $e };
+}
+
+impl Scalar for Soft {
+    const ZERO: Self = Soft(0.0);
+    const ONE: Self = Soft(1.0);
+
+    forward!(Scalar: abs() -> Self, min(other) -> Self, max(other) -> Self, rem_euclid(rhs) -> Self);
+}
+
+impl Real for Soft {
+    const PI: Self = Soft(f64::PI);
+    const TAU: Self = Soft(f64::TAU);
+    const E: Self = Soft(f64::E);
+    const INFINITY: Self = Soft(f64::INFINITY);
+    const NEG_INFINITY: Self = Soft(f64::NEG_INFINITY);
+    const NAN: Self = Soft(f64::NAN);
+
+    fn from_f64(value: f64) -> Self {
+        Soft(value)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Soft(Real::mul_add(self.0, a.0, b.0))
+    }
+
+    fn powi(self, exp: i32) -> Self {
+        Soft(Real::powi(self.0, exp))
+    }
+
+    forward!(Real:
+        signum() -> Self, is_nan() -> bool, is_infinite() -> bool, is_finite() -> bool,
+        floor() -> Self, ceil() -> Self, round() -> Self, trunc() -> Self, fract() -> Self,
+        powf(exp) -> Self, sqrt() -> Self, cbrt() -> Self, ln() -> Self, log10() -> Self,
+        log2() -> Self, log(base) -> Self, exp() -> Self, exp2() -> Self, hypot(other) -> Self,
+    );
+}
+
+impl Transcendental for Soft {
+    fn sin_cos(self) -> (Self, Self) {
+        let (s, c) = Transcendental::sin_cos(self.0);
+        (Soft(s), Soft(c))
+    }
+
+    forward!(Transcendental:
+        sin() -> Self, cos() -> Self, tan() -> Self, asin() -> Self, acos() -> Self,
+        atan() -> Self, atan2(other) -> Self, sinh() -> Self, cosh() -> Self, tanh() -> Self,
+        asinh() -> Self, acosh() -> Self, atanh() -> Self,
+    );
+}
+
+type Len<U> = Quantity<U, Soft>;
+
+#[test]
+fn arithmetic_and_comparison_use_the_blanket_impls() {
+    let a: Len<Meter> = Quantity::new(Soft(1.5));
+    let b: Len<Meter> = Quantity::new(Soft(0.5));
+    assert_eq!((a + b).value(), Soft(2.0));
+    assert_eq!((a - b).value(), Soft(1.0));
+    assert_eq!((-a).value(), Soft(-1.5));
+    assert_eq!((a * Soft(2.0)).value(), Soft(3.0));
+    assert!(a > b);
+    assert_eq!(a.max(b), a);
+
+    let t: Quantity<Second, Soft> = Quantity::new(Soft(2.0));
+    let v: Quantity<Per<Meter, Second>, Soft> = a / t;
+    assert_eq!(v.value(), Soft(0.75));
+}
+
+#[test]
+fn unit_conversion_goes_through_from_f64() {
+    let d: Len<Kilometer> = Quantity::new(Soft(1.25));
+    assert_eq!(d.to::<Meter>().value(), Soft(1250.0));
+    assert!(d.eq_unit(&Quantity::<Meter, Soft>::new(Soft(1250.0))));
+
+    let f64_view: Quantity<Kilometer> = d.cast();
+    assert_eq!(f64_view.value(), 1.25);
+}
+
+#[test]
+fn transcendental_methods_are_available() {
+    let angle: Quantity<Degree, Soft> = Quantity::new(Soft(90.0));
+    assert!((angle.to::<Radian>().value().0 - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert!((angle.sin().0 - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn formatting_uses_the_scalar_display() {
+    let d: Len<Meter> = Quantity::new(Soft(2.5));
+    assert_eq!(d.to_string(), "2.5 m");
+}
@@ -0,0 +1,11 @@
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, qtty_derive::Unit)]
+#[unit(
+    crate = qtty_core,
+    symbol = "sixth",
+    dimension = qtty_core::Length,
+    ratio = 1.0 / 6.0,
+    exact_ratio = 1 / 2 / 3
+)]
+pub struct ChainedExactRatio;
+
+fn main() {}
@@ -4,12 +4,13 @@
 //! Integration tests for integer scalar support.
 //!
 //! Tests that Quantity<U, iNN> works correctly for i8, i16, i32, i64, i128
-//! with arithmetic, Display, lossy conversion, and derived units (Per).
+//! with arithmetic, Display, lossy conversion, and derived units (Per), and
+//! that the unsigned types u8 to u128 and usize behave the same way.
 
 use qtty_core::length::{Kilometer, Meter};
 use qtty_core::scalar::{Exact, IntegerScalar};
 use qtty_core::time::Second;
-use qtty_core::{Per, Quantity, QuantityI32, QuantityI64};
+use qtty_core::{Per, Quantity, QuantityI32, QuantityI64, QuantityU32, QuantityU64};
 use std::collections::{HashMap, HashSet};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -655,3 +656,128 @@ fn test_i8_rem() {
     let a = Quantity::<Meter, i8>::new(17);
     assert_eq!((a % 5_i8).value(), 2_i8);
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Exact-ratio conversions
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_i64_to_lossy_uses_exact_ratio_beyond_f64_precision() {
+    use qtty_core::time::{Day, Nanosecond};
+
+    // 2^53 + 1 ns cannot be represented in f64; the exact path keeps it.
+    let ns = Quantity::<Nanosecond, i64>::new(9_007_199_254_740_993);
+    let s: Quantity<Second, i64> = ns.to_lossy();
+    assert_eq!(s.value(), 9_007_199);
+
+    let days = Quantity::<Day, i64>::new(106_751);
+    let back: Quantity<Nanosecond, i64> = days.to_lossy();
+    assert_eq!(back.value(), 9_223_286_400_000_000_000);
+}
+
+#[test]
+fn test_to_lossy_exact_ratio_truncates_toward_zero() {
+    use qtty_core::temperature::{Kelvin, Rankine};
+
+    // 1 °R = 5/9 K
+    let r = Quantity::<Rankine, i32>::new(-10);
+    let k: Quantity<Kelvin, i32> = r.to_lossy();
+    assert_eq!(k.value(), -5); // -5.55… truncated
+
+    let r: Quantity<Rankine, i32> = Quantity::<Kelvin, i32>::new(5).to_lossy();
+    assert_eq!(r.value(), 9);
+}
+
+#[test]
+fn test_exact_ratio_overflow_is_reported_or_saturated() {
+    use qtty_core::time::{Day, Nanosecond};
+
+    let days = Quantity::<Day, i64>::new(106_752);
+    assert_eq!(days.checked_to_lossy::<Nanosecond>(), None);
+    assert_eq!(days.to_lossy::<Nanosecond>().value(), i64::MAX);
+    assert_eq!((-days).to_lossy::<Nanosecond>().value(), i64::MIN);
+
+    let km = Quantity::<Kilometer, i128>::new(i128::MAX / 100);
+    assert_eq!(km.checked_to_lossy::<Meter>(), None);
+}
+
+#[test]
+fn test_exact_ratio_for_composite_units() {
+    use qtty_core::time::Hour;
+
+    let v = Quantity::<Per<Kilometer, Hour>, i64>::new(36);
+    let v: Quantity<Per<Meter, Second>, i64> = v.to_lossy();
+    assert_eq!(v.value(), 10);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Unsigned integers
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_unsigned_arithmetic_and_display() {
+    let a: QuantityU32<Meter> = Quantity::new(7);
+    let b: QuantityU32<Meter> = Quantity::new(3);
+    assert_eq!((a + b).value(), 10);
+    assert_eq!((a - b).value(), 4);
+    assert_eq!((2 * a).value(), 14);
+    assert_eq!((a / 2).value(), 3);
+    assert_eq!((a % 4).value(), 3);
+    assert_eq!(a.abs(), a);
+    assert_eq!(a.min(b), b);
+    assert_eq!(format!("{}", a), "7 m");
+    assert_eq!(
+        Quantity::<Meter, u8>::new(3)
+            .const_add(Quantity::new(4))
+            .value(),
+        7
+    );
+    assert_eq!((3_usize * Quantity::<Meter, usize>::new(4)).value(), 12);
+}
+
+#[test]
+fn test_unsigned_to_lossy() {
+    use qtty_core::time::{Day, Millisecond, Nanosecond};
+
+    let ticks: QuantityU32<Millisecond> = Quantity::new(1_999);
+    assert_eq!(ticks.to_lossy::<Second>().value(), 1);
+
+    // u64 nanosecond timestamps: 2^64 ns is about 213 503 days.
+    let days: QuantityU64<Day> = Quantity::new(213_503);
+    assert_eq!(
+        days.to_lossy::<Nanosecond>().value(),
+        18_446_659_200_000_000_000
+    );
+    let days: QuantityU64<Day> = Quantity::new(213_504);
+    assert_eq!(days.checked_to_lossy::<Nanosecond>(), None);
+    assert_eq!(days.to_lossy::<Nanosecond>().value(), u64::MAX);
+
+    // u128 values above i128::MAX still convert exactly.
+    let m = Quantity::<Meter, u128>::new(u128::MAX - 999);
+    let km: Quantity<Kilometer, u128> = m.to_lossy();
+    assert_eq!(km.value(), (u128::MAX - 999) / 1_000);
+}
+
+#[test]
+fn test_unsigned_exact_f64_fallback() {
+    assert_eq!(<u8 as Exact>::from_f64_approx(-3.5), 0);
+    assert_eq!(<u8 as Exact>::from_f64_approx(300.0), u8::MAX);
+    assert_eq!(<u8 as Exact>::from_f64_approx(f64::NAN), 0);
+    assert_eq!(<u8 as Exact>::checked_from_f64(255.9), Some(255));
+    assert_eq!(<u8 as Exact>::checked_from_f64(256.0), None);
+    assert_eq!(<u8 as Exact>::checked_from_f64(-0.5), Some(0));
+    assert_eq!(<u8 as Exact>::checked_from_f64(-1.0), None);
+    assert_eq!(<u64 as Exact>::checked_from_f64(u64::MAX as f64), None);
+    assert_eq!(<u128 as Exact>::to_f64_approx(1 << 100), 2f64.powi(100));
+}
+
+#[test]
+fn test_unsigned_types_are_integer_scalars() {
+    fn assert_integer<S: IntegerScalar>() {}
+    assert_integer::<u8>();
+    assert_integer::<u16>();
+    assert_integer::<u32>();
+    assert_integer::<u64>();
+    assert_integer::<u128>();
+    assert_integer::<usize>();
+}
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "nalgebra")]
+
+use approx::assert_relative_eq;
+use core::f64::consts::FRAC_PI_2;
+use nalgebra::{Matrix3, Rotation3, UnitQuaternion, Vector3};
+use qtty_core::length::{Kilometer, Meter, Meters};
+use qtty_core::time::{Second, Seconds};
+use qtty_core::{Per, Prod, QVector3, Quantity, QuantityVector3};
+
+fn m(x: f64, y: f64, z: f64) -> QVector3<Meter> {
+    QVector3::new(Meters::new(x), Meters::new(y), Meters::new(z))
+}
+
+#[test]
+fn components_and_norm_are_quantities() {
+    let v = m(3.0, 4.0, 12.0);
+    assert_eq!(v.x(), Meters::new(3.0));
+    assert_eq!(v.y(), Meters::new(4.0));
+    assert_eq!(v.z(), Meters::new(12.0));
+    assert_eq!(v.norm(), Meters::new(13.0));
+    assert_eq!(v.as_vector(), &Vector3::new(3.0, 4.0, 12.0));
+    assert_eq!(QVector3::<Meter>::zeros().norm(), Meters::new(0.0));
+}
+
+#[test]
+fn rotations_apply_to_length_vectors() {
+    let r = m(1.0, 0.0, 0.0);
+    let rot = Rotation3::from_axis_angle(&Vector3::z_axis(), FRAC_PI_2);
+
+    let by_rotation = rot * r;
+    assert_relative_eq!(by_rotation.x().value(), 0.0, epsilon = 1e-12);
+    assert_relative_eq!(by_rotation.y().value(), 1.0, epsilon = 1e-12);
+
+    let by_quaternion = UnitQuaternion::from_rotation_matrix(&rot) * r;
+    assert_relative_eq!(by_quaternion.y().value(), 1.0, epsilon = 1e-12);
+
+    let by_matrix = Matrix3::from_diagonal_element(2.0) * r;
+    assert_eq!(by_matrix, m(2.0, 0.0, 0.0));
+}
+
+#[test]
+fn dot_and_cross_use_unit_arithmetic() {
+    let a = m(1.0, 2.0, 3.0);
+    let b = m(4.0, 5.0, 6.0);
+    let dot: Quantity<Prod<Meter, Meter>> = a.dot(&b);
+    assert_eq!(dot.value(), 32.0);
+
+    let cross: QVector3<Prod<Meter, Meter>> = m(1.0, 0.0, 0.0).cross(&m(0.0, 1.0, 0.0));
+    assert_eq!(cross.as_vector(), &Vector3::new(0.0, 0.0, 1.0));
+
+    let t = QVector3::<Second>::from_vector(Vector3::new(1.0, 1.0, 1.0));
+    let mixed: Quantity<Prod<Meter, Second>> = a.dot(&t);
+    assert_eq!(mixed.value(), 6.0);
+}
+
+#[test]
+fn arithmetic_and_scaling() {
+    let mut a = m(1.0, 2.0, 3.0);
+    let b = m(1.0, 1.0, 1.0);
+    assert_eq!(a + b, m(2.0, 3.0, 4.0));
+    assert_eq!(a - b, m(0.0, 1.0, 2.0));
+    assert_eq!(-b, m(-1.0, -1.0, -1.0));
+    assert_eq!(a * 2.0, m(2.0, 4.0, 6.0));
+    assert_eq!(a / 2.0, m(0.5, 1.0, 1.5));
+    a += b;
+    a -= b * 2.0;
+    assert_eq!(a, m(0.0, 1.0, 2.0));
+
+    let swept: QVector3<Prod<Meter, Second>> = b * Seconds::new(3.0);
+    assert_eq!(swept.as_vector(), &Vector3::new(3.0, 3.0, 3.0));
+}
+
+#[test]
+fn direction_times_magnitude_rebuilds_the_vector() {
+    let v = m(0.0, 3.0, 4.0);
+    let dir = v.direction(Meters::new(0.0)).unwrap();
+    let rebuilt: QVector3<Meter> = dir * v.norm();
+    assert_relative_eq!(rebuilt.y().value(), 3.0, epsilon = 1e-12);
+    assert_relative_eq!(rebuilt.z().value(), 4.0, epsilon = 1e-12);
+    assert!(QVector3::<Meter>::zeros()
+        .direction(Meters::new(0.0))
+        .is_none());
+
+    // Vectors no longer than `min_norm` have no direction.
+    let tiny = m(1e-12, 0.0, 0.0);
+    assert!(tiny.direction(Meters::new(0.0)).is_some());
+    assert!(tiny.direction(Meters::new(1e-9)).is_none());
+    assert!(v.direction(Meters::new(5.0)).is_none());
+}
+
+#[test]
+fn conversion_and_interop() {
+    let km = m(1500.0, 0.0, -250.0).to::<Kilometer>();
+    assert_eq!(km.as_vector(), &Vector3::new(1.5, 0.0, -0.25));
+
+    let plain: QuantityVector3<Meter> = m(1.0, 2.0, 3.0).into();
+    assert_eq!(
+        plain,
+        QuantityVector3::new(Meters::new(1.0), Meters::new(2.0), Meters::new(3.0))
+    );
+    assert_eq!(QVector3::from(plain), m(1.0, 2.0, 3.0));
+
+    let v = QVector3::<Per<Meter, Second>>::from_vector(Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(v.into_vector(), Vector3::new(1.0, 0.0, 0.0));
+}
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "ndarray")]
+
+use approx::assert_relative_eq;
+use ndarray::{array, Array2, Ix2};
+use qtty_core::area::SquareMeter;
+use qtty_core::length::{Kilometer, Kilometers, Meter, Meters};
+use qtty_core::time::{Second, Seconds};
+use qtty_core::{Per, Prod, Quantity, QuantityArray};
+
+fn meters(values: &[f64]) -> QuantityArray<Meter> {
+    QuantityArray::from_array(ndarray::Array1::from(values.to_vec()))
+}
+
+#[test]
+fn elementwise_add_and_sub_keep_the_unit() {
+    let a = meters(&[1.0, 2.0, 3.0]);
+    let b = meters(&[0.5, 0.5, 0.5]);
+    assert_eq!((&a + &b).as_array(), &array![1.5, 2.5, 3.5]);
+    assert_eq!((&a - &b).as_array(), &array![0.5, 1.5, 2.5]);
+    assert_eq!((a.clone() + b.clone()), &a + &b);
+    assert_eq!((-(a - b)).as_array(), &array![-0.5, -1.5, -2.5]);
+}
+
+#[test]
+fn scalar_mul_and_div() {
+    let a = meters(&[2.0, 4.0]);
+    assert_eq!((a.clone() * 2.0).as_array(), &array![4.0, 8.0]);
+    assert_eq!((a / 4.0).as_array(), &array![0.5, 1.0]);
+}
+
+#[test]
+fn array_products_use_unit_arithmetic() {
+    let a = meters(&[2.0, 3.0]);
+    let area: QuantityArray<Prod<Meter, Meter>> = &a * &a;
+    assert_eq!(area.as_array(), &array![4.0, 9.0]);
+
+    let t = QuantityArray::<Second>::from_quantities([Seconds::new(1.0), Seconds::new(2.0)]);
+    let v: QuantityArray<Per<Meter, Second>> = &a / &t;
+    assert_eq!(v.as_array(), &array![2.0, 1.5]);
+
+    // Same-unit division cancels to a plain array, like `Quantity / Quantity`.
+    let ratio: ndarray::Array1<f64> = &a / &meters(&[1.0, 2.0]);
+    assert_eq!(ratio, array![2.0, 1.5]);
+
+    let scaled: QuantityArray<Prod<Meter, Second>> = a.clone() * Seconds::new(10.0);
+    assert_eq!(scaled.as_array(), &array![20.0, 30.0]);
+    let per: QuantityArray<Per<Meter, Second>> = a / Seconds::new(2.0);
+    assert_eq!(per.as_array(), &array![1.0, 1.5]);
+}
+
+#[test]
+fn conversion_scales_every_element() {
+    let km = meters(&[1500.0, 250.0]).to::<Kilometer>();
+    assert_eq!(km.as_array(), &array![1.5, 0.25]);
+    assert_eq!(km[0], Kilometers::new(1.5));
+}
+
+#[test]
+fn reductions_return_quantities() {
+    let a = meters(&[1.0, 2.0, 6.0]);
+    assert_eq!(a.sum(), Meters::new(9.0));
+    assert_eq!(a.mean(), Some(Meters::new(3.0)));
+    assert_eq!(meters(&[]).mean(), None);
+    assert_eq!(meters(&[]).sum(), Meters::new(0.0));
+}
+
+#[test]
+fn indexing_yields_quantities() {
+    let mut grid = QuantityArray::<SquareMeter, Ix2>::from_array(Array2::zeros((2, 3)));
+    grid[[1, 2]] = Quantity::new(7.0);
+    assert_eq!(grid[[1, 2]].value(), 7.0);
+    assert_eq!(grid.get([1, 2]), Some(Quantity::new(7.0)));
+    assert_eq!(grid.get([2, 0]), None);
+    assert_eq!(grid.shape(), &[2, 3]);
+    assert_eq!(grid.len(), 6);
+    assert_eq!(grid.iter().filter(|q| q.value() > 0.0).count(), 1);
+}
+
+#[test]
+fn map_changes_unit() {
+    let a = meters(&[1.0, 2.0]);
+    let squared: QuantityArray<Prod<Meter, Meter>> = a.map(|x| x * x);
+    assert_relative_eq!(squared.as_array()[1], 4.0);
+    let collected: QuantityArray<Meter> = a.iter().map(|x| x * 2.0).collect();
+    assert_eq!(collected.as_array(), &array![2.0, 4.0]);
+}
+
+#[test]
+#[should_panic]
+fn mismatched_shapes_panic() {
+    let _ = &meters(&[1.0, 2.0]) + &meters(&[1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "parse")]
+
+use approx::assert_relative_eq;
+use qtty_core::energy::{Joule, Joules};
+use qtty_core::length::{Kilometer, Kilometers, Meter, Meters};
+use qtty_core::mass::Kilogram;
+use qtty_core::time::{Second, Seconds};
+use qtty_core::velocity::Velocity;
+use qtty_core::*;
+
+fn unit(s: &str) -> DynUnit {
+    s.parse()
+        .unwrap_or_else(|e| panic!("failed to parse {s:?}: {e}"))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Unit expressions
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn parses_builtin_symbols() {
+    assert_eq!(unit("m"), DynUnit::of::<Meter>());
+    assert_eq!(unit("km"), DynUnit::of::<Kilometer>());
+    assert_eq!(unit("kg"), DynUnit::of::<Kilogram>());
+    assert_eq!(unit(" s "), DynUnit::of::<Second>());
+}
+
+#[test]
+fn parses_si_prefixes_on_coherent_symbols() {
+    let mk = unit("mK");
+    assert_eq!(mk.dimension(), Temperature::EXPONENTS);
+    assert_relative_eq!(mk.ratio(), 1e-3);
+
+    assert_relative_eq!(unit("daJ").ratio(), 10.0);
+    assert_relative_eq!(unit("μs").ratio(), 1e-6);
+    assert_relative_eq!(unit("us").ratio(), 1e-6);
+    assert_relative_eq!(unit("Qm").ratio(), 1e30);
+}
+
+#[test]
+fn parses_products_quotients_and_exponents() {
+    let v = unit("km/s");
+    assert_eq!(v.dimension(), qtty_core::Velocity::EXPONENTS);
+    assert_relative_eq!(v.ratio(), 1000.0);
+
+    for expr in ["kg·m²·s⁻²", "kg*m^2*s^-2", "kg ⋅ m^2 / s^2", "kg·m²/s²"] {
+        let e = unit(expr);
+        assert_eq!(e.dimension(), Energy::EXPONENTS, "{expr}");
+        assert_eq!(
+            e,
+            DynUnit::of::<Kilogram>() * DynUnit::of::<Meter>().powi(2)
+                / DynUnit::of::<Second>().powi(2)
+        );
+    }
+
+    assert_eq!(unit("1/s").dimension(), Frequency::EXPONENTS);
+    assert_eq!(unit("m^+3"), unit("m³"));
+    assert_eq!(unit("m^−1"), unit("m⁻¹"));
+}
+
+#[test]
+fn everything_after_slash_divides() {
+    assert_eq!(unit("J/kg·K"), unit("J/(kg·K)"));
+    assert_eq!(unit("m/s/s"), unit("m/s²"));
+    assert_eq!(
+        unit("(m/s)^2").dimension(),
+        Area::EXPONENTS / Time::EXPONENTS / Time::EXPONENTS
+    );
+}
+
+#[test]
+fn parses_long_names_and_aliases() {
+    assert_eq!(unit("kilometre"), DynUnit::of::<Kilometer>());
+    assert_eq!(unit("kilometer"), DynUnit::of::<Kilometer>());
+    assert_eq!(unit("Kilometres"), DynUnit::of::<Kilometer>());
+    assert_eq!(unit("metres/second"), unit("m/s"));
+    assert_eq!(unit("joule"), DynUnit::of::<Joule>());
+
+    let d: Kilometers = "12.5 kilometres".parse().unwrap();
+    assert_relative_eq!(d.value(), 12.5);
+    // Symbols still win over names.
+    assert_eq!(unit("min"), DynUnit::of::<time::Minute>());
+}
+
+#[test]
+fn composite_builtin_symbol_matches_whole_string() {
+    assert_eq!(
+        unit("m/s²"),
+        DynUnit::of::<acceleration::MeterPerSecondSquared>()
+    );
+}
+
+#[test]
+fn unit_errors_report_position() {
+    assert_eq!("".parse::<DynUnit>(), Err(ParseUnitError::Empty));
+    assert_eq!("   ".parse::<DynUnit>(), Err(ParseUnitError::Empty));
+    assert_eq!(
+        "km/furlong".parse::<DynUnit>(),
+        Err(ParseUnitError::UnknownUnit { position: 3 })
+    );
+    assert_eq!(
+        "km/".parse::<DynUnit>(),
+        Err(ParseUnitError::UnexpectedChar { position: 3 })
+    );
+    assert_eq!(
+        "m^x".parse::<DynUnit>(),
+        Err(ParseUnitError::InvalidExponent { position: 1 })
+    );
+    assert_eq!(
+        "m^200".parse::<DynUnit>(),
+        Err(ParseUnitError::InvalidExponent { position: 1 })
+    );
+    // Exponents that fit in `i8` on their own but overflow when combined.
+    assert_eq!(
+        "m^127*m".parse::<DynUnit>(),
+        Err(ParseUnitError::InvalidExponent { position: 5 })
+    );
+    assert_eq!(
+        "(m^64)^2".parse::<DynUnit>(),
+        Err(ParseUnitError::InvalidExponent { position: 6 })
+    );
+    assert_eq!(
+        "m/m^-128".parse::<DynUnit>(),
+        Err(ParseUnitError::InvalidExponent { position: 1 })
+    );
+    assert!("1 m^127·m".parse::<DynQuantity>().is_err());
+    assert!("1 (m^64)^2".parse::<Meters>().is_err());
+    assert_eq!(unit("m^-128/m^-128"), DynUnit::DIMENSIONLESS);
+    assert_eq!(
+        "J/(kg·K".parse::<DynUnit>(),
+        Err(ParseUnitError::UnclosedParenthesis { position: 2 })
+    );
+    assert_eq!(
+        "N m".parse::<DynUnit>(),
+        Err(ParseUnitError::UnexpectedChar { position: 2 })
+    );
+    assert_eq!(
+        ParseUnitError::UnknownUnit { position: 3 }.to_string(),
+        "unknown unit at byte 3"
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Quantities
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn parses_quantity_and_converts_to_target_unit() {
+    let d: Meters = "12.5 km".parse().unwrap();
+    assert_relative_eq!(d.value(), 12_500.0);
+
+    let d: Kilometers = "250m".parse().unwrap();
+    assert_relative_eq!(d.value(), 0.25);
+
+    let v: Velocity<Meter, Second> = "12.5 km/s".parse().unwrap();
+    assert_relative_eq!(v.value(), 12_500.0);
+
+    let e: Joules = "-2e3 kg·m²·s⁻²".parse().unwrap();
+    assert_relative_eq!(e.value(), -2e3 * Kilogram::RATIO);
+
+    let t: Quantity<Second, f32> = "1.5 min".parse().unwrap();
+    assert_relative_eq!(t.value(), 90.0);
+}
+
+#[test]
+fn quantity_without_unit_is_dimensionless_only() {
+    let q: Quantity<Per<Meter, Meter>> = "0.25".parse().unwrap();
+    assert_relative_eq!(q.value(), 0.25);
+    assert!("0.25".parse::<Meters>().is_err());
+}
+
+#[test]
+fn quantity_accepts_non_finite_numbers() {
+    let q: Seconds = "inf s".parse().unwrap();
+    assert!(q.value().is_infinite());
+    let q: Seconds = "NaN s".parse().unwrap();
+    assert!(q.value().is_nan());
+}
+
+#[test]
+fn quantity_errors() {
+    assert_eq!(
+        "3 s".parse::<Meters>(),
+        Err(ParseQuantityError::DimensionMismatch {
+            expected: Length::EXPONENTS,
+            found: Time::EXPONENTS,
+        })
+    );
+    assert_eq!(
+        "km".parse::<Meters>(),
+        Err(ParseQuantityError::InvalidNumber)
+    );
+    assert_eq!(
+        "1 km/?".parse::<Meters>(),
+        Err(ParseQuantityError::Unit(ParseUnitError::UnknownUnit {
+            position: 5
+        }))
+    );
+    assert_eq!(
+        "3 s".parse::<Joules>().unwrap_err().to_string(),
+        "dimension mismatch: expected L²·T⁻²·M, found T"
+    );
+}
+
+#[test]
+fn display_round_trips_through_parse() {
+    let v = Meters::new(10.0) / Seconds::new(4.0);
+    let back: Velocity<Meter, Second> = v.to_string().parse().unwrap();
+    assert_eq!(back, v);
+
+    let e = Joules::new(3.5);
+    assert_eq!(e.to_string().parse::<Quantity<Joule>>().unwrap(), e);
+
+    // Nested composites render collapsed (`m/s²`, `J/(kg·K)`) and parse back.
+    let a: Quantity<Per<Per<Meter, Second>, Second>> = Quantity::new(9.81);
+    assert_eq!(
+        a.to_string()
+            .parse::<Quantity<Per<Per<Meter, Second>, Second>>>(),
+        Ok(a)
+    );
+    let c: Quantity<Per<Joule, Prod<Kilogram, temperature::Kelvin>>> = Quantity::new(4184.0);
+    assert_eq!(c.to_string(), "4184 J/(kg·K)");
+    assert_eq!(c.to_string().parse(), Ok(c));
+}
+
+#[cfg(feature = "astro")]
+#[test]
+fn feature_gated_symbols_resolve() {
+    assert_eq!(unit("au"), DynUnit::of::<length::AstronomicalUnit>());
+    assert_eq!(unit("kpc"), DynUnit::of::<length::Kiloparsec>());
+    // `h` resolves to the hour, not the hour angle.
+    assert_eq!(unit("h"), DynUnit::of::<time::Hour>());
+    assert_eq!(unit("astronomical units"), unit("au"));
+}
+
+#[cfg(feature = "navigation")]
+#[test]
+fn multi_word_names_resolve() {
+    let d: Meters = "2 nautical miles".parse().unwrap();
+    assert_relative_eq!(d.value(), 3704.0);
+}
+
+#[test]
+fn parses_dyn_quantity_without_target_unit() {
+    let q: DynQuantity = "9.81 m/s²".parse().unwrap();
+    assert_eq!(q.dimension(), Acceleration::EXPONENTS);
+    assert_relative_eq!(q.value(), 9.81);
+
+    let d: DynQuantity = "2 km".parse().unwrap();
+    let t: DynQuantity = "30 min".parse().unwrap();
+    let v: Velocity<Kilometer, qtty_core::time::Hour> = (d / t).try_into().unwrap();
+    assert_relative_eq!(v.value(), 4.0);
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use qtty_core::length::{Kilometer, Kilometers, Meter, Meters};
+use qtty_core::proptest::{finite, in_range, units_of};
+use qtty_core::{DynQuantity, Length, Quantity, Quantity32, QuantityRange};
+
+proptest! {
+    #[test]
+    fn finite_never_yields_nan_or_infinity(d in finite::<Meter>()) {
+        prop_assert!(d.value().is_finite());
+    }
+
+    #[test]
+    fn in_range_respects_inclusive_bounds(d in in_range(Meters::new(-5.0)..=Meters::new(5.0))) {
+        prop_assert!(d >= Meters::new(-5.0) && d <= Meters::new(5.0));
+    }
+
+    #[test]
+    fn in_range_respects_exclusive_bounds(
+        d in in_range(QuantityRange::open(Meters::new(0.0), Meters::new(1e-300)))
+    ) {
+        prop_assert!(d > Meters::new(0.0) && d < Meters::new(1e-300));
+    }
+
+    #[test]
+    fn in_range_accepts_bounds_in_another_unit(
+        d in in_range(Kilometers::new(1.0)..Meters::new(1500.0).to())
+    ) {
+        prop_assert!(d >= Kilometers::new(1.0) && d < Kilometers::new(1.5));
+    }
+
+    #[test]
+    fn units_of_covers_only_the_dimension(unit in units_of::<Length>(), v in -1e6..1e6f64) {
+        let q = DynQuantity::new(v, unit.dyn_unit());
+        let meters = q.to_quantity::<Meter>().unwrap();
+        prop_assert!((meters.value() - v * unit.ratio()).abs() <= 1e-9 * meters.value().abs().max(1.0));
+    }
+}
+
+#[test]
+fn any_quantity_wraps_the_scalar_strategy() {
+    let mut scalars = TestRunner::deterministic();
+    let mut quantities = TestRunner::deterministic();
+    for _ in 0..100 {
+        let raw = any::<f32>().new_tree(&mut scalars).unwrap().current();
+        let q = any::<Quantity32<Kilometer>>()
+            .new_tree(&mut quantities)
+            .unwrap()
+            .current();
+        assert_eq!(q.value().to_bits(), raw.to_bits());
+    }
+    let _: Quantity<Meter, i16> = any::<Quantity<Meter, i16>>()
+        .new_tree(&mut scalars)
+        .unwrap()
+        .current();
+}
+
+#[test]
+fn units_of_lists_the_length_inventory() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = units_of::<Length>();
+    let mut seen = std::collections::BTreeSet::new();
+    for _ in 0..500 {
+        seen.insert(strategy.new_tree(&mut runner).unwrap().current().symbol());
+    }
+    assert!(seen.contains("m") && seen.contains("km"));
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn in_range_rejects_empty_ranges() {
+    let _ = in_range(Meters::new(1.0)..Meters::new(1.0));
+}
@@ -28,4 +28,5 @@ fn derive_macro_compile_fail_cases_are_covered() {
     cases.compile_fail("tests/fixtures/qtty_derive/missing_unit_attribute.rs");
     cases.compile_fail("tests/fixtures/qtty_derive/missing_dimension.rs");
     cases.compile_fail("tests/fixtures/qtty_derive/unknown_field.rs");
+    cases.compile_fail("tests/fixtures/qtty_derive/chained_exact_ratio.rs");
 }
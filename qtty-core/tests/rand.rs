@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "rand")]
+
+use core::f64::consts::{FRAC_PI_2, TAU};
+use qtty_core::angular::{Radians, UniformDirection};
+use qtty_core::length::{Kilometers, Meter, Meters};
+use qtty_core::time::Seconds;
+use qtty_core::{Quantity, QuantityLogNormal, QuantityNormal};
+use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::NormalError;
+
+const N: usize = 20_000;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x5eed)
+}
+
+fn mean_and_std(samples: &[f64]) -> (f64, f64) {
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+    (mean, var.sqrt())
+}
+
+#[test]
+fn gen_range_samples_quantities() {
+    let mut rng = rng();
+    for _ in 0..1_000 {
+        let d = rng.gen_range(Meters::new(0.0)..Kilometers::new(1.0).to());
+        assert!(d >= Meters::new(0.0) && d < Meters::new(1_000.0));
+
+        let t = rng.gen_range(Seconds::new(-1.0)..=Seconds::new(1.0));
+        assert!(t.value().abs() <= 1.0);
+
+        let n: Quantity<Meter, i32> = rng.gen_range(Quantity::new(3)..Quantity::new(5));
+        assert!((3..5).contains(&n.value()));
+    }
+}
+
+#[test]
+fn uniform_distribution_over_quantities() {
+    let uniform = Uniform::new_inclusive(Meters::new(10.0), Meters::new(20.0));
+    let samples: Vec<f64> = uniform
+        .sample_iter(rng())
+        .take(N)
+        .map(|d| d.value())
+        .collect();
+    let (mean, _) = mean_and_std(&samples);
+    assert!(samples.iter().all(|v| (10.0..=20.0).contains(v)));
+    assert!((mean - 15.0).abs() < 0.1);
+}
+
+#[test]
+fn normal_uses_quantity_parameters() {
+    let normal = QuantityNormal::new(Meters::new(5.0), Meters::new(0.5)).unwrap();
+    assert_eq!(normal.mean(), Meters::new(5.0));
+    assert_eq!(normal.std_dev(), Meters::new(0.5));
+
+    let mut rng = rng();
+    let samples: Vec<f64> = (0..N).map(|_| normal.sample(&mut rng).value()).collect();
+    let (mean, std) = mean_and_std(&samples);
+    assert!((mean - 5.0).abs() < 0.02);
+    assert!((std - 0.5).abs() < 0.02);
+
+    assert!(matches!(
+        QuantityNormal::new(Meters::new(0.0), Meters::new(-1.0)),
+        Err(NormalError::BadVariance)
+    ));
+}
+
+#[test]
+fn log_normal_matches_requested_moments() {
+    let log_normal = QuantityLogNormal::new(Meters::new(2.0), Meters::new(0.5)).unwrap();
+    assert_eq!(log_normal.mean(), Meters::new(2.0));
+    assert_eq!(log_normal.std_dev(), Meters::new(0.5));
+
+    let mut rng = rng();
+    let samples: Vec<f64> = (0..N)
+        .map(|_| log_normal.sample(&mut rng).value())
+        .collect();
+    let (mean, std) = mean_and_std(&samples);
+    assert!(samples.iter().all(|v| *v > 0.0));
+    assert!((mean - 2.0).abs() < 0.02);
+    assert!((std - 0.5).abs() < 0.03);
+
+    assert!(matches!(
+        QuantityLogNormal::new(Meters::new(0.0), Meters::new(1.0)),
+        Err(NormalError::MeanTooSmall)
+    ));
+    assert!(QuantityLogNormal::new(Meters::new(1.0), Meters::new(f64::NAN)).is_err());
+}
+
+#[test]
+fn directions_are_uniform_on_the_sphere() {
+    let mut rng = rng();
+    let directions: Vec<(Radians, Radians)> =
+        (0..N).map(|_| UniformDirection.sample(&mut rng)).collect();
+
+    for (lon, lat) in &directions {
+        assert!(lon.value() >= 0.0 && lon.value() < TAU);
+        assert!(lat.value().abs() <= FRAC_PI_2);
+    }
+
+    // Equal-area bands: |sin(lat)| < 0.5 covers half the sphere, and the
+    // mean unit vector is close to zero.
+    let equatorial = directions
+        .iter()
+        .filter(|(_, lat)| lat.value().sin().abs() < 0.5)
+        .count() as f64;
+    assert!((equatorial / N as f64 - 0.5).abs() < 0.02);
+
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+    for (lon, lat) in &directions {
+        x += lat.value().cos() * lon.value().cos();
+        y += lat.value().cos() * lon.value().sin();
+        z += lat.value().sin();
+    }
+    let n = N as f64;
+    assert!((x / n).abs() < 0.02 && (y / n).abs() < 0.02 && (z / n).abs() < 0.02);
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "fixed")]
+
+use fixed::types::{I16F16, I32F32, I4F28};
+use qtty_core::length::{Kilometer, Meter, Millimeter};
+use qtty_core::scalar::{Exact, Scalar};
+use qtty_core::{ExactRatio, Quantity};
+
+#[test]
+fn fixed_scalar_basics() {
+    assert_eq!(I16F16::ZERO, I16F16::from_num(0));
+    assert_eq!(<I16F16 as Scalar>::ONE, I16F16::from_num(1));
+    assert_eq!(Scalar::abs(I16F16::from_num(-2.5)), I16F16::from_num(2.5));
+    assert_eq!(Scalar::abs(I16F16::MIN), I16F16::MAX);
+    assert_eq!(
+        Scalar::rem_euclid(I16F16::from_num(-7), I16F16::from_num(3)),
+        I16F16::from_num(2)
+    );
+    assert_eq!(
+        Scalar::max(I32F32::from_num(1), I32F32::from_num(-1)),
+        I32F32::from_num(1)
+    );
+}
+
+#[test]
+fn fixed_quantity_arithmetic() {
+    let a: Quantity<Meter, I16F16> = Quantity::new(I16F16::from_num(1.5));
+    let b: Quantity<Meter, I16F16> = Quantity::new(I16F16::from_num(0.25));
+    assert_eq!((a + b).value(), I16F16::from_num(1.75));
+    assert_eq!((a - b).value(), I16F16::from_num(1.25));
+    assert_eq!((a * I16F16::from_num(2)).value(), I16F16::from_num(3));
+    assert_eq!((-a).value(), I16F16::from_num(-1.5));
+    assert_eq!(format!("{a}"), "1.5 m");
+}
+
+#[test]
+fn fixed_to_lossy_uses_the_exact_ratio() {
+    let mm: Quantity<Millimeter, I16F16> = Quantity::new(I16F16::from_num(1250));
+    let m: Quantity<Meter, I16F16> = mm.to_lossy();
+    assert_eq!(m.value(), I16F16::from_num(1.25));
+    assert_eq!(m.to_lossy::<Millimeter>(), mm);
+
+    // 1 mm is not a dyadic fraction of a metre: the result is truncated
+    // toward zero at the type's resolution rather than rounded through f64.
+    let one_mm: Quantity<Millimeter, I16F16> = Quantity::new(I16F16::ONE);
+    assert_eq!(
+        one_mm.to_lossy::<Meter>().value(),
+        I16F16::from_bits(65_536 / 1_000)
+    );
+    let neg_mm: Quantity<Millimeter, I16F16> = Quantity::new(-I16F16::ONE);
+    assert_eq!(
+        neg_mm.to_lossy::<Meter>().value(),
+        -I16F16::from_bits(65_536 / 1_000)
+    );
+}
+
+#[test]
+fn fixed_checked_to_lossy_detects_overflow() {
+    let km: Quantity<Kilometer, I16F16> = Quantity::new(I16F16::from_num(40));
+    assert_eq!(km.checked_to_lossy::<Meter>(), None);
+    assert_eq!(km.to_lossy::<Meter>().value(), I16F16::MAX);
+    assert_eq!((-km).to_lossy::<Meter>().value(), I16F16::MIN);
+
+    let wide: Quantity<Kilometer, I32F32> = Quantity::new(I32F32::from_num(40));
+    assert_eq!(
+        wide.checked_to_lossy::<Meter>().map(Quantity::value),
+        Some(I32F32::from_num(40_000))
+    );
+}
+
+#[test]
+fn fixed_f64_fallback() {
+    assert_eq!(I16F16::from_num(0.75).to_f64_approx(), 0.75);
+    assert_eq!(
+        <I16F16 as Exact>::from_f64_approx(0.75),
+        I16F16::from_num(0.75)
+    );
+    assert_eq!(<I16F16 as Exact>::from_f64_approx(f64::NAN), I16F16::ZERO);
+    assert_eq!(<I16F16 as Exact>::from_f64_approx(1e9), I16F16::MAX);
+    assert_eq!(<I16F16 as Exact>::checked_from_f64(1e9), None);
+    assert_eq!(<I16F16 as Exact>::checked_from_f64(f64::INFINITY), None);
+    assert_eq!(
+        <I4F28 as Exact>::checked_mul_ratio(I4F28::from_num(2), ExactRatio::new(5, 1)),
+        None
+    );
+}
+
+#[cfg(feature = "electrical")]
+#[test]
+fn millivolt_sensor_reading_converts_to_volts() {
+    use qtty_core::electrical::{Millivolt, Volt};
+
+    let reading: Quantity<Millivolt, I16F16> = Quantity::new(I16F16::from_num(3300));
+    let volts: Quantity<Volt, I16F16> = reading.to_lossy();
+    // 3300 · 2¹⁶ / 1000 = 216 268.8, truncated to the nearest bit below.
+    assert_eq!(volts.value(), I16F16::from_bits(216_268));
+    assert!(I16F16::from_num(3.3) - volts.value() <= I16F16::DELTA);
+}
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+#![cfg(feature = "half")]
+
+use half::{bf16, f16};
+use qtty_core::angular::{Degrees, Radian};
+use qtty_core::length::{Kilometer, Meter, Meters};
+use qtty_core::scalar::{Real, Scalar, Transcendental};
+use qtty_core::{Quantity, Quantity16, QuantityBf16};
+
+#[test]
+fn half_scalar_constants() {
+    assert_eq!(<f16 as Scalar>::ZERO, f16::from_f32(0.0));
+    assert_eq!(<bf16 as Scalar>::ONE, bf16::from_f32(1.0));
+    assert_eq!(<f16 as Real>::TAU, f16::from_f64(core::f64::consts::TAU));
+    assert!(<bf16 as Real>::NAN.is_nan());
+    assert_eq!(Scalar::abs(f16::from_f32(-2.5)), f16::from_f32(2.5));
+    assert_eq!(
+        Scalar::rem_euclid(f16::from_f32(-7.0), f16::from_f32(3.0)),
+        f16::from_f32(2.0)
+    );
+}
+
+#[test]
+fn half_real_and_transcendental_promote_to_f32() {
+    let x = f16::from_f32(2.0);
+    assert_eq!(Real::sqrt(f16::from_f32(9.0)), f16::from_f32(3.0));
+    assert_eq!(Real::powi(x, 10), f16::from_f32(1024.0));
+    assert_eq!(
+        Real::hypot(f16::from_f32(3.0), f16::from_f32(4.0)),
+        f16::from_f32(5.0)
+    );
+    assert_eq!(Real::floor(f16::from_f32(-1.5)), f16::from_f32(-2.0));
+    assert_eq!(Transcendental::sin(f16::ZERO), f16::ZERO);
+    assert_eq!(
+        Transcendental::atan2(bf16::ONE, bf16::ONE),
+        bf16::from_f32(core::f32::consts::FRAC_PI_4)
+    );
+    assert!(Real::ln(f16::from_f32(-1.0)).is_nan());
+}
+
+#[test]
+fn half_quantities_convert_units() {
+    let d: Quantity16<Kilometer> = Quantity::new(f16::from_f32(1.5));
+    let m: Quantity16<Meter> = d.to();
+    assert_eq!(m.value(), f16::from_f32(1500.0));
+    assert_eq!(format!("{m}"), "1500 m");
+    assert_eq!((f16::from_f32(2.0) * m).value(), f16::from_f32(3000.0));
+
+    // f16 tops out at 65504; bf16 trades precision for f32's range.
+    let far: QuantityBf16<Kilometer> = Quantity::new(bf16::from_f32(1.0e6));
+    assert_eq!(far.to::<Meter>().value(), bf16::from_f32(1.0e9));
+    assert!(Quantity16::<Kilometer>::new(f16::from_f32(100.0))
+        .to::<Meter>()
+        .value()
+        .is_infinite());
+
+    let angle = Degrees::new(90.0).cast::<f16>().to::<Radian>();
+    assert!((angle.value().to_f32() - core::f32::consts::FRAC_PI_2).abs() < 1e-3);
+}
+
+#[test]
+fn half_cast_to_and_from_wider_floats() {
+    let m = Meters::new(0.1);
+    let half: Quantity16<Meter> = m.cast();
+    assert_eq!(half.value(), f16::from_f64(0.1));
+    let back: Quantity<Meter, f32> = half.cast();
+    assert_eq!(back.value(), f16::from_f64(0.1).to_f32());
+
+    let brain: QuantityBf16<Meter> = back.cast();
+    assert_eq!(brain.cast::<f64>().value(), bf16::from_f64(0.1).to_f64());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn half_serde_roundtrip() {
+    let m: Quantity16<Meter> = Quantity::new(f16::from_f32(1.5));
+    let json = serde_json::to_string(&m).unwrap();
+    // The compact form is the numeric value, not `half`'s raw bits.
+    assert_eq!(json, "1.5");
+    assert_eq!(serde_json::from_str::<Quantity16<Meter>>(&json).unwrap(), m);
+
+    let b: QuantityBf16<Meter> = Quantity::new(bf16::from_f32(-0.25));
+    let json = serde_json::to_string(&b).unwrap();
+    assert_eq!(json, "-0.25");
+    assert_eq!(
+        serde_json::from_str::<QuantityBf16<Meter>>(&json).unwrap(),
+        b
+    );
+    assert_eq!(
+        serde_json::from_str::<Quantity16<Meter>>("2").unwrap(),
+        Quantity16::<Meter>::new(f16::from_f32(2.0))
+    );
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+#[test]
+fn half_serde_with_unit_writes_the_value_as_a_number() {
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Reading {
+        #[serde(with = "qtty_core::serde_with_unit")]
+        distance: Quantity16<Meter>,
+    }
+
+    let reading = Reading {
+        distance: Quantity::new(f16::from_f32(1.5)),
+    };
+    let json = serde_json::to_string(&reading).unwrap();
+    assert_eq!(json, r#"{"distance":{"value":1.5,"unit":"m"}}"#);
+    assert_eq!(serde_json::from_str::<Reading>(&json).unwrap(), reading);
+}
@@ -310,3 +310,29 @@ fn test_rational32_to_lossy() {
     let val_f64 = Exact::to_f64_approx(m.value());
     assert!((val_f64 - 2000.0).abs() < 1.0);
 }
+
+#[test]
+fn test_rational64_to_lossy_is_exact_with_exact_ratio() {
+    use qtty_core::temperature::{Kelvin, Rankine};
+    use qtty_core::time::{Hour, Minute};
+
+    let h = Quantity::<Hour, Rational64>::new(Rational64::new(1, 3));
+    let min: Quantity<Minute, Rational64> = h.to_lossy();
+    assert_eq!(min.value(), Rational64::from_integer(20));
+
+    let r = Quantity::<Rankine, Rational64>::new(Rational64::from_integer(1));
+    let k: Quantity<Kelvin, Rational64> = r.to_lossy();
+    assert_eq!(k.value(), Rational64::new(5, 9));
+}
+
+#[test]
+fn test_rational_checked_to_lossy_reports_overflow() {
+    use qtty_core::length::Kilometer;
+
+    let km = Quantity::<Kilometer, Rational32>::new(Rational32::from_integer(i32::MAX / 10));
+    assert_eq!(km.checked_to_lossy::<Meter>(), None);
+    assert_eq!(
+        km.to_lossy::<Meter>().value(),
+        Rational32::from_integer(i32::MAX)
+    );
+}
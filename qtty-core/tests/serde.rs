@@ -197,6 +197,44 @@ fn serde_with_f32() {
     assert!((restored.value() - 42.5).abs() < 0.01);
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum TestTimeUnit {}
+impl Unit for TestTimeUnit {
+    const RATIO: f64 = 1.0;
+    type Dim = Time;
+    const SYMBOL: &'static str = "ts";
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct CompositeStruct {
+    #[serde(with = "crate::serde_with_unit")]
+    accel: Quantity<Per<Per<TestUnit, TestTimeUnit>, TestTimeUnit>>,
+}
+
+#[test]
+fn serde_with_unit_composite_symbol() {
+    let data = CompositeStruct {
+        accel: Quantity::new(9.5),
+    };
+    let json = serde_json::to_string(&data).unwrap();
+    assert_eq!(json, r#"{"accel":{"value":9.5,"unit":"tu/ts²"}}"#);
+
+    let restored: CompositeStruct = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.accel.value(), 9.5);
+
+    let wrong = r#"{"accel":{"value":9.5,"unit":"tu/ts"}}"#;
+    let err = serde_json::from_str::<CompositeStruct>(wrong).unwrap_err();
+    assert!(err.to_string().contains("expected 'tu/ts²'"));
+}
+
+#[test]
+fn serde_with_unit_accepts_legacy_empty_composite_symbol() {
+    // Earlier versions wrote `U::SYMBOL`, which is empty for composite units.
+    let legacy = r#"{"accel":{"value":9.5,"unit":""}}"#;
+    let restored: CompositeStruct = serde_json::from_str(legacy).unwrap();
+    assert_eq!(restored.accel.value(), 9.5);
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // serde_scalar module tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -3,6 +3,7 @@
 
 //! Tests for the stable unit arithmetic layer (UnitDiv / UnitMul).
 
+use qtty_core::typenum::{N1, N2, P1, P2, P3, P4, P5, P6};
 use qtty_core::units::length::{Kilometer, Meter};
 use qtty_core::units::mass::Kilogram;
 use qtty_core::units::time::Second;
@@ -143,6 +144,80 @@ fn plain_multiplication_converts_to_named_unit() {
     assert!((area.value() - 20.0).abs() < 1e-12);
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Integer powers and roots
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn powi_small_exponents_match_multiplication() {
+    let s = Quantity::<Meter>::new(3.0);
+    let same: Quantity<Meter> = s.powi::<P1>();
+    let square: Quantity<Prod<Meter, Meter>> = s.powi::<P2>();
+    let cube: Quantity<Prod<Prod<Meter, Meter>, Meter>> = s.powi::<P3>();
+    assert_eq!(same, s);
+    assert_eq!(square, s * s);
+    assert_eq!(cube, s * s * s);
+}
+
+#[test]
+fn pow_unit_multiplies_dimension_and_ratio() {
+    assert_eq!(
+        <Pow<Meter, P4> as Unit>::Dim::EXPONENTS,
+        Area::EXPONENTS * Area::EXPONENTS
+    );
+    assert_eq!(
+        <Pow<Second, N2> as Unit>::Dim::EXPONENTS,
+        Frequency::EXPONENTS * Frequency::EXPONENTS
+    );
+    assert!((<Pow<Kilometer, P4>>::RATIO - 1e12).abs() < 1e-3);
+    assert_eq!(
+        <Pow<Kilometer, N2>>::EXACT_RATIO,
+        Some(ExactRatio::new(1, 1_000_000))
+    );
+
+    let inv: Quantity<Pow<Kilometer, N1>> = Quantity::<Kilometer>::new(4.0).powi::<N1>();
+    assert!((inv.value() - 0.25).abs() < 1e-12);
+    let per_meter: Quantity<Pow<Meter, N1>> = inv.to();
+    assert!((per_meter.value() - 0.000_25).abs() < 1e-15);
+}
+
+#[test]
+fn roots_undo_powers() {
+    let s = Quantity::<Kilometer>::new(2.0);
+
+    let r: Quantity<Kilometer> = s.powi::<P2>().sqrt();
+    assert!((r.value() - 2.0).abs() < 1e-12);
+    let r: Quantity<Kilometer> = s.powi::<P3>().cbrt();
+    assert!((r.value() - 2.0).abs() < 1e-12);
+
+    let p6 = s.powi::<P6>();
+    let r: Quantity<Kilometer> = p6.root::<P6>();
+    assert!((r.value() - 2.0).abs() < 1e-12);
+    let r: Quantity<Prod<Prod<Kilometer, Kilometer>, Kilometer>> = p6.sqrt();
+    assert!((r.value() - 8.0).abs() < 1e-12);
+    let r: Quantity<Prod<Kilometer, Kilometer>> = p6.cbrt();
+    assert!((r.value() - 4.0).abs() < 1e-12);
+
+    let r: Quantity<Kilometer> = (s * s * (s * s)).root::<P4>();
+    assert!((r.value() - 2.0).abs() < 1e-12);
+    let r: Quantity<Kilometer> = (s * (s * s)).cbrt();
+    assert!((r.value() - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn odd_roots_keep_sign() {
+    let x: Quantity<Pow<Second, P5>> = Quantity::new(-32.0);
+    let r: Quantity<Second> = x.root::<P5>();
+    assert!((r.value() + 2.0).abs() < 1e-12);
+
+    let x: Quantity<Pow<Second, P4>> = Quantity::new(-16.0);
+    assert!(x.root::<P4>().value().is_nan());
+
+    let x: Quantity<Pow<Second, N2>> = Quantity::new(0.25);
+    let r: Quantity<Second> = x.root::<N2>();
+    assert!((r.value() - 2.0).abs() < 1e-12);
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Custom unit tests: downstream users restore arithmetic via macros
 // ─────────────────────────────────────────────────────────────────────────────
@@ -298,3 +373,12 @@ fn _check_prod_fallback() {
     fn assert_prod_m_s(_: Quantity<Prod<Meter, Second>>) {}
     assert_prod_m_s(Quantity::<Meter>::new(1.0) * Quantity::<Second>::new(1.0));
 }
+
+/// Compile-time check: powi::<N> for N ∉ 1..=3 → Pow<U, N>
+fn _check_pow_fallback() {
+    fn assert_pow_m_4(_: Quantity<Pow<Meter, P4>>) {}
+    assert_pow_m_4(Quantity::<Meter>::new(1.0).powi::<P4>());
+
+    fn assert_pow_s_neg_2(_: Quantity<Pow<Second, N2>>) {}
+    assert_pow_s_neg_2(Quantity::<Second>::new(1.0).powi::<N2>());
+}
@@ -9,9 +9,29 @@ macro_rules! touch_units {
     ($($unit:ident),+ $(,)?) => {{
         $(
             assert!(<$unit as Unit>::RATIO.is_finite());
+            if let Some(exact) = <$unit as Unit>::EXACT_RATIO {
+                let ratio = <$unit as Unit>::RATIO;
+                assert!(
+                    ((exact.to_f64() - ratio) / ratio).abs() < 1e-12,
+                    "{}: EXACT_RATIO {} disagrees with RATIO {}",
+                    stringify!($unit),
+                    exact,
+                    ratio,
+                );
+            }
 
             let rendered = format!("{}", Quantity::<$unit>::new(1.25));
             assert!(!rendered.is_empty());
+            // Composite aliases (`Prod`, `Per`, `Pow`) have no long name and
+            // keep their symbol; every derived unit is named and classified.
+            let named = format!("{:#}", Quantity::<$unit>::new(1.25));
+            if <$unit as Unit>::LONG_NAME.is_empty() {
+                assert_eq!(named, rendered);
+            } else {
+                assert!(named.ends_with(<$unit as Unit>::PLURAL), "{named:?}");
+                let system = <$unit as Unit>::SYSTEM;
+                assert!(!system.is_empty(), "{} has no system", stringify!($unit));
+            }
             let lower_exp = format!("{:e}", Quantity::<$unit>::new(1.25));
             assert!(!lower_exp.is_empty());
             let upper_exp = format!("{:E}", Quantity::<$unit>::new(1.25));
@@ -25,6 +25,15 @@
 //! - `symbol = "m"`: displayed unit symbol
 //! - `dimension = SomeDim`: dimension marker type
 //! - `ratio = 1000.0`: conversion ratio to the canonical unit of the dimension
+//! - `exact_ratio = 1000` or `exact_ratio = 5 / 9`: optional exact integer
+//!   fraction equal to `ratio`, used by exact scalar conversions
+//! - `long_name = "kilometre"`: optional singular name. Defaults to the type
+//!   name split into lower-case words (`AstronomicalUnit` → `"astronomical unit"`)
+//! - `plural = "feet"`: optional plural name. Defaults to `long_name` with an
+//!   English `-s`/`-es`/`-ies` ending
+//! - `system = "SI"`: optional unit system label
+//! - `aliases = ["kilometer", "kilometers"]`: optional extra names accepted
+//!   by name lookups
 //! - `crate = qtty`: optional crate path when deriving from a downstream crate
 
 #![deny(missing_docs)]
@@ -36,14 +45,18 @@ use quote::quote;
 use syn::{
     ext::IdentExt,
     parse::{Parse, ParseStream},
-    parse_macro_input, Attribute, DeriveInput, Expr, Ident, LitStr, Path, Token,
+    parse_macro_input,
+    punctuated::Punctuated,
+    Attribute, BinOp, DeriveInput, Expr, Ident, LitStr, Path, Token,
 };
 
 /// Derive `crate::Unit` and a `Display` impl for `crate::Quantity<ThisUnit>`.
 ///
 /// The derive must be paired with a `#[unit(...)]` attribute providing
-/// `symbol`, `dimension`, and `ratio`. Downstream crates using the public
-/// `qtty` facade should also set `crate = qtty`.
+/// `symbol`, `dimension`, and `ratio`, plus an optional `exact_ratio` when the
+/// ratio is an exact integer fraction and optional `long_name`, `plural`,
+/// `system` and `aliases` metadata. Downstream crates using the public `qtty`
+/// facade should also set `crate = qtty`.
 ///
 /// Note that downstream crates only receive the `Unit` impl. Formatting impls
 /// for `qtty::Quantity<CustomUnit, S>` would violate Rust's orphan rules.
@@ -78,11 +91,46 @@ fn derive_unit_impl(input: DeriveInput) -> syn::Result<TokenStream2> {
         .map(|path| quote!(#path))
         .unwrap_or_else(|| quote!(crate));
 
+    let exact_ratio = unit_attr
+        .exact_ratio
+        .as_ref()
+        .map(|expr| {
+            let (numer, denom) = split_exact_ratio(expr)?;
+            Ok::<_, syn::Error>(quote! {
+                const EXACT_RATIO: ::core::option::Option<#crate_path::ExactRatio> =
+                    ::core::option::Option::Some(#crate_path::ExactRatio::new(#numer, #denom));
+            })
+        })
+        .transpose()?;
+
+    let long_name = unit_attr
+        .long_name
+        .as_ref()
+        .map(LitStr::value)
+        .unwrap_or_else(|| default_long_name(&name.to_string()));
+    let plural = unit_attr
+        .plural
+        .as_ref()
+        .map(LitStr::value)
+        .unwrap_or_else(|| default_plural(&long_name));
+    let system = unit_attr.system.as_ref().map(|system| {
+        quote! { const SYSTEM: &'static str = #system; }
+    });
+    let aliases = (!unit_attr.aliases.is_empty()).then(|| {
+        let aliases = &unit_attr.aliases;
+        quote! { const ALIASES: &'static [&'static str] = &[#(#aliases),*]; }
+    });
+
     let expanded = quote! {
         impl #crate_path::Unit for #name {
             const RATIO: f64 = #ratio;
+            #exact_ratio
             type Dim = #dimension;
             const SYMBOL: &'static str = #symbol;
+            const LONG_NAME: &'static str = #long_name;
+            const PLURAL: &'static str = #plural;
+            #system
+            #aliases
         }
 
     };
@@ -94,7 +142,17 @@ fn derive_unit_impl(input: DeriveInput) -> syn::Result<TokenStream2> {
                     // Forward all format flags (precision, width, fill, …) to the
                     // inner scalar so that e.g. `format!("{:.9}", my_au)` works.
                     ::core::fmt::Display::fmt(&self.value(), f)?;
-                    write!(f, " {}", <#name as #crate_path::Unit>::SYMBOL)
+                    // `{:#}` spells the unit out, singular only for a magnitude of one.
+                    if f.alternate() {
+                        let name = if #crate_path::Scalar::abs(self.value()) == <S as #crate_path::Scalar>::ONE {
+                            <#name as #crate_path::Unit>::LONG_NAME
+                        } else {
+                            <#name as #crate_path::Unit>::PLURAL
+                        };
+                        write!(f, " {}", name)
+                    } else {
+                        write!(f, " {}", <#name as #crate_path::Unit>::SYMBOL)
+                    }
                 }
             }
 
@@ -127,13 +185,14 @@ struct UnitAttribute {
     symbol: LitStr,
     dimension: Expr,
     ratio: Expr,
+    exact_ratio: Option<Expr>,
     crate_path: Option<Path>,
+    long_name: Option<LitStr>,
+    plural: Option<LitStr>,
+    system: Option<LitStr>,
+    aliases: Vec<LitStr>,
     // Future extensions:
-    // long_name: Option<LitStr>,
-    // plural: Option<LitStr>,
-    // system: Option<LitStr>,
     // base_unit: Option<bool>,
-    // aliases: Option<Vec<LitStr>>,
 }
 
 impl Parse for UnitAttribute {
@@ -141,7 +200,12 @@ impl Parse for UnitAttribute {
         let mut symbol: Option<LitStr> = None;
         let mut dimension: Option<Expr> = None;
         let mut ratio: Option<Expr> = None;
+        let mut exact_ratio: Option<Expr> = None;
         let mut crate_path: Option<Path> = None;
+        let mut long_name: Option<LitStr> = None;
+        let mut plural: Option<LitStr> = None;
+        let mut system: Option<LitStr> = None;
+        let mut aliases: Vec<LitStr> = Vec::new();
 
         while !input.is_empty() {
             let ident = Ident::parse_any(input)?;
@@ -160,12 +224,27 @@ impl Parse for UnitAttribute {
                 "ratio" => {
                     ratio = Some(input.parse()?);
                 }
+                "exact_ratio" => {
+                    exact_ratio = Some(input.parse()?);
+                }
+                "long_name" => {
+                    long_name = Some(input.parse()?);
+                }
+                "plural" => {
+                    plural = Some(input.parse()?);
+                }
+                "system" => {
+                    system = Some(input.parse()?);
+                }
+                "aliases" => {
+                    let content;
+                    syn::bracketed!(content in input);
+                    aliases = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?
+                        .into_iter()
+                        .collect();
+                }
                 // Future extensions would be handled here:
-                // "long_name" => { ... }
-                // "plural" => { ... }
-                // "system" => { ... }
                 // "base_unit" => { ... }
-                // "aliases" => { ... }
                 other => {
                     return Err(syn::Error::new(
                         ident.span(),
@@ -192,11 +271,93 @@ impl Parse for UnitAttribute {
             symbol,
             dimension,
             ratio,
+            exact_ratio,
             crate_path,
+            long_name,
+            plural,
+            system,
+            aliases,
         })
     }
 }
 
+/// Splits a type name into lower-case words: `AstronomicalUnit` →
+/// `"astronomical unit"`.
+fn default_long_name(ident: &str) -> String {
+    let mut name = String::with_capacity(ident.len() + 4);
+    for (i, ch) in ident.chars().enumerate() {
+        if ch.is_uppercase() && i > 0 {
+            name.push(' ');
+        }
+        name.extend(ch.to_lowercase());
+    }
+    name
+}
+
+/// Regular English plural of `name`: `inch` → `inches`, `century` →
+/// `centuries`, `metre` → `metres`.
+fn default_plural(name: &str) -> String {
+    let sibilant = ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|end| name.ends_with(end));
+    if sibilant {
+        return format!("{name}es");
+    }
+    if let Some(stem) = name.strip_suffix('y') {
+        if !stem.ends_with(['a', 'e', 'i', 'o', 'u']) {
+            return format!("{stem}ies");
+        }
+    }
+    format!("{name}s")
+}
+
+/// Splits `exact_ratio = N / D` into its numerator and denominator tokens.
+///
+/// A bare expression is a whole number, so its denominator is `1`. Both parts
+/// are integer expressions, so a further division inside either of them
+/// (`1 / 2 / 3`, `1 / (2 / 3)`) would silently truncate and is rejected.
+fn split_exact_ratio(expr: &Expr) -> syn::Result<(TokenStream2, TokenStream2)> {
+    match strip_parens(expr) {
+        Expr::Binary(binary) if matches!(binary.op, BinOp::Div(_)) => {
+            let (left, right) = (&binary.left, &binary.right);
+            reject_division(left)?;
+            reject_division(right)?;
+            Ok((quote!(#left), quote!(#right)))
+        }
+        other => {
+            reject_division(other)?;
+            Ok((quote!(#other), quote!(1)))
+        }
+    }
+}
+
+/// Looks through the groups that `macro_rules!` forwarding adds and through
+/// explicit parentheses.
+fn strip_parens(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Group(group) => strip_parens(&group.expr),
+        Expr::Paren(paren) => strip_parens(&paren.expr),
+        other => other,
+    }
+}
+
+/// Errors if `expr` contains a division anywhere outside a nested call.
+fn reject_division(expr: &Expr) -> syn::Result<()> {
+    match strip_parens(expr) {
+        Expr::Binary(binary) if matches!(binary.op, BinOp::Div(_)) => Err(syn::Error::new_spanned(
+            binary,
+            "`exact_ratio` must be `N` or `N / D` with integer `N` and `D`; \
+             chained or nested division is not supported",
+        )),
+        Expr::Binary(binary) => {
+            reject_division(&binary.left)?;
+            reject_division(&binary.right)
+        }
+        Expr::Unary(unary) => reject_division(&unary.expr),
+        _ => Ok(()),
+    }
+}
+
 fn parse_unit_attribute(attrs: &[Attribute]) -> syn::Result<UnitAttribute> {
     for attr in attrs {
         if attr.path().is_ident("unit") {
@@ -341,6 +502,70 @@ mod tests {
         assert!(code.contains("const RATIO : f64 = 1000.0"));
     }
 
+    #[test]
+    fn test_derive_unit_impl_with_exact_ratio() {
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "°R", dimension = Temperature, ratio = 5.0 / 9.0, exact_ratio = 5 / 9)]
+            pub enum Rankine {}
+        };
+
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(code.contains("crate :: ExactRatio :: new (5 , 9)"));
+
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "km", dimension = Length, ratio = 1000.0, exact_ratio = 1_000)]
+            pub enum Kilometer {}
+        };
+
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(code.contains("crate :: ExactRatio :: new (1_000 , 1)"));
+
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "d", dimension = Time, ratio = 86_400.0, exact_ratio = (24 * 3_600))]
+            pub enum Day {}
+        };
+
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(code.contains("crate :: ExactRatio :: new (24 * 3_600 , 1)"));
+
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "x", dimension = Length, ratio = 0.5, exact_ratio = (1 / 2))]
+            pub enum Half {}
+        };
+
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(code.contains("crate :: ExactRatio :: new (1 , 2)"));
+    }
+
+    #[test]
+    fn test_derive_unit_impl_rejects_chained_exact_ratio() {
+        for input in [
+            parse_quote! {
+                #[unit(symbol = "x", dimension = Length, ratio = 1.0 / 6.0, exact_ratio = 1 / 2 / 3)]
+                pub enum Chained {}
+            },
+            parse_quote! {
+                #[unit(symbol = "x", dimension = Length, ratio = 1.5, exact_ratio = 1 / (2 / 3))]
+                pub enum Nested {}
+            },
+        ] {
+            let input: DeriveInput = input;
+            let err = derive_unit_impl(input).unwrap_err();
+            assert!(err.to_string().contains("chained or nested division"));
+        }
+    }
+
+    #[test]
+    fn test_derive_unit_impl_without_exact_ratio() {
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "rad", dimension = Angular, ratio = 57.29)]
+            pub enum Radian {}
+        };
+
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(!code.contains("EXACT_RATIO"));
+    }
+
     #[test]
     fn test_derive_unit_impl_with_downstream_crate_path() {
         let input: DeriveInput = parse_quote! {
@@ -384,6 +609,56 @@ mod tests {
         assert_eq!(attr.symbol.value(), "km");
     }
 
+    #[test]
+    fn test_unit_attribute_parse_metadata() {
+        let tokens = quote! {
+            symbol = "ft", dimension = Length, ratio = 0.3048,
+            long_name = "foot", plural = "feet", system = "customary",
+            aliases = ["foots", "ft."],
+        };
+        let attr: UnitAttribute = syn::parse2(tokens).unwrap();
+        assert_eq!(attr.long_name.unwrap().value(), "foot");
+        assert_eq!(attr.plural.unwrap().value(), "feet");
+        assert_eq!(attr.system.unwrap().value(), "customary");
+        let aliases: Vec<_> = attr.aliases.iter().map(LitStr::value).collect();
+        assert_eq!(aliases, ["foots", "ft."]);
+    }
+
+    #[test]
+    fn test_derive_unit_impl_metadata() {
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "km", dimension = Length, ratio = 1000.0,
+                   long_name = "kilometre", system = "SI", aliases = ["kilometer"])]
+            pub enum Kilometer {}
+        };
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(code.contains("const LONG_NAME : & 'static str = \"kilometre\""));
+        assert!(code.contains("const PLURAL : & 'static str = \"kilometres\""));
+        assert!(code.contains("const SYSTEM : & 'static str = \"SI\""));
+        assert!(code.contains("const ALIASES : & 'static [& 'static str] = & [\"kilometer\"]"));
+
+        let input: DeriveInput = parse_quote! {
+            #[unit(symbol = "au", dimension = Length, ratio = 1.0)]
+            pub enum AstronomicalUnit {}
+        };
+        let code = derive_unit_impl(input).unwrap().to_string();
+        assert!(code.contains("const LONG_NAME : & 'static str = \"astronomical unit\""));
+        assert!(code.contains("const PLURAL : & 'static str = \"astronomical units\""));
+        assert!(!code.contains("SYSTEM"));
+        assert!(!code.contains("ALIASES"));
+    }
+
+    #[test]
+    fn test_default_names() {
+        assert_eq!(default_long_name("Meter"), "meter");
+        assert_eq!(default_long_name("NauticalMile"), "nautical mile");
+        assert_eq!(default_plural("inch"), "inches");
+        assert_eq!(default_plural("solar mass"), "solar masses");
+        assert_eq!(default_plural("century"), "centuries");
+        assert_eq!(default_plural("day"), "days");
+        assert_eq!(default_plural("metre"), "metres");
+    }
+
     #[test]
     fn test_parse_empty_attribute() {
         let tokens = quote! {};
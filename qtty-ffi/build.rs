@@ -37,6 +37,8 @@ fn main() {
     generate_unit_enum(&resolved, &out_dir);
     generate_unit_names(&resolved, &out_dir);
     generate_unit_names_cstr(&resolved, &out_dir);
+    generate_unit_long_names(&resolved, &out_dir);
+    generate_unit_long_names_cstr(&resolved, &out_dir);
     generate_unit_symbols(&resolved, &out_dir);
     generate_from_u32(&resolved, &out_dir);
     generate_registry(&resolved, &out_dir);
@@ -260,6 +262,47 @@ fn generate_unit_names_cstr(units: &[ResolvedUnit], out_dir: &str) {
     fs::write(&dest_path, code).expect("Failed to write unit_names_cstr.rs");
 }
 
+fn generate_unit_long_names(units: &[ResolvedUnit], out_dir: &str) {
+    // Long names are derived from qtty-core at compile time via the Unit trait.
+    let mut code = String::from(
+        "// Auto-generated — long names derived from <Type as qtty::Unit>::LONG_NAME.\n\
+         match self {\n",
+    );
+    for unit in units {
+        code.push_str(&format!(
+            "    UnitId::{} => <{} as qtty::Unit>::LONG_NAME,\n",
+            unit.ffi_name, unit.rust_type_path
+        ));
+    }
+    code.push_str("}\n");
+
+    let dest_path = PathBuf::from(out_dir).join("unit_long_names.rs");
+    fs::write(&dest_path, code).expect("Failed to write unit_long_names.rs");
+}
+
+fn generate_unit_long_names_cstr(units: &[ResolvedUnit], out_dir: &str) {
+    // Long names are derived from qtty-core at compile time via the Unit trait.
+    let mut code = String::from(
+        "// Auto-generated — long names derived from <Type as qtty::Unit>::LONG_NAME.\n\
+         match self {\n",
+    );
+    for unit in units {
+        code.push_str(&format!(
+            "    UnitId::{} => {{\n\
+             \x20       const NAME: &str = <{} as qtty::Unit>::LONG_NAME;\n\
+             \x20       const CSTR: [u8; NAME.len() + 1] = nul_terminated(NAME);\n\
+             \x20       let cstr: &'static [u8; NAME.len() + 1] = &CSTR;\n\
+             \x20       cstr.as_ptr() as *const c_char\n\
+             \x20   }}\n",
+            unit.ffi_name, unit.rust_type_path
+        ));
+    }
+    code.push_str("}\n");
+
+    let dest_path = PathBuf::from(out_dir).join("unit_long_names_cstr.rs");
+    fs::write(&dest_path, code).expect("Failed to write unit_long_names_cstr.rs");
+}
+
 fn generate_unit_symbols(units: &[ResolvedUnit], out_dir: &str) {
     // Symbols are derived from qtty-core at compile time via the Unit trait.
     let mut code = String::from(
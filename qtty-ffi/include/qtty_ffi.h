@@ -2261,6 +2261,21 @@ QttyStatus qtty_units_compatible(uint32_t a_id, uint32_t b_id, bool *out);
  */
 const char *qtty_unit_name(uint32_t unit_id);
 
+/*
+ Gets the long name of a unit (e.g. `"kilometre"`) as a NUL-terminated C string.
+
+ # Arguments
+
+ * `unit_id` - Raw `uint32_t` unit identifier
+
+ # Returns
+
+ A pointer to a static, NUL-terminated C string, or null if the unit ID is
+ not recognized. The pointer points to static memory; the caller must not
+ free or modify it.
+ */
+const char *qtty_unit_long_name(uint32_t unit_id);
+
 /*
  Returns the FFI ABI version (major*10000 + minor*100 + patch).
 
@@ -33,5 +33,8 @@ pub use derived::{qtty_derived_convert, qtty_derived_make};
 pub use quantity::{
     qtty_quantity_convert, qtty_quantity_convert_value, qtty_quantity_format, qtty_quantity_make,
 };
-pub use unit::{qtty_unit_dimension, qtty_unit_is_valid, qtty_unit_name, qtty_units_compatible};
+pub use unit::{
+    qtty_unit_dimension, qtty_unit_is_valid, qtty_unit_long_name, qtty_unit_name,
+    qtty_units_compatible,
+};
 pub use version::qtty_ffi_version;
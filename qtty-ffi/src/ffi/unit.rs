@@ -119,6 +119,27 @@ pub extern "C" fn qtty_unit_name(unit_id: u32) -> *const c_char {
     })
 }
 
+/// Gets the long name of a unit (e.g. `"kilometre"`) as a NUL-terminated C string.
+///
+/// # Arguments
+///
+/// * `unit_id` - Raw `uint32_t` unit identifier
+///
+/// # Returns
+///
+/// A pointer to a static, NUL-terminated C string, or null if the unit ID is
+/// not recognized. The pointer points to static memory; the caller must not
+/// free or modify it.
+#[no_mangle]
+pub extern "C" fn qtty_unit_long_name(unit_id: u32) -> *const c_char {
+    catch_panic_or!(core::ptr::null(), {
+        match UnitId::from_u32(unit_id) {
+            Some(unit) if registry::meta(unit).is_some() => unit.long_name_cstr(),
+            _ => core::ptr::null(),
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
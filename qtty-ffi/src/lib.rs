@@ -85,7 +85,7 @@ mod types;
 pub use ffi::{
     qtty_derived_convert, qtty_derived_make, qtty_ffi_version, qtty_quantity_convert,
     qtty_quantity_convert_value, qtty_quantity_format, qtty_quantity_make, qtty_unit_dimension,
-    qtty_unit_is_valid, qtty_unit_name, qtty_units_compatible,
+    qtty_unit_is_valid, qtty_unit_long_name, qtty_unit_name, qtty_units_compatible,
 };
 
 // Named unit ID constants for C caller ergonomics — each is the u32 discriminant
@@ -168,6 +168,22 @@ pub enum DimensionId {
 // - Mass units: 4xxxx (40000-49999), Power units: 5xxxx (50000-59999)
 include!(concat!(env!("OUT_DIR"), "/unit_id_enum.rs"));
 
+/// Copies `name` into a NUL-terminated byte array of length `N`.
+///
+/// `N` must be `name.len() + 1`; used by the generated [`UnitId::long_name_cstr`] table.
+const fn nul_terminated<const N: usize>(name: &str) -> [u8; N] {
+    let bytes = name.as_bytes();
+    assert!(bytes.len() + 1 == N);
+    let mut out = [0u8; N];
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(bytes[i] != 0, "unit names must not contain NUL");
+        out[i] = bytes[i];
+        i += 1;
+    }
+    out
+}
+
 impl UnitId {
     /// Returns the unit name as a static NUL-terminated C string.
     ///
@@ -183,6 +199,24 @@ impl UnitId {
         include!(concat!(env!("OUT_DIR"), "/unit_names.rs"))
     }
 
+    /// Returns the unit's long name (e.g. `"kilometre"`) as a static
+    /// NUL-terminated C string.
+    ///
+    /// The text comes from [`qtty::Unit::LONG_NAME`], so it always matches the
+    /// Rust-side metadata. This is safe to call from C code and returns a
+    /// pointer to static memory.
+    #[inline]
+    pub const fn long_name_cstr(&self) -> *const c_char {
+        include!(concat!(env!("OUT_DIR"), "/unit_long_names_cstr.rs"))
+    }
+
+    /// Returns the unit's long name (e.g. `"kilometre"`) as a Rust string
+    /// slice, taken from [`qtty::Unit::LONG_NAME`].
+    #[inline]
+    pub const fn long_name(&self) -> &'static str {
+        include!(concat!(env!("OUT_DIR"), "/unit_long_names.rs"))
+    }
+
     /// Returns the unit symbol as a Rust string slice (e.g., "m", "km", "s").
     #[inline]
     pub const fn symbol(&self) -> &'static str {
@@ -659,6 +693,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unit_long_name_matches_long_name_cstr() {
+        for unit in [UnitId::Kilometer, UnitId::Second, UnitId::Degree] {
+            let cstr = unsafe { core::ffi::CStr::from_ptr(unit.long_name_cstr()) };
+            assert_eq!(cstr.to_str().unwrap(), unit.long_name());
+        }
+        assert_eq!(UnitId::Kilometer.long_name(), "kilometre");
+        assert_eq!(UnitId::Kilometer.name(), "Kilometer");
+    }
+
     // ─── QttyQuantity method coverage ────────────────────────────────────────
 
     #[test]
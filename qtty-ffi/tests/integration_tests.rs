@@ -10,8 +10,8 @@ use core::f64::consts::PI;
 use qtty_ffi::{
     qtty_derived_convert, qtty_derived_make, qtty_ffi_version, qtty_quantity_convert,
     qtty_quantity_convert_value, qtty_quantity_format, qtty_quantity_make, qtty_unit_dimension,
-    qtty_unit_is_valid, qtty_unit_name, qtty_units_compatible, DimensionId, QttyDerivedQuantity,
-    QttyQuantity, QttyStatus, UnitId, QTTY_FMT_LOWER_EXP, QTTY_FMT_UPPER_EXP,
+    qtty_unit_is_valid, qtty_unit_long_name, qtty_unit_name, qtty_units_compatible, DimensionId,
+    QttyDerivedQuantity, QttyQuantity, QttyStatus, UnitId, QTTY_FMT_LOWER_EXP, QTTY_FMT_UPPER_EXP,
 };
 use std::ffi::CStr;
 
@@ -375,6 +375,32 @@ fn test_unit_names() {
     }
 }
 
+#[test]
+fn test_unit_long_names() {
+    let test_cases = [
+        (UnitId::Meter, "metre"),
+        (UnitId::Kilometer, "kilometre"),
+        (UnitId::Second, "second"),
+        (UnitId::Degree, "degree"),
+    ];
+    for (unit, expected_name) in test_cases {
+        let name_ptr = qtty_unit_long_name(unit as u32);
+        assert!(
+            !name_ptr.is_null(),
+            "Long name for {:?} should not be null",
+            unit
+        );
+        let name = unsafe { CStr::from_ptr(name_ptr) };
+        assert_eq!(
+            name.to_str().unwrap(),
+            expected_name,
+            "Long name mismatch for {:?}",
+            unit
+        );
+    }
+    assert!(qtty_unit_long_name(0).is_null());
+}
+
 #[test]
 fn test_unit_name_invalid_id_returns_null() {
     let ptr = qtty_unit_name(0);
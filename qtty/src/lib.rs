@@ -73,7 +73,12 @@
 //! - `f32` - single precision floating point (use `qtty::f32::*`)
 //! - `i8`, `i16`, `i32`, `i64`, `i128` - signed integers
 //!   (use `qtty::i8::*`, `qtty::i16::*`, `qtty::i32::*`, `qtty::i64::*`, `qtty::i128::*`)
+//! - `u8`, `u16`, `u32`, `u64`, `u128`, `usize` - unsigned integers
+//!   (use `qtty::u8::*`, `qtty::u16::*`, `qtty::u32::*`, `qtty::u64::*`, `qtty::u128::*`,
+//!   `qtty::usize::*`); these cannot be negated, since they do not implement [`SignedScalar`]
 //! - `Rational64` - exact rational (feature `scalar-rational`)
+//! - `FixedI32<Frac>`, `FixedI64<Frac>` - fixed point from the `fixed` crate (feature `fixed`)
+//! - `f16`, `bf16` - half-precision floats from the `half` crate (feature `half`)
 //!
 //! Integer quantities provide compile-time unit safety for discrete values.
 //! They support basic arithmetic and lossy unit conversion via
@@ -90,7 +95,7 @@
 //! - `qtty::area`, `qtty::volume`, `qtty::force`, `qtty::energy`
 //! - `qtty::accel` (`Length / Time²` aliases)
 //! - `qtty::pressure` (pressure units: Pa, hPa, kPa, bar)
-//! - `qtty::temperature` (thermodynamic temperature units: K)
+//! - `qtty::temperature` (thermodynamic temperature units: K, °R; affine °C/°F points)
 //! - `qtty::unit` (type-level unit markers)
 //! - `qtty::f32` (all units with `f32` scalar)
 //! - `qtty::f64` (all units with `f64` scalar - same as root)
@@ -99,14 +104,24 @@
 //! - `qtty::i32` (all units with `i32` scalar)
 //! - `qtty::i64` (all units with `i64` scalar)
 //! - `qtty::i128` (all units with `i128` scalar)
+//! - `qtty::u8` (all units with `u8` scalar)
+//! - `qtty::u16` (all units with `u16` scalar)
+//! - `qtty::u32` (all units with `u32` scalar)
+//! - `qtty::u64` (all units with `u64` scalar)
+//! - `qtty::u128` (all units with `u128` scalar)
+//! - `qtty::usize` (all units with `usize` scalar)
 //!
 //! # Feature flags
 //!
 //! - `std` (default): enables `std` support in `qtty-core`.
 //! - `cross-unit-ops` (default): enables direct cross-unit comparison operators (`==`, `<`, etc.) for built-in units.
-//! - `alloc`: enables heap-backed helpers (like `qtty_vec!(vec ...)`) in `no_std` builds.
+//! - `alloc`: enables heap-backed helpers ([`QuantityVec`], `qtty_vec!(vec ...)` and `qtty_vec!(qvec ...)`) in `no_std` builds.
 //! - `serde`: enables `serde` support for `Quantity<U, S>`; serialization is the raw scalar value.
 //! - `scalar-rational`: enables `num_rational::Rational64` as a scalar type.
+//! - `fixed`: enables `fixed::FixedI32<Frac>` and `FixedI64<Frac>` as scalar types.
+//! - `half`: enables `half::f16` and `half::bf16` as scalar types (`Quantity16`, `QuantityBf16`).
+//! - `parse`: enables parsing unit expressions into [`DynUnit`] and `FromStr` for quantities
+//!   (`"12.5 km/s".parse::<Velocity<Kilometer, Second>>()`).
 //!
 //! # Custom Units
 //!
@@ -158,14 +173,17 @@
 //! The following items are **explicitly excluded from the semver guarantee**
 //! even though they appear in the compiled crate:
 //!
-//! - [`Dim`], [`DimDiv`], [`DimMul`] — typenum-driven dimension markers used
-//!   internally by `impl_unit_*` macros.  Their shape will change if the
-//!   underlying numeric representation is ever replaced.
-//! - [`UnitDiv`], [`UnitMul`] — arithmetic-layer traits whose associated-type
-//!   signatures depend on the dimension representation above.
-//!
-//! All five are marked `#[doc(hidden)]`.  Do not depend on their concrete
-//! types in downstream code.
+//! - [`Dim`], [`DimDiv`], [`DimMul`], [`DimPow`] — typenum-driven dimension
+//!   markers used internally by `impl_unit_*` macros.  Their shape will change
+//!   if the underlying numeric representation is ever replaced.
+//! - [`UnitDiv`], [`UnitMul`], [`UnitPow`], [`UnitRoot`] — arithmetic-layer
+//!   traits whose associated-type signatures depend on the dimension
+//!   representation above.
+//!
+//! All of these are marked `#[doc(hidden)]`.  Do not depend on their concrete
+//! types in downstream code.  The [`typenum`] re-export exists only so that
+//! exponents such as `typenum::P3` can be named in [`Quantity::powi`] calls;
+//! it tracks whichever `typenum` release `qtty-core` depends on.
 #![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 
@@ -173,18 +191,22 @@
 extern crate alloc;
 
 pub use qtty_core::{
-    impl_unit_arithmetic_pairs, impl_unit_arithmetic_pairs_between, impl_unit_cross_unit_ops,
-    impl_unit_cross_unit_ops_between, impl_unit_division_pairs, impl_unit_division_pairs_between,
-    impl_unit_from_conversions, impl_unit_from_conversions_between, impl_unit_multiplication_pairs,
+    assert_qty_eq, impl_unit_arithmetic_pairs, impl_unit_arithmetic_pairs_between,
+    impl_unit_cross_unit_ops, impl_unit_cross_unit_ops_between, impl_unit_division_pairs,
+    impl_unit_division_pairs_between, impl_unit_from_conversions,
+    impl_unit_from_conversions_between, impl_unit_multiplication_pairs,
     impl_unit_multiplication_pairs_between,
 };
 pub use qtty_core::{
     Acceleration, AmountOfSubstance, Angular, AngularRate, Area, Capacitance, Charge, Current,
-    Density, Dimension, Dimensionless, Energy, Exact, Force, Frequency, Illuminance, Inductance,
+    Density, DimExponents, Dimension, DimensionMismatch, Dimensionless, DynQuantity, DynUnit,
+    Energy, EngineeringDisplay, Exact, ExactRatio, Force, Frequency, Illuminance, Inductance,
     IntegerScalar, Length, LuminousFlux, LuminousIntensity, MagneticFlux, MagneticFluxDensity,
-    Mass, Per, Power, Pressure, Prod, Quantity, Quantity32, Quantity64, QuantityI128, QuantityI16,
-    QuantityI32, QuantityI64, QuantityI8, Real, Resistance, Scalar, Temperature, Time,
-    Transcendental, Unit, Velocity, Voltage, Volume,
+    Mass, Measurement, Per, Pow, Power, Pressure, Prod, Quantity, Quantity32, Quantity64,
+    QuantityI128, QuantityI16, QuantityI32, QuantityI64, QuantityI8, QuantityRange, QuantityU128,
+    QuantityU16, QuantityU32, QuantityU64, QuantityU8, QuantityUsize, QuantityVector2,
+    QuantityVector3, RangeLinspace, RangeStepBy, Real, Resistance, Scalar, SignedScalar,
+    Temperature, Time, Transcendental, Unit, UnitSymbol, Velocity, Voltage, Volume,
 };
 
 // `UnitDiv`, `UnitMul`, and the dimension-level traits are needed by the
@@ -200,8 +222,9 @@ pub use qtty_core::{
 // are implementation details of the macro-generated arithmetic layer.
 // Specifically:
 //
-// * `Dim` and its `DimDiv`/`DimMul` associated types are driven by
-//   `typenum` integers.  Replacing `typenum` would be a breaking change to
+// * `Dim` and its `DimDiv`/`DimMul`/`DimPow` associated types are driven by
+//   `typenum` integers.  The same holds for `UnitPow`/`UnitRoot`, which are
+//   keyed on `typenum` exponents.  Replacing `typenum` would be a breaking change to
 //   these types, and that replacement is not considered a breaking change
 //   for `qtty`'s public API.
 // * The `impl_unit_*` macros re-export these at their expansion sites so
@@ -211,11 +234,46 @@ pub use qtty_core::{
 // application code, consider opening an issue — that is a sign of a missing
 // ergonomic abstraction in the public API.
 #[doc(hidden)]
-pub use qtty_core::{Dim, DimDiv, DimMul, UnitDiv, UnitMul, UnitSqrt};
+pub use qtty_core::{Dim, DimDiv, DimMul, DimPow, UnitDiv, UnitMul, UnitPow, UnitRoot, UnitSqrt};
+
+/// Type-level integers used as exponents by [`Pow`] and [`Quantity::powi`].
+pub use qtty_core::typenum;
+
+/// Runtime catalogue of the built-in units enabled by this crate's features.
+pub use qtty_core::catalog;
+
+/// Zero-copy views between quantity slices and scalar slices.
+pub use qtty_core::slice;
+
+#[cfg(feature = "alloc")]
+pub use qtty_core::QuantityVec;
+
+#[cfg(feature = "ndarray")]
+pub use qtty_core::QuantityArray;
+
+#[cfg(feature = "nalgebra")]
+pub use qtty_core::QVector3;
+
+/// `proptest` strategies for quantities.
+#[cfg(feature = "proptest")]
+pub use qtty_core::proptest;
+
+/// Forward-mode automatic differentiation with unit-typed derivatives.
+#[cfg(feature = "autodiff")]
+pub use qtty_core::autodiff;
+
+#[cfg(feature = "rand")]
+pub use qtty_core::{QuantityLogNormal, QuantityNormal, UniformQuantity};
+
+#[cfg(feature = "half")]
+pub use qtty_core::{Quantity16, QuantityBf16};
 
 #[cfg(feature = "scalar-rational")]
 pub use qtty_core::QuantityRational;
 
+#[cfg(feature = "parse")]
+pub use qtty_core::{ParseQuantityError, ParseUnitError};
+
 #[cfg(all(feature = "serde", feature = "std"))]
 pub use qtty_core::serde_with_unit;
 
@@ -386,6 +444,12 @@ pub mod i16;
 pub mod i32;
 pub mod i64;
 pub mod i8;
+pub mod u128;
+pub mod u16;
+pub mod u32;
+pub mod u64;
+pub mod u8;
+pub mod usize;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Dimension modules (re-exported from qtty-core)
@@ -428,7 +492,9 @@ pub use qtty_core::units::frequency;
 /// [`Second`]. Use this module when generic code needs the unit marker itself,
 /// for example `Quantity<unit::Meter, S>` or `to::<unit::Kilometer>()`.
 pub mod unit {
-    pub use qtty_core::{Per, Prod, Unit, UnitDiv, UnitMul, UnitSqrt};
+    pub use qtty_core::{
+        ExactRatio, Per, Pow, Prod, Unit, UnitDiv, UnitMul, UnitPow, UnitRoot, UnitSqrt, UnitSymbol,
+    };
 
     #[cfg(feature = "navigation")]
     pub use qtty_core::units::angular::Gradian;
@@ -654,6 +720,8 @@ pub mod __private {
 ///   `qtty::qtty_vec!(Second; 1.0, 2.0, 3.0)`
 /// - Vector:
 ///   `qtty::qtty_vec!(vec Second; 1.0, 2.0, 3.0)` (requires `std` or `alloc`)
+/// - Owning buffer ([`QuantityVec`]):
+///   `qtty::qtty_vec!(qvec Second; 1.0, 2.0, 3.0)` (requires `std` or `alloc`)
 ///
 /// # Examples
 ///
@@ -665,6 +733,9 @@ pub mod __private {
 ///
 /// let samples: Vec<Second> = qtty::qtty_vec!(vec Second; 1.0, 2.0, 3.0);
 /// assert_eq!(samples.len(), 3);
+///
+/// let buffer = qtty::qtty_vec!(qvec Second; 1.0, 2.0, 3.0);
+/// assert_eq!(buffer.to::<qtty::unit::Millisecond>().as_values(), &[1000.0, 2000.0, 3000.0]);
 /// ```
 #[cfg(any(feature = "std", feature = "alloc"))]
 #[macro_export]
@@ -672,6 +743,11 @@ macro_rules! qtty_vec {
     (vec $unit:ty; $($value:expr),* $(,)?) => {
         <$crate::__private::Vec<$unit>>::from([$(<$unit>::new($value)),*])
     };
+    (qvec $unit:ty; $($value:expr),* $(,)?) => {
+        <$crate::QuantityVec<_, _> as ::core::iter::FromIterator<$unit>>::from_iter([
+            $(<$unit>::new($value)),*
+        ])
+    };
     ($unit:ty; $($value:expr),* $(,)?) => {
         [$(<$unit>::new($value)),*]
     };
@@ -683,6 +759,12 @@ macro_rules! qtty_vec {
     (vec $unit:ty; $($value:expr),* $(,)?) => {
         compile_error!(
             "`qtty::qtty_vec!(vec ...)` requires the `std` or `alloc` feature. \
+Use `qtty::qtty_vec!(Unit; ...)` in pure `no_std` builds."
+        )
+    };
+    (qvec $unit:ty; $($value:expr),* $(,)?) => {
+        compile_error!(
+            "`qtty::qtty_vec!(qvec ...)` requires the `std` or `alloc` feature. \
 Use `qtty::qtty_vec!(Unit; ...)` in pure `no_std` builds."
         )
     };
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Re-exports of quantity types specialized to `u128` scalar.
+//!
+//! This module provides type aliases for all unit types using `u128` as the
+//! underlying scalar type. Unsigned quantities provide compile-time unit safety
+//! for very large non-negative counts.
+//!
+//! Unsigned quantities support basic arithmetic but **not** negation or unit
+//! conversion via [`to()`](crate::Quantity::to). Use
+//! [`to_lossy()`](crate::Quantity::to_lossy) for lossy (truncating) conversion
+//! between units.
+//!
+//! # Example
+//!
+//! ```rust
+//! use qtty::u128::{Nanosecond, Year};
+//!
+//! let span: Year = Year::new(1_000_000);
+//! let ns: Nanosecond = span.to_lossy();
+//! assert!(ns.value() > u64::MAX as u128);
+//! ```
+
+macro_rules! _alias {
+    ($($unit:ident),+ $(,)?) => {
+        $(pub type $unit = $crate::Quantity<$crate::unit::$unit, u128>;)+
+    };
+}
+
+crate::__qtty_invoke_all_inventories!(_alias);
+crate::__qtty_invoke_optional_inventories!(_alias);
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Re-exports of quantity types specialized to `u16` scalar.
+//!
+//! This module provides type aliases for all unit types using `u16` as the
+//! underlying scalar type. Unsigned quantities provide compile-time unit safety
+//! for 16-bit raw readings (ADC samples, encoder positions, etc.).
+//!
+//! Unsigned quantities support basic arithmetic but **not** negation or unit
+//! conversion via [`to()`](crate::Quantity::to). Use
+//! [`to_lossy()`](crate::Quantity::to_lossy) for lossy (truncating) conversion
+//! between units.
+//!
+//! # Example
+//!
+//! ```rust
+//! use qtty::u16::{Meter, Millimeter};
+//!
+//! let reading: Millimeter = Millimeter::new(4_095);
+//! let m: Meter = reading.to_lossy();
+//! assert_eq!(m.value(), 4);
+//! ```
+
+macro_rules! _alias {
+    ($($unit:ident),+ $(,)?) => {
+        $(pub type $unit = $crate::Quantity<$crate::unit::$unit, u16>;)+
+    };
+}
+
+crate::__qtty_invoke_all_inventories!(_alias);
+crate::__qtty_invoke_optional_inventories!(_alias);
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Re-exports of quantity types specialized to `u32` scalar.
+//!
+//! This module provides type aliases for all unit types using `u32` as the
+//! underlying scalar type. Unsigned quantities provide compile-time unit safety
+//! for counters that never go negative (tick counts, sample indices, etc.).
+//!
+//! Unsigned quantities support basic arithmetic but **not** negation or unit
+//! conversion via [`to()`](crate::Quantity::to). Use
+//! [`to_lossy()`](crate::Quantity::to_lossy) for lossy (truncating) conversion
+//! between units.
+//!
+//! # Example
+//!
+//! ```rust
+//! use qtty::u32::{Millisecond, Second};
+//!
+//! let ticks: Millisecond = Millisecond::new(1_500);
+//! let s: Second = ticks.to_lossy();
+//! assert_eq!(s.value(), 1);
+//! ```
+
+macro_rules! _alias {
+    ($($unit:ident),+ $(,)?) => {
+        $(pub type $unit = $crate::Quantity<$crate::unit::$unit, u32>;)+
+    };
+}
+
+crate::__qtty_invoke_all_inventories!(_alias);
+crate::__qtty_invoke_optional_inventories!(_alias);
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Re-exports of quantity types specialized to `u64` scalar.
+//!
+//! This module provides type aliases for all unit types using `u64` as the
+//! underlying scalar type. Unsigned quantities provide compile-time unit safety
+//! for timestamps and large counters (nanosecond clocks, byte totals, etc.).
+//!
+//! Unsigned quantities support basic arithmetic but **not** negation or unit
+//! conversion via [`to()`](crate::Quantity::to). Use
+//! [`to_lossy()`](crate::Quantity::to_lossy) for lossy (truncating) conversion
+//! between units.
+//!
+//! # Example
+//!
+//! ```rust
+//! use qtty::u64::{Nanosecond, Second};
+//!
+//! let uptime: Second = Second::new(86_400);
+//! let ns: Nanosecond = uptime.to_lossy();
+//! assert_eq!(ns.value(), 86_400_000_000_000);
+//! ```
+
+macro_rules! _alias {
+    ($($unit:ident),+ $(,)?) => {
+        $(pub type $unit = $crate::Quantity<$crate::unit::$unit, u64>;)+
+    };
+}
+
+crate::__qtty_invoke_all_inventories!(_alias);
+crate::__qtty_invoke_optional_inventories!(_alias);
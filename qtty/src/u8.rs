@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Re-exports of quantity types specialized to `u8` scalar.
+//!
+//! This module provides type aliases for all unit types using `u8` as the
+//! underlying scalar type. Unsigned quantities provide compile-time unit safety
+//! for 8-bit raw readings (ADC samples, sensor registers, etc.).
+//!
+//! Unsigned quantities support basic arithmetic but **not** negation or unit
+//! conversion via [`to()`](crate::Quantity::to). Use
+//! [`to_lossy()`](crate::Quantity::to_lossy) for lossy (truncating) conversion
+//! between units.
+//!
+//! # Example
+//!
+//! ```rust
+//! use qtty::u8::Millimeter;
+//!
+//! let gap: Millimeter = Millimeter::new(200);
+//! assert_eq!(gap.value(), 200);
+//! ```
+
+macro_rules! _alias {
+    ($($unit:ident),+ $(,)?) => {
+        $(pub type $unit = $crate::Quantity<$crate::unit::$unit, u8>;)+
+    };
+}
+
+crate::__qtty_invoke_all_inventories!(_alias);
+crate::__qtty_invoke_optional_inventories!(_alias);
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: BSD-3-Clause
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Re-exports of quantity types specialized to `usize` scalar.
+//!
+//! This module provides type aliases for all unit types using `usize` as the
+//! underlying scalar type. Unsigned quantities provide compile-time unit safety
+//! for sizes and indices that interoperate with `len()` and indexing.
+//!
+//! Unsigned quantities support basic arithmetic but **not** negation or unit
+//! conversion via [`to()`](crate::Quantity::to). Use
+//! [`to_lossy()`](crate::Quantity::to_lossy) for lossy (truncating) conversion
+//! between units.
+//!
+//! # Example
+//!
+//! ```rust
+//! use qtty::usize::Meter;
+//!
+//! let spacing: Meter = Meter::new(3);
+//! let total = spacing * [1, 2, 3].len();
+//! assert_eq!(total.value(), 9);
+//! ```
+
+macro_rules! _alias {
+    ($($unit:ident),+ $(,)?) => {
+        $(pub type $unit = $crate::Quantity<$crate::unit::$unit, usize>;)+
+    };
+}
+
+crate::__qtty_invoke_all_inventories!(_alias);
+crate::__qtty_invoke_optional_inventories!(_alias);
@@ -355,6 +355,17 @@ fn qtty_values_macro_builds_seconds_vec() {
     assert_abs_diff_eq!(dt[1].value(), 63.83, epsilon = 1e-12);
 }
 
+#[test]
+fn qtty_values_macro_builds_seconds_quantity_vec() {
+    let dt: qtty::QuantityVec<qtty::unit::Second> =
+        qtty::qtty_vec!(qvec Second; 56.86, 63.83, 70.0);
+    assert_eq!(dt.len(), 3);
+    assert_abs_diff_eq!(dt[1].value(), 63.83, epsilon = 1e-12);
+
+    let empty = qtty::qtty_vec!(qvec Second;);
+    assert!(empty.is_empty());
+}
+
 #[test]
 fn per_unit_division_creates_composite() {
     let d = Kilometer::new(100.0);
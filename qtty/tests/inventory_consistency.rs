@@ -123,6 +123,66 @@ macro_rules! assert_i128_alias_exists {
     };
 }
 
+macro_rules! assert_u8_alias_exists {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            const _: () = {
+                fn _check(_: qtty::u8::$name) {}
+            };
+        )+
+    };
+}
+
+macro_rules! assert_u16_alias_exists {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            const _: () = {
+                fn _check(_: qtty::u16::$name) {}
+            };
+        )+
+    };
+}
+
+macro_rules! assert_u32_alias_exists {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            const _: () = {
+                fn _check(_: qtty::u32::$name) {}
+            };
+        )+
+    };
+}
+
+macro_rules! assert_u64_alias_exists {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            const _: () = {
+                fn _check(_: qtty::u64::$name) {}
+            };
+        )+
+    };
+}
+
+macro_rules! assert_u128_alias_exists {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            const _: () = {
+                fn _check(_: qtty::u128::$name) {}
+            };
+        )+
+    };
+}
+
+macro_rules! assert_usize_alias_exists {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            const _: () = {
+                fn _check(_: qtty::usize::$name) {}
+            };
+        )+
+    };
+}
+
 // ── Unit marker re-exports (qtty::unit::*) ──────────────────────────────────
 
 qtty::__qtty_invoke_all_inventories!(assert_unit_reexported);
@@ -149,3 +209,38 @@ qtty::__qtty_invoke_all_inventories!(assert_i64_alias_exists);
 qtty::__qtty_invoke_optional_inventories!(assert_i64_alias_exists);
 qtty::__qtty_invoke_all_inventories!(assert_i128_alias_exists);
 qtty::__qtty_invoke_optional_inventories!(assert_i128_alias_exists);
+qtty::__qtty_invoke_all_inventories!(assert_u8_alias_exists);
+qtty::__qtty_invoke_optional_inventories!(assert_u8_alias_exists);
+qtty::__qtty_invoke_all_inventories!(assert_u16_alias_exists);
+qtty::__qtty_invoke_optional_inventories!(assert_u16_alias_exists);
+qtty::__qtty_invoke_all_inventories!(assert_u32_alias_exists);
+qtty::__qtty_invoke_optional_inventories!(assert_u32_alias_exists);
+qtty::__qtty_invoke_all_inventories!(assert_u64_alias_exists);
+qtty::__qtty_invoke_optional_inventories!(assert_u64_alias_exists);
+qtty::__qtty_invoke_all_inventories!(assert_u128_alias_exists);
+qtty::__qtty_invoke_optional_inventories!(assert_u128_alias_exists);
+qtty::__qtty_invoke_all_inventories!(assert_usize_alias_exists);
+qtty::__qtty_invoke_optional_inventories!(assert_usize_alias_exists);
+
+// ── Runtime catalogue (qtty::catalog) ───────────────────────────────────────
+
+/// Assert at runtime that every listed unit has a matching `qtty::catalog`
+/// entry (same type name, dimension and ratio).
+macro_rules! assert_in_catalog {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            assert!(
+                qtty::catalog::units().any(|info| info.type_name() == stringify!($name)
+                    && info.dyn_unit() == qtty::DynUnit::of::<qtty::unit::$name>()),
+                "{} is missing from qtty::catalog",
+                stringify!($name),
+            );
+        )+
+    };
+}
+
+#[test]
+fn every_inventory_unit_is_in_the_catalog() {
+    qtty::__qtty_invoke_all_inventories!(assert_in_catalog);
+    qtty::__qtty_invoke_optional_inventories!(assert_in_catalog);
+}